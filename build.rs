@@ -0,0 +1,8 @@
+fn main() {
+    tower_grpc_build::Config::new()
+        .enable_server(true)
+        .enable_client(true)
+        .build(&["proto/svc_mesh.proto"], &["proto"])
+        .unwrap_or_else(|e| panic!("protobuf compilation failed: {}", e));
+    println!("cargo:rerun-if-changed=proto/svc_mesh.proto");
+}
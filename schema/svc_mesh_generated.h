@@ -10,14 +10,36 @@ namespace service {
 namespace mesh {
 namespace schema {
 
+struct AnimationSampler;
+
+struct AnimationChannel;
+
 struct Animation;
 
 struct MeshStream;
 
+struct VertexAttribute;
+
+struct MaterialTexture;
+
 struct MeshMaterial;
 
 struct MeshPart;
 
+struct NodeParts;
+
+struct Skeleton;
+
+struct Vector3;
+
+struct Bounds;
+
+struct OptimizationStats;
+
+struct MeshLodPart;
+
+struct MeshLod;
+
 struct Mesh;
 
 struct Manifest;
@@ -30,11 +52,26 @@ enum StreamFormat {
   StreamFormat_Vector2 = 4,
   StreamFormat_Int = 5,
   StreamFormat_Int3 = 6,
+  StreamFormat_Meshlet = 7,
+  StreamFormat_Half2 = 8,
+  StreamFormat_Half4 = 9,
+  StreamFormat_Snorm8x4 = 10,
+  StreamFormat_Unorm8x4 = 11,
+  StreamFormat_Snorm16x2 = 12,
+  StreamFormat_Snorm16x4 = 13,
+  StreamFormat_Unorm16x2 = 14,
+  StreamFormat_Unorm16x4 = 15,
+  StreamFormat_Octahedral8 = 16,
+  StreamFormat_Octahedral16 = 17,
+  StreamFormat_Snorm1010102 = 18,
+  StreamFormat_Unorm1010102 = 19,
+  StreamFormat_Short = 20,
+  StreamFormat_Interleaved = 21,
   StreamFormat_MIN = StreamFormat_Invalid,
-  StreamFormat_MAX = StreamFormat_Int3
+  StreamFormat_MAX = StreamFormat_Interleaved
 };
 
-inline const StreamFormat (&EnumValuesStreamFormat())[7] {
+inline const StreamFormat (&EnumValuesStreamFormat())[22] {
   static const StreamFormat values[] = {
     StreamFormat_Invalid,
     StreamFormat_Float,
@@ -42,7 +79,22 @@ inline const StreamFormat (&EnumValuesStreamFormat())[7] {
     StreamFormat_Vector3,
     StreamFormat_Vector2,
     StreamFormat_Int,
-    StreamFormat_Int3
+    StreamFormat_Int3,
+    StreamFormat_Meshlet,
+    StreamFormat_Half2,
+    StreamFormat_Half4,
+    StreamFormat_Snorm8x4,
+    StreamFormat_Unorm8x4,
+    StreamFormat_Snorm16x2,
+    StreamFormat_Snorm16x4,
+    StreamFormat_Unorm16x2,
+    StreamFormat_Unorm16x4,
+    StreamFormat_Octahedral8,
+    StreamFormat_Octahedral16,
+    StreamFormat_Snorm1010102,
+    StreamFormat_Unorm1010102,
+    StreamFormat_Short,
+    StreamFormat_Interleaved
   };
   return values;
 }
@@ -56,6 +108,21 @@ inline const char * const *EnumNamesStreamFormat() {
     "Vector2",
     "Int",
     "Int3",
+    "Meshlet",
+    "Half2",
+    "Half4",
+    "Snorm8x4",
+    "Unorm8x4",
+    "Snorm16x2",
+    "Snorm16x4",
+    "Unorm16x2",
+    "Unorm16x4",
+    "Octahedral8",
+    "Octahedral16",
+    "Snorm1010102",
+    "Unorm1010102",
+    "Short",
+    "Interleaved",
     nullptr
   };
   return names;
@@ -74,11 +141,15 @@ enum StreamType {
   StreamType_TextureCoordinates = 4,
   StreamType_Colors = 5,
   StreamType_Indices = 6,
+  StreamType_Meshlets = 7,
+  StreamType_MeshletVertices = 8,
+  StreamType_MeshletTriangles = 9,
+  StreamType_Interleaved = 10,
   StreamType_MIN = StreamType_Positions,
-  StreamType_MAX = StreamType_Indices
+  StreamType_MAX = StreamType_Interleaved
 };
 
-inline const StreamType (&EnumValuesStreamType())[7] {
+inline const StreamType (&EnumValuesStreamType())[11] {
   static const StreamType values[] = {
     StreamType_Positions,
     StreamType_Normals,
@@ -86,7 +157,11 @@ inline const StreamType (&EnumValuesStreamType())[7] {
     StreamType_Bitangents,
     StreamType_TextureCoordinates,
     StreamType_Colors,
-    StreamType_Indices
+    StreamType_Indices,
+    StreamType_Meshlets,
+    StreamType_MeshletVertices,
+    StreamType_MeshletTriangles,
+    StreamType_Interleaved
   };
   return values;
 }
@@ -100,6 +175,10 @@ inline const char * const *EnumNamesStreamType() {
     "TextureCoordinates",
     "Colors",
     "Indices",
+    "Meshlets",
+    "MeshletVertices",
+    "MeshletTriangles",
+    "Interleaved",
     nullptr
   };
   return names;
@@ -110,6 +189,105 @@ inline const char *EnumNameStreamType(StreamType e) {
   return EnumNamesStreamType()[index];
 }
 
+enum StreamCompression {
+  StreamCompression_None = 0,
+  StreamCompression_MeshoptVertex = 1,
+  StreamCompression_MeshoptIndex = 2,
+  StreamCompression_Zlib = 3,
+  StreamCompression_MIN = StreamCompression_None,
+  StreamCompression_MAX = StreamCompression_Zlib
+};
+
+inline const StreamCompression (&EnumValuesStreamCompression())[4] {
+  static const StreamCompression values[] = {
+    StreamCompression_None,
+    StreamCompression_MeshoptVertex,
+    StreamCompression_MeshoptIndex,
+    StreamCompression_Zlib
+  };
+  return values;
+}
+
+inline const char * const *EnumNamesStreamCompression() {
+  static const char * const names[] = {
+    "None",
+    "MeshoptVertex",
+    "MeshoptIndex",
+    "Zlib",
+    nullptr
+  };
+  return names;
+}
+
+inline const char *EnumNameStreamCompression(StreamCompression e) {
+  const size_t index = static_cast<int>(e);
+  return EnumNamesStreamCompression()[index];
+}
+
+enum AlphaMode {
+  AlphaMode_Opaque = 0,
+  AlphaMode_Mask = 1,
+  AlphaMode_Blend = 2,
+  AlphaMode_MIN = AlphaMode_Opaque,
+  AlphaMode_MAX = AlphaMode_Blend
+};
+
+inline const AlphaMode (&EnumValuesAlphaMode())[3] {
+  static const AlphaMode values[] = {
+    AlphaMode_Opaque,
+    AlphaMode_Mask,
+    AlphaMode_Blend
+  };
+  return values;
+}
+
+inline const char * const *EnumNamesAlphaMode() {
+  static const char * const names[] = {
+    "Opaque",
+    "Mask",
+    "Blend",
+    nullptr
+  };
+  return names;
+}
+
+inline const char *EnumNameAlphaMode(AlphaMode e) {
+  const size_t index = static_cast<int>(e);
+  return EnumNamesAlphaMode()[index];
+}
+
+enum Topology {
+  Topology_Triangles = 0,
+  Topology_Lines = 1,
+  Topology_Points = 2,
+  Topology_MIN = Topology_Triangles,
+  Topology_MAX = Topology_Points
+};
+
+inline const Topology (&EnumValuesTopology())[3] {
+  static const Topology values[] = {
+    Topology_Triangles,
+    Topology_Lines,
+    Topology_Points
+  };
+  return values;
+}
+
+inline const char * const *EnumNamesTopology() {
+  static const char * const names[] = {
+    "Triangles",
+    "Lines",
+    "Points",
+    nullptr
+  };
+  return names;
+}
+
+inline const char *EnumNameTopology(Topology e) {
+  const size_t index = static_cast<int>(e);
+  return EnumNamesTopology()[index];
+}
+
 enum AnimationType {
   AnimationType_None = 0,
   AnimationType_Rigid = 1,
@@ -142,9 +320,243 @@ inline const char *EnumNameAnimationType(AnimationType e) {
   return EnumNamesAnimationType()[index];
 }
 
+enum Interpolation {
+  Interpolation_Linear = 0,
+  Interpolation_Step = 1,
+  Interpolation_CatmullRomSpline = 2,
+  Interpolation_CubicSpline = 3,
+  Interpolation_MIN = Interpolation_Linear,
+  Interpolation_MAX = Interpolation_CubicSpline
+};
+
+inline const Interpolation (&EnumValuesInterpolation())[4] {
+  static const Interpolation values[] = {
+    Interpolation_Linear,
+    Interpolation_Step,
+    Interpolation_CatmullRomSpline,
+    Interpolation_CubicSpline
+  };
+  return values;
+}
+
+inline const char * const *EnumNamesInterpolation() {
+  static const char * const names[] = {
+    "Linear",
+    "Step",
+    "CatmullRomSpline",
+    "CubicSpline",
+    nullptr
+  };
+  return names;
+}
+
+inline const char *EnumNameInterpolation(Interpolation e) {
+  const size_t index = static_cast<int>(e);
+  return EnumNamesInterpolation()[index];
+}
+
+struct AnimationSampler FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
+  enum {
+    VT_INTERPOLATION = 4,
+    VT_TIMES = 6,
+    VT_VALUES = 8
+  };
+  Interpolation interpolation() const {
+    return static_cast<Interpolation>(GetField<int8_t>(VT_INTERPOLATION, 0));
+  }
+  const flatbuffers::Vector<float> *times() const {
+    return GetPointer<const flatbuffers::Vector<float> *>(VT_TIMES);
+  }
+  const flatbuffers::Vector<float> *values() const {
+    return GetPointer<const flatbuffers::Vector<float> *>(VT_VALUES);
+  }
+  bool Verify(flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyField<int8_t>(verifier, VT_INTERPOLATION) &&
+           VerifyOffset(verifier, VT_TIMES) &&
+           verifier.VerifyVector(times()) &&
+           VerifyOffset(verifier, VT_VALUES) &&
+           verifier.VerifyVector(values()) &&
+           verifier.EndTable();
+  }
+};
+
+struct AnimationSamplerBuilder {
+  flatbuffers::FlatBufferBuilder &fbb_;
+  flatbuffers::uoffset_t start_;
+  void add_interpolation(Interpolation interpolation) {
+    fbb_.AddElement<int8_t>(AnimationSampler::VT_INTERPOLATION, static_cast<int8_t>(interpolation), 0);
+  }
+  void add_times(flatbuffers::Offset<flatbuffers::Vector<float>> times) {
+    fbb_.AddOffset(AnimationSampler::VT_TIMES, times);
+  }
+  void add_values(flatbuffers::Offset<flatbuffers::Vector<float>> values) {
+    fbb_.AddOffset(AnimationSampler::VT_VALUES, values);
+  }
+  explicit AnimationSamplerBuilder(flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  AnimationSamplerBuilder &operator=(const AnimationSamplerBuilder &);
+  flatbuffers::Offset<AnimationSampler> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = flatbuffers::Offset<AnimationSampler>(end);
+    return o;
+  }
+};
+
+inline flatbuffers::Offset<AnimationSampler> CreateAnimationSampler(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    Interpolation interpolation = Interpolation_Linear,
+    flatbuffers::Offset<flatbuffers::Vector<float>> times = 0,
+    flatbuffers::Offset<flatbuffers::Vector<float>> values = 0) {
+  AnimationSamplerBuilder builder_(_fbb);
+  builder_.add_values(values);
+  builder_.add_times(times);
+  builder_.add_interpolation(interpolation);
+  return builder_.Finish();
+}
+
+inline flatbuffers::Offset<AnimationSampler> CreateAnimationSamplerDirect(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    Interpolation interpolation = Interpolation_Linear,
+    const std::vector<float> *times = nullptr,
+    const std::vector<float> *values = nullptr) {
+  return service::mesh::schema::CreateAnimationSampler(
+      _fbb,
+      interpolation,
+      times ? _fbb.CreateVector<float>(*times) : 0,
+      values ? _fbb.CreateVector<float>(*values) : 0);
+}
+
+struct AnimationChannel FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
+  enum {
+    VT_NODE = 4,
+    VT_JOINT = 6,
+    VT_TRANSLATION = 8,
+    VT_ROTATION = 10,
+    VT_SCALE = 12
+  };
+  const flatbuffers::String *node() const {
+    return GetPointer<const flatbuffers::String *>(VT_NODE);
+  }
+  int32_t joint() const {
+    return GetField<int32_t>(VT_JOINT, -1);
+  }
+  const AnimationSampler *translation() const {
+    return GetPointer<const AnimationSampler *>(VT_TRANSLATION);
+  }
+  const AnimationSampler *rotation() const {
+    return GetPointer<const AnimationSampler *>(VT_ROTATION);
+  }
+  const AnimationSampler *scale() const {
+    return GetPointer<const AnimationSampler *>(VT_SCALE);
+  }
+  bool Verify(flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_NODE) &&
+           verifier.VerifyString(node()) &&
+           VerifyField<int32_t>(verifier, VT_JOINT) &&
+           VerifyOffset(verifier, VT_TRANSLATION) &&
+           verifier.VerifyTable(translation()) &&
+           VerifyOffset(verifier, VT_ROTATION) &&
+           verifier.VerifyTable(rotation()) &&
+           VerifyOffset(verifier, VT_SCALE) &&
+           verifier.VerifyTable(scale()) &&
+           verifier.EndTable();
+  }
+};
+
+struct AnimationChannelBuilder {
+  flatbuffers::FlatBufferBuilder &fbb_;
+  flatbuffers::uoffset_t start_;
+  void add_node(flatbuffers::Offset<flatbuffers::String> node) {
+    fbb_.AddOffset(AnimationChannel::VT_NODE, node);
+  }
+  void add_joint(int32_t joint) {
+    fbb_.AddElement<int32_t>(AnimationChannel::VT_JOINT, joint, -1);
+  }
+  void add_translation(flatbuffers::Offset<AnimationSampler> translation) {
+    fbb_.AddOffset(AnimationChannel::VT_TRANSLATION, translation);
+  }
+  void add_rotation(flatbuffers::Offset<AnimationSampler> rotation) {
+    fbb_.AddOffset(AnimationChannel::VT_ROTATION, rotation);
+  }
+  void add_scale(flatbuffers::Offset<AnimationSampler> scale) {
+    fbb_.AddOffset(AnimationChannel::VT_SCALE, scale);
+  }
+  explicit AnimationChannelBuilder(flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  AnimationChannelBuilder &operator=(const AnimationChannelBuilder &);
+  flatbuffers::Offset<AnimationChannel> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = flatbuffers::Offset<AnimationChannel>(end);
+    return o;
+  }
+};
+
+inline flatbuffers::Offset<AnimationChannel> CreateAnimationChannel(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    flatbuffers::Offset<flatbuffers::String> node = 0,
+    int32_t joint = -1,
+    flatbuffers::Offset<AnimationSampler> translation = 0,
+    flatbuffers::Offset<AnimationSampler> rotation = 0,
+    flatbuffers::Offset<AnimationSampler> scale = 0) {
+  AnimationChannelBuilder builder_(_fbb);
+  builder_.add_scale(scale);
+  builder_.add_rotation(rotation);
+  builder_.add_translation(translation);
+  builder_.add_joint(joint);
+  builder_.add_node(node);
+  return builder_.Finish();
+}
+
+inline flatbuffers::Offset<AnimationChannel> CreateAnimationChannelDirect(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    const char *node = nullptr,
+    int32_t joint = -1,
+    flatbuffers::Offset<AnimationSampler> translation = 0,
+    flatbuffers::Offset<AnimationSampler> rotation = 0,
+    flatbuffers::Offset<AnimationSampler> scale = 0) {
+  return service::mesh::schema::CreateAnimationChannel(
+      _fbb,
+      node ? _fbb.CreateString(node) : 0,
+      joint,
+      translation,
+      rotation,
+      scale);
+}
+
 struct Animation FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
+  enum {
+    VT_NAME = 4,
+    VT_START = 6,
+    VT_DURATION = 8,
+    VT_CHANNELS = 10
+  };
+  const flatbuffers::String *name() const {
+    return GetPointer<const flatbuffers::String *>(VT_NAME);
+  }
+  float start() const {
+    return GetField<float>(VT_START, 0.0f);
+  }
+  float duration() const {
+    return GetField<float>(VT_DURATION, 0.0f);
+  }
+  const flatbuffers::Vector<flatbuffers::Offset<AnimationChannel>> *channels() const {
+    return GetPointer<const flatbuffers::Vector<flatbuffers::Offset<AnimationChannel>> *>(VT_CHANNELS);
+  }
   bool Verify(flatbuffers::Verifier &verifier) const {
     return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_NAME) &&
+           verifier.VerifyString(name()) &&
+           VerifyField<float>(verifier, VT_START) &&
+           VerifyField<float>(verifier, VT_DURATION) &&
+           VerifyOffset(verifier, VT_CHANNELS) &&
+           verifier.VerifyVector(channels()) &&
+           verifier.VerifyVectorOfTables(channels()) &&
            verifier.EndTable();
   }
 };
@@ -152,6 +564,18 @@ struct Animation FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
 struct AnimationBuilder {
   flatbuffers::FlatBufferBuilder &fbb_;
   flatbuffers::uoffset_t start_;
+  void add_name(flatbuffers::Offset<flatbuffers::String> name) {
+    fbb_.AddOffset(Animation::VT_NAME, name);
+  }
+  void add_start(float start) {
+    fbb_.AddElement<float>(Animation::VT_START, start, 0.0f);
+  }
+  void add_duration(float duration) {
+    fbb_.AddElement<float>(Animation::VT_DURATION, duration, 0.0f);
+  }
+  void add_channels(flatbuffers::Offset<flatbuffers::Vector<flatbuffers::Offset<AnimationChannel>>> channels) {
+    fbb_.AddOffset(Animation::VT_CHANNELS, channels);
+  }
   explicit AnimationBuilder(flatbuffers::FlatBufferBuilder &_fbb)
         : fbb_(_fbb) {
     start_ = fbb_.StartTable();
@@ -165,17 +589,44 @@ struct AnimationBuilder {
 };
 
 inline flatbuffers::Offset<Animation> CreateAnimation(
-    flatbuffers::FlatBufferBuilder &_fbb) {
+    flatbuffers::FlatBufferBuilder &_fbb,
+    flatbuffers::Offset<flatbuffers::String> name = 0,
+    float start = 0.0f,
+    float duration = 0.0f,
+    flatbuffers::Offset<flatbuffers::Vector<flatbuffers::Offset<AnimationChannel>>> channels = 0) {
   AnimationBuilder builder_(_fbb);
+  builder_.add_channels(channels);
+  builder_.add_duration(duration);
+  builder_.add_start(start);
+  builder_.add_name(name);
   return builder_.Finish();
 }
 
+inline flatbuffers::Offset<Animation> CreateAnimationDirect(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    const char *name = nullptr,
+    float start = 0.0f,
+    float duration = 0.0f,
+    const std::vector<flatbuffers::Offset<AnimationChannel>> *channels = nullptr) {
+  return service::mesh::schema::CreateAnimation(
+      _fbb,
+      name ? _fbb.CreateString(name) : 0,
+      start,
+      duration,
+      channels ? _fbb.CreateVector<flatbuffers::Offset<AnimationChannel>>(*channels) : 0);
+}
+
 struct MeshStream FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
   enum {
     VT_TYPE = 4,
     VT_FORMAT = 6,
     VT_ELEMENTS = 8,
-    VT_DATA = 10
+    VT_DATA = 10,
+    VT_OFFSET = 12,
+    VT_SCALE = 14,
+    VT_ATTRIBUTES = 16,
+    VT_COMPRESSION = 18,
+    VT_DECODED_SIZE = 20
   };
   StreamType type() const {
     return static_cast<StreamType>(GetField<int8_t>(VT_TYPE, 0));
@@ -189,6 +640,21 @@ struct MeshStream FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
   const flatbuffers::Vector<uint8_t> *data() const {
     return GetPointer<const flatbuffers::Vector<uint8_t> *>(VT_DATA);
   }
+  const Vector3 *offset() const {
+    return GetPointer<const Vector3 *>(VT_OFFSET);
+  }
+  const Vector3 *scale() const {
+    return GetPointer<const Vector3 *>(VT_SCALE);
+  }
+  const flatbuffers::Vector<flatbuffers::Offset<VertexAttribute>> *attributes() const {
+    return GetPointer<const flatbuffers::Vector<flatbuffers::Offset<VertexAttribute>> *>(VT_ATTRIBUTES);
+  }
+  StreamCompression compression() const {
+    return static_cast<StreamCompression>(GetField<int8_t>(VT_COMPRESSION, 0));
+  }
+  uint64_t decoded_size() const {
+    return GetField<uint64_t>(VT_DECODED_SIZE, 0);
+  }
   bool Verify(flatbuffers::Verifier &verifier) const {
     return VerifyTableStart(verifier) &&
            VerifyField<int8_t>(verifier, VT_TYPE) &&
@@ -196,6 +662,15 @@ struct MeshStream FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
            VerifyField<uint64_t>(verifier, VT_ELEMENTS) &&
            VerifyOffset(verifier, VT_DATA) &&
            verifier.VerifyVector(data()) &&
+           VerifyOffset(verifier, VT_OFFSET) &&
+           verifier.VerifyTable(offset()) &&
+           VerifyOffset(verifier, VT_SCALE) &&
+           verifier.VerifyTable(scale()) &&
+           VerifyOffset(verifier, VT_ATTRIBUTES) &&
+           verifier.VerifyVector(attributes()) &&
+           verifier.VerifyVectorOfTables(attributes()) &&
+           VerifyField<int8_t>(verifier, VT_COMPRESSION) &&
+           VerifyField<uint64_t>(verifier, VT_DECODED_SIZE) &&
            verifier.EndTable();
   }
 };
@@ -215,6 +690,21 @@ struct MeshStreamBuilder {
   void add_data(flatbuffers::Offset<flatbuffers::Vector<uint8_t>> data) {
     fbb_.AddOffset(MeshStream::VT_DATA, data);
   }
+  void add_offset(flatbuffers::Offset<Vector3> offset) {
+    fbb_.AddOffset(MeshStream::VT_OFFSET, offset);
+  }
+  void add_scale(flatbuffers::Offset<Vector3> scale) {
+    fbb_.AddOffset(MeshStream::VT_SCALE, scale);
+  }
+  void add_attributes(flatbuffers::Offset<flatbuffers::Vector<flatbuffers::Offset<VertexAttribute>>> attributes) {
+    fbb_.AddOffset(MeshStream::VT_ATTRIBUTES, attributes);
+  }
+  void add_compression(StreamCompression compression) {
+    fbb_.AddElement<int8_t>(MeshStream::VT_COMPRESSION, static_cast<int8_t>(compression), 0);
+  }
+  void add_decoded_size(uint64_t decoded_size) {
+    fbb_.AddElement<uint64_t>(MeshStream::VT_DECODED_SIZE, decoded_size, 0);
+  }
   explicit MeshStreamBuilder(flatbuffers::FlatBufferBuilder &_fbb)
         : fbb_(_fbb) {
     start_ = fbb_.StartTable();
@@ -232,10 +722,20 @@ inline flatbuffers::Offset<MeshStream> CreateMeshStream(
     StreamType type = StreamType_Positions,
     StreamFormat format = StreamFormat_Invalid,
     uint64_t elements = 0,
-    flatbuffers::Offset<flatbuffers::Vector<uint8_t>> data = 0) {
+    flatbuffers::Offset<flatbuffers::Vector<uint8_t>> data = 0,
+    flatbuffers::Offset<Vector3> offset = 0,
+    flatbuffers::Offset<Vector3> scale = 0,
+    flatbuffers::Offset<flatbuffers::Vector<flatbuffers::Offset<VertexAttribute>>> attributes = 0,
+    StreamCompression compression = StreamCompression_None,
+    uint64_t decoded_size = 0) {
   MeshStreamBuilder builder_(_fbb);
+  builder_.add_decoded_size(decoded_size);
   builder_.add_elements(elements);
+  builder_.add_attributes(attributes);
+  builder_.add_scale(scale);
+  builder_.add_offset(offset);
   builder_.add_data(data);
+  builder_.add_compression(compression);
   builder_.add_format(format);
   builder_.add_type(type);
   return builder_.Finish();
@@ -246,126 +746,457 @@ inline flatbuffers::Offset<MeshStream> CreateMeshStreamDirect(
     StreamType type = StreamType_Positions,
     StreamFormat format = StreamFormat_Invalid,
     uint64_t elements = 0,
-    const std::vector<uint8_t> *data = nullptr) {
+    const std::vector<uint8_t> *data = nullptr,
+    flatbuffers::Offset<Vector3> offset = 0,
+    flatbuffers::Offset<Vector3> scale = 0,
+    const std::vector<flatbuffers::Offset<VertexAttribute>> *attributes = nullptr,
+    StreamCompression compression = StreamCompression_None,
+    uint64_t decoded_size = 0) {
   return service::mesh::schema::CreateMeshStream(
       _fbb,
       type,
       format,
       elements,
-      data ? _fbb.CreateVector<uint8_t>(*data) : 0);
+      data ? _fbb.CreateVector<uint8_t>(*data) : 0,
+      offset,
+      scale,
+      attributes ? _fbb.CreateVector<flatbuffers::Offset<VertexAttribute>>(*attributes) : 0,
+      compression,
+      decoded_size);
 }
 
-struct MeshMaterial FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
+struct VertexAttribute FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
   enum {
-    VT_NAME = 4,
-    VT_MATERIAL = 6,
-    VT_ALBEDO_TINT = 8,
-    VT_ROUGHNESS = 10
+    VT_SEMANTIC = 4,
+    VT_FORMAT = 6,
+    VT_OFFSET = 8,
+    VT_STRIDE = 10,
+    VT_DECODE_OFFSET = 12,
+    VT_DECODE_SCALE = 14
   };
-  const flatbuffers::String *name() const {
-    return GetPointer<const flatbuffers::String *>(VT_NAME);
+  StreamType semantic() const {
+    return static_cast<StreamType>(GetField<int8_t>(VT_SEMANTIC, 0));
   }
-  const flatbuffers::String *material() const {
-    return GetPointer<const flatbuffers::String *>(VT_MATERIAL);
+  StreamFormat format() const {
+    return static_cast<StreamFormat>(GetField<int8_t>(VT_FORMAT, 0));
   }
-  const flatbuffers::Vector<float> *albedo_tint() const {
-    return GetPointer<const flatbuffers::Vector<float> *>(VT_ALBEDO_TINT);
+  uint32_t offset() const {
+    return GetField<uint32_t>(VT_OFFSET, 0);
   }
-  float roughness() const {
-    return GetField<float>(VT_ROUGHNESS, 0.0f);
+  uint32_t stride() const {
+    return GetField<uint32_t>(VT_STRIDE, 0);
+  }
+  const Vector3 *decode_offset() const {
+    return GetPointer<const Vector3 *>(VT_DECODE_OFFSET);
+  }
+  const Vector3 *decode_scale() const {
+    return GetPointer<const Vector3 *>(VT_DECODE_SCALE);
   }
   bool Verify(flatbuffers::Verifier &verifier) const {
     return VerifyTableStart(verifier) &&
-           VerifyOffset(verifier, VT_NAME) &&
-           verifier.VerifyString(name()) &&
-           VerifyOffset(verifier, VT_MATERIAL) &&
-           verifier.VerifyString(material()) &&
-           VerifyOffset(verifier, VT_ALBEDO_TINT) &&
-           verifier.VerifyVector(albedo_tint()) &&
-           VerifyField<float>(verifier, VT_ROUGHNESS) &&
+           VerifyField<int8_t>(verifier, VT_SEMANTIC) &&
+           VerifyField<int8_t>(verifier, VT_FORMAT) &&
+           VerifyField<uint32_t>(verifier, VT_OFFSET) &&
+           VerifyField<uint32_t>(verifier, VT_STRIDE) &&
+           VerifyOffset(verifier, VT_DECODE_OFFSET) &&
+           verifier.VerifyTable(decode_offset()) &&
+           VerifyOffset(verifier, VT_DECODE_SCALE) &&
+           verifier.VerifyTable(decode_scale()) &&
            verifier.EndTable();
   }
 };
 
-struct MeshMaterialBuilder {
+struct VertexAttributeBuilder {
   flatbuffers::FlatBufferBuilder &fbb_;
   flatbuffers::uoffset_t start_;
-  void add_name(flatbuffers::Offset<flatbuffers::String> name) {
-    fbb_.AddOffset(MeshMaterial::VT_NAME, name);
+  void add_semantic(StreamType semantic) {
+    fbb_.AddElement<int8_t>(VertexAttribute::VT_SEMANTIC, static_cast<int8_t>(semantic), 0);
   }
-  void add_material(flatbuffers::Offset<flatbuffers::String> material) {
-    fbb_.AddOffset(MeshMaterial::VT_MATERIAL, material);
+  void add_format(StreamFormat format) {
+    fbb_.AddElement<int8_t>(VertexAttribute::VT_FORMAT, static_cast<int8_t>(format), 0);
   }
-  void add_albedo_tint(flatbuffers::Offset<flatbuffers::Vector<float>> albedo_tint) {
-    fbb_.AddOffset(MeshMaterial::VT_ALBEDO_TINT, albedo_tint);
+  void add_offset(uint32_t offset) {
+    fbb_.AddElement<uint32_t>(VertexAttribute::VT_OFFSET, offset, 0);
   }
-  void add_roughness(float roughness) {
-    fbb_.AddElement<float>(MeshMaterial::VT_ROUGHNESS, roughness, 0.0f);
+  void add_stride(uint32_t stride) {
+    fbb_.AddElement<uint32_t>(VertexAttribute::VT_STRIDE, stride, 0);
   }
-  explicit MeshMaterialBuilder(flatbuffers::FlatBufferBuilder &_fbb)
+  void add_decode_offset(flatbuffers::Offset<Vector3> decode_offset) {
+    fbb_.AddOffset(VertexAttribute::VT_DECODE_OFFSET, decode_offset);
+  }
+  void add_decode_scale(flatbuffers::Offset<Vector3> decode_scale) {
+    fbb_.AddOffset(VertexAttribute::VT_DECODE_SCALE, decode_scale);
+  }
+  explicit VertexAttributeBuilder(flatbuffers::FlatBufferBuilder &_fbb)
         : fbb_(_fbb) {
     start_ = fbb_.StartTable();
   }
-  MeshMaterialBuilder &operator=(const MeshMaterialBuilder &);
-  flatbuffers::Offset<MeshMaterial> Finish() {
+  VertexAttributeBuilder &operator=(const VertexAttributeBuilder &);
+  flatbuffers::Offset<VertexAttribute> Finish() {
     const auto end = fbb_.EndTable(start_);
-    auto o = flatbuffers::Offset<MeshMaterial>(end);
+    auto o = flatbuffers::Offset<VertexAttribute>(end);
     return o;
   }
 };
 
-inline flatbuffers::Offset<MeshMaterial> CreateMeshMaterial(
+inline flatbuffers::Offset<VertexAttribute> CreateVertexAttribute(
     flatbuffers::FlatBufferBuilder &_fbb,
-    flatbuffers::Offset<flatbuffers::String> name = 0,
-    flatbuffers::Offset<flatbuffers::String> material = 0,
-    flatbuffers::Offset<flatbuffers::Vector<float>> albedo_tint = 0,
-    float roughness = 0.0f) {
-  MeshMaterialBuilder builder_(_fbb);
-  builder_.add_roughness(roughness);
-  builder_.add_albedo_tint(albedo_tint);
-  builder_.add_material(material);
-  builder_.add_name(name);
+    StreamType semantic = StreamType_Positions,
+    StreamFormat format = StreamFormat_Invalid,
+    uint32_t offset = 0,
+    uint32_t stride = 0,
+    flatbuffers::Offset<Vector3> decode_offset = 0,
+    flatbuffers::Offset<Vector3> decode_scale = 0) {
+  VertexAttributeBuilder builder_(_fbb);
+  builder_.add_decode_scale(decode_scale);
+  builder_.add_decode_offset(decode_offset);
+  builder_.add_stride(stride);
+  builder_.add_offset(offset);
+  builder_.add_format(format);
+  builder_.add_semantic(semantic);
   return builder_.Finish();
 }
 
-inline flatbuffers::Offset<MeshMaterial> CreateMeshMaterialDirect(
-    flatbuffers::FlatBufferBuilder &_fbb,
-    const char *name = nullptr,
-    const char *material = nullptr,
-    const std::vector<float> *albedo_tint = nullptr,
-    float roughness = 0.0f) {
-  return service::mesh::schema::CreateMeshMaterial(
-      _fbb,
-      name ? _fbb.CreateString(name) : 0,
-      material ? _fbb.CreateString(material) : 0,
-      albedo_tint ? _fbb.CreateVector<float>(*albedo_tint) : 0,
-      roughness);
-}
-
-struct MeshPart FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
+struct MaterialTexture FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
   enum {
-    VT_INDEX_START = 4,
-    VT_INDEX_COUNT = 6,
-    VT_MATERIAL_INDEX = 8,
-    VT_NODE_INDEX = 10,
-    VT_BASE_TRANSFORM = 12,
-    VT_NAME = 14,
-    VT_ANIMATION_TYPE = 16
+    VT_IMAGE = 4,
+    VT_TEX_COORD = 6
   };
-  uint32_t index_start() const {
-    return GetField<uint32_t>(VT_INDEX_START, 0);
+  const flatbuffers::String *image() const {
+    return GetPointer<const flatbuffers::String *>(VT_IMAGE);
   }
-  uint32_t index_count() const {
-    return GetField<uint32_t>(VT_INDEX_COUNT, 0);
+  uint32_t tex_coord() const {
+    return GetField<uint32_t>(VT_TEX_COORD, 0);
   }
-  int32_t material_index() const {
-    return GetField<int32_t>(VT_MATERIAL_INDEX, 0);
+  bool Verify(flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_IMAGE) &&
+           verifier.VerifyString(image()) &&
+           VerifyField<uint32_t>(verifier, VT_TEX_COORD) &&
+           verifier.EndTable();
   }
-  int32_t node_index() const {
-    return GetField<int32_t>(VT_NODE_INDEX, 0);
+};
+
+struct MaterialTextureBuilder {
+  flatbuffers::FlatBufferBuilder &fbb_;
+  flatbuffers::uoffset_t start_;
+  void add_image(flatbuffers::Offset<flatbuffers::String> image) {
+    fbb_.AddOffset(MaterialTexture::VT_IMAGE, image);
   }
-  const flatbuffers::Vector<float> *base_transform() const {
-    return GetPointer<const flatbuffers::Vector<float> *>(VT_BASE_TRANSFORM);
+  void add_tex_coord(uint32_t tex_coord) {
+    fbb_.AddElement<uint32_t>(MaterialTexture::VT_TEX_COORD, tex_coord, 0);
+  }
+  explicit MaterialTextureBuilder(flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  MaterialTextureBuilder &operator=(const MaterialTextureBuilder &);
+  flatbuffers::Offset<MaterialTexture> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = flatbuffers::Offset<MaterialTexture>(end);
+    return o;
+  }
+};
+
+inline flatbuffers::Offset<MaterialTexture> CreateMaterialTexture(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    flatbuffers::Offset<flatbuffers::String> image = 0,
+    uint32_t tex_coord = 0) {
+  MaterialTextureBuilder builder_(_fbb);
+  builder_.add_tex_coord(tex_coord);
+  builder_.add_image(image);
+  return builder_.Finish();
+}
+
+inline flatbuffers::Offset<MaterialTexture> CreateMaterialTextureDirect(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    const char *image = nullptr,
+    uint32_t tex_coord = 0) {
+  return service::mesh::schema::CreateMaterialTexture(
+      _fbb,
+      image ? _fbb.CreateString(image) : 0,
+      tex_coord);
+}
+
+struct MeshMaterial FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
+  enum {
+    VT_NAME = 4,
+    VT_MATERIAL = 6,
+    VT_ALBEDO_TINT = 8,
+    VT_ROUGHNESS = 10,
+    VT_ALPHA = 12,
+    VT_METALLIC = 14,
+    VT_EMISSIVE = 16,
+    VT_NORMAL_SCALE = 18,
+    VT_OCCLUSION_STRENGTH = 20,
+    VT_ALPHA_MODE = 22,
+    VT_ALPHA_CUTOFF = 24,
+    VT_DOUBLE_SIDED = 26,
+    VT_BASE_COLOR_TEXTURE = 28,
+    VT_METALLIC_ROUGHNESS_TEXTURE = 30,
+    VT_NORMAL_TEXTURE = 32,
+    VT_OCCLUSION_TEXTURE = 34,
+    VT_EMISSIVE_TEXTURE = 36
+  };
+  const flatbuffers::String *name() const {
+    return GetPointer<const flatbuffers::String *>(VT_NAME);
+  }
+  const flatbuffers::String *material() const {
+    return GetPointer<const flatbuffers::String *>(VT_MATERIAL);
+  }
+  const Vector3 *albedo_tint() const {
+    return GetPointer<const Vector3 *>(VT_ALBEDO_TINT);
+  }
+  float roughness() const {
+    return GetField<float>(VT_ROUGHNESS, 0.0f);
+  }
+  float alpha() const {
+    return GetField<float>(VT_ALPHA, 1.0f);
+  }
+  float metallic() const {
+    return GetField<float>(VT_METALLIC, 1.0f);
+  }
+  const Vector3 *emissive() const {
+    return GetPointer<const Vector3 *>(VT_EMISSIVE);
+  }
+  float normal_scale() const {
+    return GetField<float>(VT_NORMAL_SCALE, 1.0f);
+  }
+  float occlusion_strength() const {
+    return GetField<float>(VT_OCCLUSION_STRENGTH, 1.0f);
+  }
+  AlphaMode alpha_mode() const {
+    return static_cast<AlphaMode>(GetField<int8_t>(VT_ALPHA_MODE, 0));
+  }
+  float alpha_cutoff() const {
+    return GetField<float>(VT_ALPHA_CUTOFF, 0.5f);
+  }
+  bool double_sided() const {
+    return GetField<uint8_t>(VT_DOUBLE_SIDED, 0) != 0;
+  }
+  const MaterialTexture *base_color_texture() const {
+    return GetPointer<const MaterialTexture *>(VT_BASE_COLOR_TEXTURE);
+  }
+  const MaterialTexture *metallic_roughness_texture() const {
+    return GetPointer<const MaterialTexture *>(VT_METALLIC_ROUGHNESS_TEXTURE);
+  }
+  const MaterialTexture *normal_texture() const {
+    return GetPointer<const MaterialTexture *>(VT_NORMAL_TEXTURE);
+  }
+  const MaterialTexture *occlusion_texture() const {
+    return GetPointer<const MaterialTexture *>(VT_OCCLUSION_TEXTURE);
+  }
+  const MaterialTexture *emissive_texture() const {
+    return GetPointer<const MaterialTexture *>(VT_EMISSIVE_TEXTURE);
+  }
+  bool Verify(flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_NAME) &&
+           verifier.VerifyString(name()) &&
+           VerifyOffset(verifier, VT_MATERIAL) &&
+           verifier.VerifyString(material()) &&
+           VerifyOffset(verifier, VT_ALBEDO_TINT) &&
+           verifier.VerifyTable(albedo_tint()) &&
+           VerifyField<float>(verifier, VT_ROUGHNESS) &&
+           VerifyField<float>(verifier, VT_ALPHA) &&
+           VerifyField<float>(verifier, VT_METALLIC) &&
+           VerifyOffset(verifier, VT_EMISSIVE) &&
+           verifier.VerifyTable(emissive()) &&
+           VerifyField<float>(verifier, VT_NORMAL_SCALE) &&
+           VerifyField<float>(verifier, VT_OCCLUSION_STRENGTH) &&
+           VerifyField<int8_t>(verifier, VT_ALPHA_MODE) &&
+           VerifyField<float>(verifier, VT_ALPHA_CUTOFF) &&
+           VerifyField<uint8_t>(verifier, VT_DOUBLE_SIDED) &&
+           VerifyOffset(verifier, VT_BASE_COLOR_TEXTURE) &&
+           verifier.VerifyTable(base_color_texture()) &&
+           VerifyOffset(verifier, VT_METALLIC_ROUGHNESS_TEXTURE) &&
+           verifier.VerifyTable(metallic_roughness_texture()) &&
+           VerifyOffset(verifier, VT_NORMAL_TEXTURE) &&
+           verifier.VerifyTable(normal_texture()) &&
+           VerifyOffset(verifier, VT_OCCLUSION_TEXTURE) &&
+           verifier.VerifyTable(occlusion_texture()) &&
+           VerifyOffset(verifier, VT_EMISSIVE_TEXTURE) &&
+           verifier.VerifyTable(emissive_texture()) &&
+           verifier.EndTable();
+  }
+};
+
+struct MeshMaterialBuilder {
+  flatbuffers::FlatBufferBuilder &fbb_;
+  flatbuffers::uoffset_t start_;
+  void add_name(flatbuffers::Offset<flatbuffers::String> name) {
+    fbb_.AddOffset(MeshMaterial::VT_NAME, name);
+  }
+  void add_material(flatbuffers::Offset<flatbuffers::String> material) {
+    fbb_.AddOffset(MeshMaterial::VT_MATERIAL, material);
+  }
+  void add_albedo_tint(flatbuffers::Offset<Vector3> albedo_tint) {
+    fbb_.AddOffset(MeshMaterial::VT_ALBEDO_TINT, albedo_tint);
+  }
+  void add_roughness(float roughness) {
+    fbb_.AddElement<float>(MeshMaterial::VT_ROUGHNESS, roughness, 0.0f);
+  }
+  void add_alpha(float alpha) {
+    fbb_.AddElement<float>(MeshMaterial::VT_ALPHA, alpha, 1.0f);
+  }
+  void add_metallic(float metallic) {
+    fbb_.AddElement<float>(MeshMaterial::VT_METALLIC, metallic, 1.0f);
+  }
+  void add_emissive(flatbuffers::Offset<Vector3> emissive) {
+    fbb_.AddOffset(MeshMaterial::VT_EMISSIVE, emissive);
+  }
+  void add_normal_scale(float normal_scale) {
+    fbb_.AddElement<float>(MeshMaterial::VT_NORMAL_SCALE, normal_scale, 1.0f);
+  }
+  void add_occlusion_strength(float occlusion_strength) {
+    fbb_.AddElement<float>(MeshMaterial::VT_OCCLUSION_STRENGTH, occlusion_strength, 1.0f);
+  }
+  void add_alpha_mode(AlphaMode alpha_mode) {
+    fbb_.AddElement<int8_t>(MeshMaterial::VT_ALPHA_MODE, static_cast<int8_t>(alpha_mode), 0);
+  }
+  void add_alpha_cutoff(float alpha_cutoff) {
+    fbb_.AddElement<float>(MeshMaterial::VT_ALPHA_CUTOFF, alpha_cutoff, 0.5f);
+  }
+  void add_double_sided(bool double_sided) {
+    fbb_.AddElement<uint8_t>(MeshMaterial::VT_DOUBLE_SIDED, static_cast<uint8_t>(double_sided), 0);
+  }
+  void add_base_color_texture(flatbuffers::Offset<MaterialTexture> base_color_texture) {
+    fbb_.AddOffset(MeshMaterial::VT_BASE_COLOR_TEXTURE, base_color_texture);
+  }
+  void add_metallic_roughness_texture(flatbuffers::Offset<MaterialTexture> metallic_roughness_texture) {
+    fbb_.AddOffset(MeshMaterial::VT_METALLIC_ROUGHNESS_TEXTURE, metallic_roughness_texture);
+  }
+  void add_normal_texture(flatbuffers::Offset<MaterialTexture> normal_texture) {
+    fbb_.AddOffset(MeshMaterial::VT_NORMAL_TEXTURE, normal_texture);
+  }
+  void add_occlusion_texture(flatbuffers::Offset<MaterialTexture> occlusion_texture) {
+    fbb_.AddOffset(MeshMaterial::VT_OCCLUSION_TEXTURE, occlusion_texture);
+  }
+  void add_emissive_texture(flatbuffers::Offset<MaterialTexture> emissive_texture) {
+    fbb_.AddOffset(MeshMaterial::VT_EMISSIVE_TEXTURE, emissive_texture);
+  }
+  explicit MeshMaterialBuilder(flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  MeshMaterialBuilder &operator=(const MeshMaterialBuilder &);
+  flatbuffers::Offset<MeshMaterial> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = flatbuffers::Offset<MeshMaterial>(end);
+    return o;
+  }
+};
+
+inline flatbuffers::Offset<MeshMaterial> CreateMeshMaterial(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    flatbuffers::Offset<flatbuffers::String> name = 0,
+    flatbuffers::Offset<flatbuffers::String> material = 0,
+    flatbuffers::Offset<Vector3> albedo_tint = 0,
+    float roughness = 0.0f,
+    float alpha = 1.0f,
+    float metallic = 1.0f,
+    flatbuffers::Offset<Vector3> emissive = 0,
+    float normal_scale = 1.0f,
+    float occlusion_strength = 1.0f,
+    AlphaMode alpha_mode = AlphaMode_Opaque,
+    float alpha_cutoff = 0.5f,
+    bool double_sided = false,
+    flatbuffers::Offset<MaterialTexture> base_color_texture = 0,
+    flatbuffers::Offset<MaterialTexture> metallic_roughness_texture = 0,
+    flatbuffers::Offset<MaterialTexture> normal_texture = 0,
+    flatbuffers::Offset<MaterialTexture> occlusion_texture = 0,
+    flatbuffers::Offset<MaterialTexture> emissive_texture = 0) {
+  MeshMaterialBuilder builder_(_fbb);
+  builder_.add_emissive_texture(emissive_texture);
+  builder_.add_occlusion_texture(occlusion_texture);
+  builder_.add_normal_texture(normal_texture);
+  builder_.add_metallic_roughness_texture(metallic_roughness_texture);
+  builder_.add_base_color_texture(base_color_texture);
+  builder_.add_alpha_cutoff(alpha_cutoff);
+  builder_.add_occlusion_strength(occlusion_strength);
+  builder_.add_normal_scale(normal_scale);
+  builder_.add_emissive(emissive);
+  builder_.add_metallic(metallic);
+  builder_.add_alpha(alpha);
+  builder_.add_roughness(roughness);
+  builder_.add_albedo_tint(albedo_tint);
+  builder_.add_material(material);
+  builder_.add_name(name);
+  builder_.add_double_sided(double_sided);
+  builder_.add_alpha_mode(alpha_mode);
+  return builder_.Finish();
+}
+
+inline flatbuffers::Offset<MeshMaterial> CreateMeshMaterialDirect(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    const char *name = nullptr,
+    const char *material = nullptr,
+    flatbuffers::Offset<Vector3> albedo_tint = 0,
+    float roughness = 0.0f,
+    float alpha = 1.0f,
+    float metallic = 1.0f,
+    flatbuffers::Offset<Vector3> emissive = 0,
+    float normal_scale = 1.0f,
+    float occlusion_strength = 1.0f,
+    AlphaMode alpha_mode = AlphaMode_Opaque,
+    float alpha_cutoff = 0.5f,
+    bool double_sided = false,
+    flatbuffers::Offset<MaterialTexture> base_color_texture = 0,
+    flatbuffers::Offset<MaterialTexture> metallic_roughness_texture = 0,
+    flatbuffers::Offset<MaterialTexture> normal_texture = 0,
+    flatbuffers::Offset<MaterialTexture> occlusion_texture = 0,
+    flatbuffers::Offset<MaterialTexture> emissive_texture = 0) {
+  return service::mesh::schema::CreateMeshMaterial(
+      _fbb,
+      name ? _fbb.CreateString(name) : 0,
+      material ? _fbb.CreateString(material) : 0,
+      albedo_tint,
+      roughness,
+      alpha,
+      metallic,
+      emissive,
+      normal_scale,
+      occlusion_strength,
+      alpha_mode,
+      alpha_cutoff,
+      double_sided,
+      base_color_texture,
+      metallic_roughness_texture,
+      normal_texture,
+      occlusion_texture,
+      emissive_texture);
+}
+
+struct MeshPart FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
+  enum {
+    VT_INDEX_START = 4,
+    VT_INDEX_COUNT = 6,
+    VT_MATERIAL_INDEX = 8,
+    VT_NODE_INDEX = 10,
+    VT_BASE_TRANSFORM = 12,
+    VT_NAME = 14,
+    VT_ANIMATION_TYPE = 16,
+    VT_MESHLET_START = 18,
+    VT_MESHLET_COUNT = 20,
+    VT_BASE_VERTEX = 22,
+    VT_INSTANCE_TRANSFORMS = 24,
+    VT_BOUNDS = 26,
+    VT_TOPOLOGY = 28
+  };
+  uint32_t index_start() const {
+    return GetField<uint32_t>(VT_INDEX_START, 0);
+  }
+  uint32_t index_count() const {
+    return GetField<uint32_t>(VT_INDEX_COUNT, 0);
+  }
+  int32_t material_index() const {
+    return GetField<int32_t>(VT_MATERIAL_INDEX, 0);
+  }
+  int32_t node_index() const {
+    return GetField<int32_t>(VT_NODE_INDEX, 0);
+  }
+  const flatbuffers::Vector<float> *base_transform() const {
+    return GetPointer<const flatbuffers::Vector<float> *>(VT_BASE_TRANSFORM);
   }
   const flatbuffers::String *name() const {
     return GetPointer<const flatbuffers::String *>(VT_NAME);
@@ -373,6 +1204,24 @@ struct MeshPart FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
   AnimationType animation_type() const {
     return static_cast<AnimationType>(GetField<int8_t>(VT_ANIMATION_TYPE, 0));
   }
+  uint32_t meshlet_start() const {
+    return GetField<uint32_t>(VT_MESHLET_START, 0);
+  }
+  uint32_t meshlet_count() const {
+    return GetField<uint32_t>(VT_MESHLET_COUNT, 0);
+  }
+  uint32_t base_vertex() const {
+    return GetField<uint32_t>(VT_BASE_VERTEX, 0);
+  }
+  const flatbuffers::Vector<float> *instance_transforms() const {
+    return GetPointer<const flatbuffers::Vector<float> *>(VT_INSTANCE_TRANSFORMS);
+  }
+  const Bounds *bounds() const {
+    return GetPointer<const Bounds *>(VT_BOUNDS);
+  }
+  Topology topology() const {
+    return static_cast<Topology>(GetField<int8_t>(VT_TOPOLOGY, 0));
+  }
   bool Verify(flatbuffers::Verifier &verifier) const {
     return VerifyTableStart(verifier) &&
            VerifyField<uint32_t>(verifier, VT_INDEX_START) &&
@@ -384,6 +1233,14 @@ struct MeshPart FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
            VerifyOffset(verifier, VT_NAME) &&
            verifier.VerifyString(name()) &&
            VerifyField<int8_t>(verifier, VT_ANIMATION_TYPE) &&
+           VerifyField<uint32_t>(verifier, VT_MESHLET_START) &&
+           VerifyField<uint32_t>(verifier, VT_MESHLET_COUNT) &&
+           VerifyField<uint32_t>(verifier, VT_BASE_VERTEX) &&
+           VerifyOffset(verifier, VT_INSTANCE_TRANSFORMS) &&
+           verifier.VerifyVector(instance_transforms()) &&
+           VerifyOffset(verifier, VT_BOUNDS) &&
+           verifier.VerifyTable(bounds()) &&
+           VerifyField<int8_t>(verifier, VT_TOPOLOGY) &&
            verifier.EndTable();
   }
 };
@@ -412,6 +1269,24 @@ struct MeshPartBuilder {
   void add_animation_type(AnimationType animation_type) {
     fbb_.AddElement<int8_t>(MeshPart::VT_ANIMATION_TYPE, static_cast<int8_t>(animation_type), 0);
   }
+  void add_meshlet_start(uint32_t meshlet_start) {
+    fbb_.AddElement<uint32_t>(MeshPart::VT_MESHLET_START, meshlet_start, 0);
+  }
+  void add_meshlet_count(uint32_t meshlet_count) {
+    fbb_.AddElement<uint32_t>(MeshPart::VT_MESHLET_COUNT, meshlet_count, 0);
+  }
+  void add_base_vertex(uint32_t base_vertex) {
+    fbb_.AddElement<uint32_t>(MeshPart::VT_BASE_VERTEX, base_vertex, 0);
+  }
+  void add_instance_transforms(flatbuffers::Offset<flatbuffers::Vector<float>> instance_transforms) {
+    fbb_.AddOffset(MeshPart::VT_INSTANCE_TRANSFORMS, instance_transforms);
+  }
+  void add_bounds(flatbuffers::Offset<Bounds> bounds) {
+    fbb_.AddOffset(MeshPart::VT_BOUNDS, bounds);
+  }
+  void add_topology(Topology topology) {
+    fbb_.AddElement<int8_t>(MeshPart::VT_TOPOLOGY, static_cast<int8_t>(topology), 0);
+  }
   explicit MeshPartBuilder(flatbuffers::FlatBufferBuilder &_fbb)
         : fbb_(_fbb) {
     start_ = fbb_.StartTable();
@@ -432,14 +1307,26 @@ inline flatbuffers::Offset<MeshPart> CreateMeshPart(
     int32_t node_index = 0,
     flatbuffers::Offset<flatbuffers::Vector<float>> base_transform = 0,
     flatbuffers::Offset<flatbuffers::String> name = 0,
-    AnimationType animation_type = AnimationType_None) {
+    AnimationType animation_type = AnimationType_None,
+    uint32_t meshlet_start = 0,
+    uint32_t meshlet_count = 0,
+    uint32_t base_vertex = 0,
+    flatbuffers::Offset<flatbuffers::Vector<float>> instance_transforms = 0,
+    flatbuffers::Offset<Bounds> bounds = 0,
+    Topology topology = Topology_Triangles) {
   MeshPartBuilder builder_(_fbb);
+  builder_.add_bounds(bounds);
+  builder_.add_instance_transforms(instance_transforms);
+  builder_.add_base_vertex(base_vertex);
+  builder_.add_meshlet_count(meshlet_count);
+  builder_.add_meshlet_start(meshlet_start);
   builder_.add_name(name);
   builder_.add_base_transform(base_transform);
   builder_.add_node_index(node_index);
   builder_.add_material_index(material_index);
   builder_.add_index_count(index_count);
   builder_.add_index_start(index_start);
+  builder_.add_topology(topology);
   builder_.add_animation_type(animation_type);
   return builder_.Finish();
 }
@@ -452,7 +1339,13 @@ inline flatbuffers::Offset<MeshPart> CreateMeshPartDirect(
     int32_t node_index = 0,
     const std::vector<float> *base_transform = nullptr,
     const char *name = nullptr,
-    AnimationType animation_type = AnimationType_None) {
+    AnimationType animation_type = AnimationType_None,
+    uint32_t meshlet_start = 0,
+    uint32_t meshlet_count = 0,
+    uint32_t base_vertex = 0,
+    const std::vector<float> *instance_transforms = nullptr,
+    flatbuffers::Offset<Bounds> bounds = 0,
+    Topology topology = Topology_Triangles) {
   return service::mesh::schema::CreateMeshPart(
       _fbb,
       index_start,
@@ -461,7 +1354,525 @@ inline flatbuffers::Offset<MeshPart> CreateMeshPartDirect(
       node_index,
       base_transform ? _fbb.CreateVector<float>(*base_transform) : 0,
       name ? _fbb.CreateString(name) : 0,
-      animation_type);
+      animation_type,
+      meshlet_start,
+      meshlet_count,
+      base_vertex,
+      instance_transforms ? _fbb.CreateVector<float>(*instance_transforms) : 0,
+      bounds,
+      topology);
+}
+
+struct NodeParts FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
+  enum {
+    VT_NAME = 4,
+    VT_PARTS = 6,
+    VT_BOUNDS = 8
+  };
+  const flatbuffers::String *name() const {
+    return GetPointer<const flatbuffers::String *>(VT_NAME);
+  }
+  const flatbuffers::Vector<uint32_t> *parts() const {
+    return GetPointer<const flatbuffers::Vector<uint32_t> *>(VT_PARTS);
+  }
+  const Bounds *bounds() const {
+    return GetPointer<const Bounds *>(VT_BOUNDS);
+  }
+  bool Verify(flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_NAME) &&
+           verifier.VerifyString(name()) &&
+           VerifyOffset(verifier, VT_PARTS) &&
+           verifier.VerifyVector(parts()) &&
+           VerifyOffset(verifier, VT_BOUNDS) &&
+           verifier.VerifyTable(bounds()) &&
+           verifier.EndTable();
+  }
+};
+
+struct NodePartsBuilder {
+  flatbuffers::FlatBufferBuilder &fbb_;
+  flatbuffers::uoffset_t start_;
+  void add_name(flatbuffers::Offset<flatbuffers::String> name) {
+    fbb_.AddOffset(NodeParts::VT_NAME, name);
+  }
+  void add_parts(flatbuffers::Offset<flatbuffers::Vector<uint32_t>> parts) {
+    fbb_.AddOffset(NodeParts::VT_PARTS, parts);
+  }
+  void add_bounds(flatbuffers::Offset<Bounds> bounds) {
+    fbb_.AddOffset(NodeParts::VT_BOUNDS, bounds);
+  }
+  explicit NodePartsBuilder(flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  NodePartsBuilder &operator=(const NodePartsBuilder &);
+  flatbuffers::Offset<NodeParts> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = flatbuffers::Offset<NodeParts>(end);
+    return o;
+  }
+};
+
+inline flatbuffers::Offset<NodeParts> CreateNodeParts(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    flatbuffers::Offset<flatbuffers::String> name = 0,
+    flatbuffers::Offset<flatbuffers::Vector<uint32_t>> parts = 0,
+    flatbuffers::Offset<Bounds> bounds = 0) {
+  NodePartsBuilder builder_(_fbb);
+  builder_.add_bounds(bounds);
+  builder_.add_parts(parts);
+  builder_.add_name(name);
+  return builder_.Finish();
+}
+
+inline flatbuffers::Offset<NodeParts> CreateNodePartsDirect(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    const char *name = nullptr,
+    const std::vector<uint32_t> *parts = nullptr,
+    flatbuffers::Offset<Bounds> bounds = 0) {
+  return service::mesh::schema::CreateNodeParts(
+      _fbb,
+      name ? _fbb.CreateString(name) : 0,
+      parts ? _fbb.CreateVector<uint32_t>(*parts) : 0,
+      bounds);
+}
+
+struct Skeleton FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
+  enum {
+    VT_JOINT_NAMES = 4,
+    VT_JOINT_PARENTS = 6,
+    VT_INVERSE_BIND_MATRICES = 8,
+    VT_ROOT = 10
+  };
+  const flatbuffers::Vector<flatbuffers::Offset<flatbuffers::String>> *joint_names() const {
+    return GetPointer<const flatbuffers::Vector<flatbuffers::Offset<flatbuffers::String>> *>(VT_JOINT_NAMES);
+  }
+  const flatbuffers::Vector<int32_t> *joint_parents() const {
+    return GetPointer<const flatbuffers::Vector<int32_t> *>(VT_JOINT_PARENTS);
+  }
+  const flatbuffers::Vector<float> *inverse_bind_matrices() const {
+    return GetPointer<const flatbuffers::Vector<float> *>(VT_INVERSE_BIND_MATRICES);
+  }
+  int32_t root() const {
+    return GetField<int32_t>(VT_ROOT, -1);
+  }
+  bool Verify(flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_JOINT_NAMES) &&
+           verifier.VerifyVector(joint_names()) &&
+           verifier.VerifyVectorOfStrings(joint_names()) &&
+           VerifyOffset(verifier, VT_JOINT_PARENTS) &&
+           verifier.VerifyVector(joint_parents()) &&
+           VerifyOffset(verifier, VT_INVERSE_BIND_MATRICES) &&
+           verifier.VerifyVector(inverse_bind_matrices()) &&
+           VerifyField<int32_t>(verifier, VT_ROOT) &&
+           verifier.EndTable();
+  }
+};
+
+struct SkeletonBuilder {
+  flatbuffers::FlatBufferBuilder &fbb_;
+  flatbuffers::uoffset_t start_;
+  void add_joint_names(flatbuffers::Offset<flatbuffers::Vector<flatbuffers::Offset<flatbuffers::String>>> joint_names) {
+    fbb_.AddOffset(Skeleton::VT_JOINT_NAMES, joint_names);
+  }
+  void add_joint_parents(flatbuffers::Offset<flatbuffers::Vector<int32_t>> joint_parents) {
+    fbb_.AddOffset(Skeleton::VT_JOINT_PARENTS, joint_parents);
+  }
+  void add_inverse_bind_matrices(flatbuffers::Offset<flatbuffers::Vector<float>> inverse_bind_matrices) {
+    fbb_.AddOffset(Skeleton::VT_INVERSE_BIND_MATRICES, inverse_bind_matrices);
+  }
+  void add_root(int32_t root) {
+    fbb_.AddElement<int32_t>(Skeleton::VT_ROOT, root, -1);
+  }
+  explicit SkeletonBuilder(flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  SkeletonBuilder &operator=(const SkeletonBuilder &);
+  flatbuffers::Offset<Skeleton> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = flatbuffers::Offset<Skeleton>(end);
+    return o;
+  }
+};
+
+inline flatbuffers::Offset<Skeleton> CreateSkeleton(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    flatbuffers::Offset<flatbuffers::Vector<flatbuffers::Offset<flatbuffers::String>>> joint_names = 0,
+    flatbuffers::Offset<flatbuffers::Vector<int32_t>> joint_parents = 0,
+    flatbuffers::Offset<flatbuffers::Vector<float>> inverse_bind_matrices = 0,
+    int32_t root = -1) {
+  SkeletonBuilder builder_(_fbb);
+  builder_.add_root(root);
+  builder_.add_inverse_bind_matrices(inverse_bind_matrices);
+  builder_.add_joint_parents(joint_parents);
+  builder_.add_joint_names(joint_names);
+  return builder_.Finish();
+}
+
+inline flatbuffers::Offset<Skeleton> CreateSkeletonDirect(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    const std::vector<flatbuffers::Offset<flatbuffers::String>> *joint_names = nullptr,
+    const std::vector<int32_t> *joint_parents = nullptr,
+    const std::vector<float> *inverse_bind_matrices = nullptr,
+    int32_t root = -1) {
+  return service::mesh::schema::CreateSkeleton(
+      _fbb,
+      joint_names ? _fbb.CreateVector<flatbuffers::Offset<flatbuffers::String>>(*joint_names) : 0,
+      joint_parents ? _fbb.CreateVector<int32_t>(*joint_parents) : 0,
+      inverse_bind_matrices ? _fbb.CreateVector<float>(*inverse_bind_matrices) : 0,
+      root);
+}
+
+struct Vector3 FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
+  enum {
+    VT_X = 4,
+    VT_Y = 6,
+    VT_Z = 8
+  };
+  float x() const {
+    return GetField<float>(VT_X, 0.0f);
+  }
+  float y() const {
+    return GetField<float>(VT_Y, 0.0f);
+  }
+  float z() const {
+    return GetField<float>(VT_Z, 0.0f);
+  }
+  bool Verify(flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyField<float>(verifier, VT_X) &&
+           VerifyField<float>(verifier, VT_Y) &&
+           VerifyField<float>(verifier, VT_Z) &&
+           verifier.EndTable();
+  }
+};
+
+struct Vector3Builder {
+  flatbuffers::FlatBufferBuilder &fbb_;
+  flatbuffers::uoffset_t start_;
+  void add_x(float x) {
+    fbb_.AddElement<float>(Vector3::VT_X, x, 0.0f);
+  }
+  void add_y(float y) {
+    fbb_.AddElement<float>(Vector3::VT_Y, y, 0.0f);
+  }
+  void add_z(float z) {
+    fbb_.AddElement<float>(Vector3::VT_Z, z, 0.0f);
+  }
+  explicit Vector3Builder(flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  Vector3Builder &operator=(const Vector3Builder &);
+  flatbuffers::Offset<Vector3> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = flatbuffers::Offset<Vector3>(end);
+    return o;
+  }
+};
+
+inline flatbuffers::Offset<Vector3> CreateVector3(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    float x = 0.0f,
+    float y = 0.0f,
+    float z = 0.0f) {
+  Vector3Builder builder_(_fbb);
+  builder_.add_z(z);
+  builder_.add_y(y);
+  builder_.add_x(x);
+  return builder_.Finish();
+}
+
+struct Bounds FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
+  enum {
+    VT_MIN = 4,
+    VT_MAX = 6,
+    VT_SPHERE_CENTER = 8,
+    VT_SPHERE_RADIUS = 10
+  };
+  const Vector3 *min() const {
+    return GetPointer<const Vector3 *>(VT_MIN);
+  }
+  const Vector3 *max() const {
+    return GetPointer<const Vector3 *>(VT_MAX);
+  }
+  const Vector3 *sphere_center() const {
+    return GetPointer<const Vector3 *>(VT_SPHERE_CENTER);
+  }
+  float sphere_radius() const {
+    return GetField<float>(VT_SPHERE_RADIUS, 0.0f);
+  }
+  bool Verify(flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_MIN) &&
+           verifier.VerifyTable(min()) &&
+           VerifyOffset(verifier, VT_MAX) &&
+           verifier.VerifyTable(max()) &&
+           VerifyOffset(verifier, VT_SPHERE_CENTER) &&
+           verifier.VerifyTable(sphere_center()) &&
+           VerifyField<float>(verifier, VT_SPHERE_RADIUS) &&
+           verifier.EndTable();
+  }
+};
+
+struct BoundsBuilder {
+  flatbuffers::FlatBufferBuilder &fbb_;
+  flatbuffers::uoffset_t start_;
+  void add_min(flatbuffers::Offset<Vector3> min) {
+    fbb_.AddOffset(Bounds::VT_MIN, min);
+  }
+  void add_max(flatbuffers::Offset<Vector3> max) {
+    fbb_.AddOffset(Bounds::VT_MAX, max);
+  }
+  void add_sphere_center(flatbuffers::Offset<Vector3> sphere_center) {
+    fbb_.AddOffset(Bounds::VT_SPHERE_CENTER, sphere_center);
+  }
+  void add_sphere_radius(float sphere_radius) {
+    fbb_.AddElement<float>(Bounds::VT_SPHERE_RADIUS, sphere_radius, 0.0f);
+  }
+  explicit BoundsBuilder(flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  BoundsBuilder &operator=(const BoundsBuilder &);
+  flatbuffers::Offset<Bounds> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = flatbuffers::Offset<Bounds>(end);
+    return o;
+  }
+};
+
+inline flatbuffers::Offset<Bounds> CreateBounds(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    flatbuffers::Offset<Vector3> min = 0,
+    flatbuffers::Offset<Vector3> max = 0,
+    flatbuffers::Offset<Vector3> sphere_center = 0,
+    float sphere_radius = 0.0f) {
+  BoundsBuilder builder_(_fbb);
+  builder_.add_sphere_radius(sphere_radius);
+  builder_.add_sphere_center(sphere_center);
+  builder_.add_max(max);
+  builder_.add_min(min);
+  return builder_.Finish();
+}
+
+struct OptimizationStats FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
+  enum {
+    VT_ACMR_BEFORE = 4,
+    VT_ATVR_BEFORE = 6,
+    VT_ACMR_AFTER = 8,
+    VT_ATVR_AFTER = 10
+  };
+  float acmr_before() const {
+    return GetField<float>(VT_ACMR_BEFORE, 0.0f);
+  }
+  float atvr_before() const {
+    return GetField<float>(VT_ATVR_BEFORE, 0.0f);
+  }
+  float acmr_after() const {
+    return GetField<float>(VT_ACMR_AFTER, 0.0f);
+  }
+  float atvr_after() const {
+    return GetField<float>(VT_ATVR_AFTER, 0.0f);
+  }
+  bool Verify(flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyField<float>(verifier, VT_ACMR_BEFORE) &&
+           VerifyField<float>(verifier, VT_ATVR_BEFORE) &&
+           VerifyField<float>(verifier, VT_ACMR_AFTER) &&
+           VerifyField<float>(verifier, VT_ATVR_AFTER) &&
+           verifier.EndTable();
+  }
+};
+
+struct OptimizationStatsBuilder {
+  flatbuffers::FlatBufferBuilder &fbb_;
+  flatbuffers::uoffset_t start_;
+  void add_acmr_before(float acmr_before) {
+    fbb_.AddElement<float>(OptimizationStats::VT_ACMR_BEFORE, acmr_before, 0.0f);
+  }
+  void add_atvr_before(float atvr_before) {
+    fbb_.AddElement<float>(OptimizationStats::VT_ATVR_BEFORE, atvr_before, 0.0f);
+  }
+  void add_acmr_after(float acmr_after) {
+    fbb_.AddElement<float>(OptimizationStats::VT_ACMR_AFTER, acmr_after, 0.0f);
+  }
+  void add_atvr_after(float atvr_after) {
+    fbb_.AddElement<float>(OptimizationStats::VT_ATVR_AFTER, atvr_after, 0.0f);
+  }
+  explicit OptimizationStatsBuilder(flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  OptimizationStatsBuilder &operator=(const OptimizationStatsBuilder &);
+  flatbuffers::Offset<OptimizationStats> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = flatbuffers::Offset<OptimizationStats>(end);
+    return o;
+  }
+};
+
+inline flatbuffers::Offset<OptimizationStats> CreateOptimizationStats(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    float acmr_before = 0.0f,
+    float atvr_before = 0.0f,
+    float acmr_after = 0.0f,
+    float atvr_after = 0.0f) {
+  OptimizationStatsBuilder builder_(_fbb);
+  builder_.add_atvr_after(atvr_after);
+  builder_.add_acmr_after(acmr_after);
+  builder_.add_atvr_before(atvr_before);
+  builder_.add_acmr_before(acmr_before);
+  return builder_.Finish();
+}
+
+struct MeshLodPart FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
+  enum {
+    VT_INDEX_START = 4,
+    VT_INDEX_COUNT = 6,
+    VT_ERROR = 8
+  };
+  uint32_t index_start() const {
+    return GetField<uint32_t>(VT_INDEX_START, 0);
+  }
+  uint32_t index_count() const {
+    return GetField<uint32_t>(VT_INDEX_COUNT, 0);
+  }
+  float error() const {
+    return GetField<float>(VT_ERROR, 0.0f);
+  }
+  bool Verify(flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyField<uint32_t>(verifier, VT_INDEX_START) &&
+           VerifyField<uint32_t>(verifier, VT_INDEX_COUNT) &&
+           VerifyField<float>(verifier, VT_ERROR) &&
+           verifier.EndTable();
+  }
+};
+
+struct MeshLodPartBuilder {
+  flatbuffers::FlatBufferBuilder &fbb_;
+  flatbuffers::uoffset_t start_;
+  void add_index_start(uint32_t index_start) {
+    fbb_.AddElement<uint32_t>(MeshLodPart::VT_INDEX_START, index_start, 0);
+  }
+  void add_index_count(uint32_t index_count) {
+    fbb_.AddElement<uint32_t>(MeshLodPart::VT_INDEX_COUNT, index_count, 0);
+  }
+  void add_error(float error) {
+    fbb_.AddElement<float>(MeshLodPart::VT_ERROR, error, 0.0f);
+  }
+  explicit MeshLodPartBuilder(flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  MeshLodPartBuilder &operator=(const MeshLodPartBuilder &);
+  flatbuffers::Offset<MeshLodPart> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = flatbuffers::Offset<MeshLodPart>(end);
+    return o;
+  }
+};
+
+inline flatbuffers::Offset<MeshLodPart> CreateMeshLodPart(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    uint32_t index_start = 0,
+    uint32_t index_count = 0,
+    float error = 0.0f) {
+  MeshLodPartBuilder builder_(_fbb);
+  builder_.add_error(error);
+  builder_.add_index_count(index_count);
+  builder_.add_index_start(index_start);
+  return builder_.Finish();
+}
+
+struct MeshLod FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
+  enum {
+    VT_RATIO = 4,
+    VT_MAX_ERROR = 6,
+    VT_ERROR = 8,
+    VT_PARTS = 10
+  };
+  float ratio() const {
+    return GetField<float>(VT_RATIO, 0.0f);
+  }
+  float max_error() const {
+    return GetField<float>(VT_MAX_ERROR, 0.0f);
+  }
+  float error() const {
+    return GetField<float>(VT_ERROR, 0.0f);
+  }
+  const flatbuffers::Vector<flatbuffers::Offset<MeshLodPart>> *parts() const {
+    return GetPointer<const flatbuffers::Vector<flatbuffers::Offset<MeshLodPart>> *>(VT_PARTS);
+  }
+  bool Verify(flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyField<float>(verifier, VT_RATIO) &&
+           VerifyField<float>(verifier, VT_MAX_ERROR) &&
+           VerifyField<float>(verifier, VT_ERROR) &&
+           VerifyOffset(verifier, VT_PARTS) &&
+           verifier.VerifyVector(parts()) &&
+           verifier.VerifyVectorOfTables(parts()) &&
+           verifier.EndTable();
+  }
+};
+
+struct MeshLodBuilder {
+  flatbuffers::FlatBufferBuilder &fbb_;
+  flatbuffers::uoffset_t start_;
+  void add_ratio(float ratio) {
+    fbb_.AddElement<float>(MeshLod::VT_RATIO, ratio, 0.0f);
+  }
+  void add_max_error(float max_error) {
+    fbb_.AddElement<float>(MeshLod::VT_MAX_ERROR, max_error, 0.0f);
+  }
+  void add_error(float error) {
+    fbb_.AddElement<float>(MeshLod::VT_ERROR, error, 0.0f);
+  }
+  void add_parts(flatbuffers::Offset<flatbuffers::Vector<flatbuffers::Offset<MeshLodPart>>> parts) {
+    fbb_.AddOffset(MeshLod::VT_PARTS, parts);
+  }
+  explicit MeshLodBuilder(flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  MeshLodBuilder &operator=(const MeshLodBuilder &);
+  flatbuffers::Offset<MeshLod> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = flatbuffers::Offset<MeshLod>(end);
+    return o;
+  }
+};
+
+inline flatbuffers::Offset<MeshLod> CreateMeshLod(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    float ratio = 0.0f,
+    float max_error = 0.0f,
+    float error = 0.0f,
+    flatbuffers::Offset<flatbuffers::Vector<flatbuffers::Offset<MeshLodPart>>> parts = 0) {
+  MeshLodBuilder builder_(_fbb);
+  builder_.add_parts(parts);
+  builder_.add_error(error);
+  builder_.add_max_error(max_error);
+  builder_.add_ratio(ratio);
+  return builder_.Finish();
+}
+
+inline flatbuffers::Offset<MeshLod> CreateMeshLodDirect(
+    flatbuffers::FlatBufferBuilder &_fbb,
+    float ratio = 0.0f,
+    float max_error = 0.0f,
+    float error = 0.0f,
+    const std::vector<flatbuffers::Offset<MeshLodPart>> *parts = nullptr) {
+  return service::mesh::schema::CreateMeshLod(
+      _fbb,
+      ratio,
+      max_error,
+      error,
+      parts ? _fbb.CreateVector<flatbuffers::Offset<MeshLodPart>>(*parts) : 0);
 }
 
 struct Mesh FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
@@ -474,7 +1885,13 @@ struct Mesh FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
     VT_SKINNING_DATA = 14,
     VT_ANIMATIONS = 16,
     VT_BOUNDING_MIN = 18,
-    VT_BOUNDING_MAX = 20
+    VT_BOUNDING_MAX = 20,
+    VT_NODE_PARTS = 22,
+    VT_SKELETON = 24,
+    VT_SKINNING_INFLUENCES = 26,
+    VT_OPTIMIZATION = 28,
+    VT_LODS = 30,
+    VT_BOUNDS = 32
   };
   const flatbuffers::String *name() const {
     return GetPointer<const flatbuffers::String *>(VT_NAME);
@@ -497,11 +1914,29 @@ struct Mesh FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
   const flatbuffers::Vector<flatbuffers::Offset<Animation>> *animations() const {
     return GetPointer<const flatbuffers::Vector<flatbuffers::Offset<Animation>> *>(VT_ANIMATIONS);
   }
-  const flatbuffers::Vector<float> *bounding_min() const {
-    return GetPointer<const flatbuffers::Vector<float> *>(VT_BOUNDING_MIN);
+  const Vector3 *bounding_min() const {
+    return GetPointer<const Vector3 *>(VT_BOUNDING_MIN);
   }
-  const flatbuffers::Vector<float> *bounding_max() const {
-    return GetPointer<const flatbuffers::Vector<float> *>(VT_BOUNDING_MAX);
+  const Vector3 *bounding_max() const {
+    return GetPointer<const Vector3 *>(VT_BOUNDING_MAX);
+  }
+  const flatbuffers::Vector<flatbuffers::Offset<NodeParts>> *node_parts() const {
+    return GetPointer<const flatbuffers::Vector<flatbuffers::Offset<NodeParts>> *>(VT_NODE_PARTS);
+  }
+  const Skeleton *skeleton() const {
+    return GetPointer<const Skeleton *>(VT_SKELETON);
+  }
+  uint32_t skinning_influences() const {
+    return GetField<uint32_t>(VT_SKINNING_INFLUENCES, 0);
+  }
+  const OptimizationStats *optimization() const {
+    return GetPointer<const OptimizationStats *>(VT_OPTIMIZATION);
+  }
+  const flatbuffers::Vector<flatbuffers::Offset<MeshLod>> *lods() const {
+    return GetPointer<const flatbuffers::Vector<flatbuffers::Offset<MeshLod>> *>(VT_LODS);
+  }
+  const Bounds *bounds() const {
+    return GetPointer<const Bounds *>(VT_BOUNDS);
   }
   bool Verify(flatbuffers::Verifier &verifier) const {
     return VerifyTableStart(verifier) &&
@@ -524,9 +1959,22 @@ struct Mesh FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
            verifier.VerifyVector(animations()) &&
            verifier.VerifyVectorOfTables(animations()) &&
            VerifyOffset(verifier, VT_BOUNDING_MIN) &&
-           verifier.VerifyVector(bounding_min()) &&
+           verifier.VerifyTable(bounding_min()) &&
            VerifyOffset(verifier, VT_BOUNDING_MAX) &&
-           verifier.VerifyVector(bounding_max()) &&
+           verifier.VerifyTable(bounding_max()) &&
+           VerifyOffset(verifier, VT_NODE_PARTS) &&
+           verifier.VerifyVector(node_parts()) &&
+           verifier.VerifyVectorOfTables(node_parts()) &&
+           VerifyOffset(verifier, VT_SKELETON) &&
+           verifier.VerifyTable(skeleton()) &&
+           VerifyField<uint32_t>(verifier, VT_SKINNING_INFLUENCES) &&
+           VerifyOffset(verifier, VT_OPTIMIZATION) &&
+           verifier.VerifyTable(optimization()) &&
+           VerifyOffset(verifier, VT_LODS) &&
+           verifier.VerifyVector(lods()) &&
+           verifier.VerifyVectorOfTables(lods()) &&
+           VerifyOffset(verifier, VT_BOUNDS) &&
+           verifier.VerifyTable(bounds()) &&
            verifier.EndTable();
   }
 };
@@ -555,12 +2003,30 @@ struct MeshBuilder {
   void add_animations(flatbuffers::Offset<flatbuffers::Vector<flatbuffers::Offset<Animation>>> animations) {
     fbb_.AddOffset(Mesh::VT_ANIMATIONS, animations);
   }
-  void add_bounding_min(flatbuffers::Offset<flatbuffers::Vector<float>> bounding_min) {
+  void add_bounding_min(flatbuffers::Offset<Vector3> bounding_min) {
     fbb_.AddOffset(Mesh::VT_BOUNDING_MIN, bounding_min);
   }
-  void add_bounding_max(flatbuffers::Offset<flatbuffers::Vector<float>> bounding_max) {
+  void add_bounding_max(flatbuffers::Offset<Vector3> bounding_max) {
     fbb_.AddOffset(Mesh::VT_BOUNDING_MAX, bounding_max);
   }
+  void add_node_parts(flatbuffers::Offset<flatbuffers::Vector<flatbuffers::Offset<NodeParts>>> node_parts) {
+    fbb_.AddOffset(Mesh::VT_NODE_PARTS, node_parts);
+  }
+  void add_skeleton(flatbuffers::Offset<Skeleton> skeleton) {
+    fbb_.AddOffset(Mesh::VT_SKELETON, skeleton);
+  }
+  void add_skinning_influences(uint32_t skinning_influences) {
+    fbb_.AddElement<uint32_t>(Mesh::VT_SKINNING_INFLUENCES, skinning_influences, 0);
+  }
+  void add_optimization(flatbuffers::Offset<OptimizationStats> optimization) {
+    fbb_.AddOffset(Mesh::VT_OPTIMIZATION, optimization);
+  }
+  void add_lods(flatbuffers::Offset<flatbuffers::Vector<flatbuffers::Offset<MeshLod>>> lods) {
+    fbb_.AddOffset(Mesh::VT_LODS, lods);
+  }
+  void add_bounds(flatbuffers::Offset<Bounds> bounds) {
+    fbb_.AddOffset(Mesh::VT_BOUNDS, bounds);
+  }
   explicit MeshBuilder(flatbuffers::FlatBufferBuilder &_fbb)
         : fbb_(_fbb) {
     start_ = fbb_.StartTable();
@@ -582,9 +2048,21 @@ inline flatbuffers::Offset<Mesh> CreateMesh(
     flatbuffers::Offset<flatbuffers::Vector<flatbuffers::Offset<MeshStream>>> streams = 0,
     flatbuffers::Offset<flatbuffers::Vector<uint8_t>> skinning_data = 0,
     flatbuffers::Offset<flatbuffers::Vector<flatbuffers::Offset<Animation>>> animations = 0,
-    flatbuffers::Offset<flatbuffers::Vector<float>> bounding_min = 0,
-    flatbuffers::Offset<flatbuffers::Vector<float>> bounding_max = 0) {
+    flatbuffers::Offset<Vector3> bounding_min = 0,
+    flatbuffers::Offset<Vector3> bounding_max = 0,
+    flatbuffers::Offset<flatbuffers::Vector<flatbuffers::Offset<NodeParts>>> node_parts = 0,
+    flatbuffers::Offset<Skeleton> skeleton = 0,
+    uint32_t skinning_influences = 0,
+    flatbuffers::Offset<OptimizationStats> optimization = 0,
+    flatbuffers::Offset<flatbuffers::Vector<flatbuffers::Offset<MeshLod>>> lods = 0,
+    flatbuffers::Offset<Bounds> bounds = 0) {
   MeshBuilder builder_(_fbb);
+  builder_.add_bounds(bounds);
+  builder_.add_lods(lods);
+  builder_.add_optimization(optimization);
+  builder_.add_skinning_influences(skinning_influences);
+  builder_.add_skeleton(skeleton);
+  builder_.add_node_parts(node_parts);
   builder_.add_bounding_max(bounding_max);
   builder_.add_bounding_min(bounding_min);
   builder_.add_animations(animations);
@@ -606,8 +2084,14 @@ inline flatbuffers::Offset<Mesh> CreateMeshDirect(
     const std::vector<flatbuffers::Offset<MeshStream>> *streams = nullptr,
     const std::vector<uint8_t> *skinning_data = nullptr,
     const std::vector<flatbuffers::Offset<Animation>> *animations = nullptr,
-    const std::vector<float> *bounding_min = nullptr,
-    const std::vector<float> *bounding_max = nullptr) {
+    flatbuffers::Offset<Vector3> bounding_min = 0,
+    flatbuffers::Offset<Vector3> bounding_max = 0,
+    const std::vector<flatbuffers::Offset<NodeParts>> *node_parts = nullptr,
+    flatbuffers::Offset<Skeleton> skeleton = 0,
+    uint32_t skinning_influences = 0,
+    flatbuffers::Offset<OptimizationStats> optimization = 0,
+    const std::vector<flatbuffers::Offset<MeshLod>> *lods = nullptr,
+    flatbuffers::Offset<Bounds> bounds = 0) {
   return service::mesh::schema::CreateMesh(
       _fbb,
       name ? _fbb.CreateString(name) : 0,
@@ -617,8 +2101,14 @@ inline flatbuffers::Offset<Mesh> CreateMeshDirect(
       streams ? _fbb.CreateVector<flatbuffers::Offset<MeshStream>>(*streams) : 0,
       skinning_data ? _fbb.CreateVector<uint8_t>(*skinning_data) : 0,
       animations ? _fbb.CreateVector<flatbuffers::Offset<Animation>>(*animations) : 0,
-      bounding_min ? _fbb.CreateVector<float>(*bounding_min) : 0,
-      bounding_max ? _fbb.CreateVector<float>(*bounding_max) : 0);
+      bounding_min,
+      bounding_max,
+      node_parts ? _fbb.CreateVector<flatbuffers::Offset<NodeParts>>(*node_parts) : 0,
+      skeleton,
+      skinning_influences,
+      optimization,
+      lods ? _fbb.CreateVector<flatbuffers::Offset<MeshLod>>(*lods) : 0,
+      bounds);
 }
 
 struct Manifest FLATBUFFERS_FINAL_CLASS : private flatbuffers::Table {
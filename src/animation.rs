@@ -2,11 +2,13 @@
 #![allow(dead_code)]
 
 //use crate::GltfBuffers;
+use crate::Error;
 use crate::GltfData;
 use crate::GltfIndex;
 use crate::GltfModel;
 use crate::GltfNodeRef;
 use crate::Matrix4;
+use crate::Result;
 use crate::Vector4;
 use std::collections::HashMap;
 use std::path::Path;
@@ -35,6 +37,21 @@ pub struct GltfSkin {
     pub joints: Vec<GltfJointNode>,
 }
 
+/// A joint of the skeleton merged from every skin in the model.
+#[derive(Debug)]
+pub struct GltfSkeletonJoint {
+    pub node_index: GltfIndex,
+    pub name: String,
+    pub parent: Option<usize>, // index into the skeleton joints
+    pub inverse_bind_matrix: Matrix4,
+}
+
+#[derive(Debug, Default)]
+pub struct GltfSkeleton {
+    pub joints: Vec<GltfSkeletonJoint>,
+    pub root: Option<usize>, // index into the skeleton joints
+}
+
 impl GltfSkin {
     pub fn from_gltf(
         skin_ref: &gltf::Skin<'_>,
@@ -186,7 +203,8 @@ impl GltfAnimationSampler {
     pub fn from_gltf(
         sampler_ref: &gltf::animation::Sampler<'_>,
         data: &GltfData,
-    ) -> GltfAnimationSampler {
+    ) -> Result<GltfAnimationSampler> {
+        use gltf::accessor::{DataType, Dimensions};
         use gltf::animation::Interpolation;
         let interpolation = match sampler_ref.interpolation() {
             Interpolation::Linear => GltfInterpolationType::Linear,
@@ -197,55 +215,113 @@ impl GltfAnimationSampler {
 
         // Read sampler input time values
         let input = sampler_ref.input();
-        assert_eq!(input.data_type(), gltf::accessor::DataType::F32);
-        let buffer_view = input.view();
-        let buffer = buffer_view.buffer();
-
         let inputs: Vec<f32> = match (input.data_type(), input.dimensions()) {
-            (gltf::accessor::DataType::F32, gltf::accessor::Dimensions::Scalar) => {
-                let buffer_index = input.view().buffer().index();
-                let buffer_data = data.buffers[buffer_index].0.as_slice();
-                let iter = gltf::accessor::Iter::<f32>::new(input, buffer_data);
-                iter.collect()
+            (DataType::F32, Dimensions::Scalar) => {
+                let buffer_data = accessor_buffer(&input, data)?;
+                gltf::accessor::Iter::<f32>::new(input, buffer_data).collect()
+            }
+            (data_type, dimensions) => {
+                return Err(Error::invalid_input(format!(
+                    "animation sampler input of {:?} {:?} is not supported",
+                    data_type, dimensions
+                )))
             }
-            _ => unimplemented!(),
         };
 
-        // Read sampler output T/R/S values
+        // Read sampler output T/R/S values, normalized integers are only valid
+        // for rotations and weights
         let output = sampler_ref.output();
-        let buffer_view = output.view();
-        let buffer = buffer_view.buffer();
-
+        let buffer_data = accessor_buffer(&output, data)?;
         let outputs: Vec<Vector4> = match (output.data_type(), output.dimensions()) {
-            (gltf::accessor::DataType::F32, gltf::accessor::Dimensions::Scalar) => {
-                let buffer_index = output.view().buffer().index();
-                let buffer_data = data.buffers[buffer_index].0.as_slice();
+            (DataType::F32, Dimensions::Scalar) => {
                 let iter = gltf::accessor::Iter::<f32>::new(output, buffer_data);
                 iter.map(|x| Vector4::new(x, x, x, x)).collect()
             }
-            (gltf::accessor::DataType::F32, gltf::accessor::Dimensions::Vec3) => {
-                let buffer_index = output.view().buffer().index();
-                let buffer_data = data.buffers[buffer_index].0.as_slice();
+            (DataType::F32, Dimensions::Vec3) => {
                 let iter = gltf::accessor::Iter::<[f32; 3]>::new(output, buffer_data);
                 iter.map(|[x, y, z]| Vector4::new(x, y, z, 0.0)).collect()
             }
-            (gltf::accessor::DataType::F32, gltf::accessor::Dimensions::Vec4) => {
-                let buffer_index = output.view().buffer().index();
-                let buffer_data = data.buffers[buffer_index].0.as_slice();
+            (DataType::F32, Dimensions::Vec4) => {
                 let iter = gltf::accessor::Iter::<[f32; 4]>::new(output, buffer_data);
                 iter.map(|[x, y, z, w]| Vector4::new(x, y, z, w)).collect()
             }
-            _ => unimplemented!(),
+            (DataType::I8, Dimensions::Vec4) => {
+                let iter = gltf::accessor::Iter::<[i8; 4]>::new(output, buffer_data);
+                iter.map(|value| normalized(value, |c| f32::from(c) / 127.0))
+                    .collect()
+            }
+            (DataType::U8, Dimensions::Vec4) => {
+                let iter = gltf::accessor::Iter::<[u8; 4]>::new(output, buffer_data);
+                iter.map(|value| normalized(value, |c| f32::from(c) / 255.0))
+                    .collect()
+            }
+            (DataType::I16, Dimensions::Vec4) => {
+                let iter = gltf::accessor::Iter::<[i16; 4]>::new(output, buffer_data);
+                iter.map(|value| normalized(value, |c| f32::from(c) / 32767.0))
+                    .collect()
+            }
+            (DataType::U16, Dimensions::Vec4) => {
+                let iter = gltf::accessor::Iter::<[u16; 4]>::new(output, buffer_data);
+                iter.map(|value| normalized(value, |c| f32::from(c) / 65535.0))
+                    .collect()
+            }
+            (DataType::I8, Dimensions::Scalar) => {
+                let iter = gltf::accessor::Iter::<i8>::new(output, buffer_data);
+                iter.map(|x| normalized([x; 4], |c| f32::from(c) / 127.0))
+                    .collect()
+            }
+            (DataType::U8, Dimensions::Scalar) => {
+                let iter = gltf::accessor::Iter::<u8>::new(output, buffer_data);
+                iter.map(|x| normalized([x; 4], |c| f32::from(c) / 255.0))
+                    .collect()
+            }
+            (DataType::I16, Dimensions::Scalar) => {
+                let iter = gltf::accessor::Iter::<i16>::new(output, buffer_data);
+                iter.map(|x| normalized([x; 4], |c| f32::from(c) / 32767.0))
+                    .collect()
+            }
+            (DataType::U16, Dimensions::Scalar) => {
+                let iter = gltf::accessor::Iter::<u16>::new(output, buffer_data);
+                iter.map(|x| normalized([x; 4], |c| f32::from(c) / 65535.0))
+                    .collect()
+            }
+            (data_type, dimensions) => {
+                return Err(Error::invalid_input(format!(
+                    "animation sampler output of {:?} {:?} is not supported",
+                    data_type, dimensions
+                )))
+            }
         };
 
-        GltfAnimationSampler {
+        Ok(GltfAnimationSampler {
             interpolation_type: interpolation,
             inputs,
             outputs,
-        }
+        })
     }
 }
 
+/// Contents of the buffer an accessor reads from.
+fn accessor_buffer<'a>(accessor: &gltf::Accessor<'_>, data: &'a GltfData) -> Result<&'a [u8]> {
+    let buffer_index = accessor.view().buffer().index();
+    data.buffers
+        .get(buffer_index)
+        .map(|buffer| buffer.0.as_slice())
+        .ok_or_else(|| {
+            Error::invalid_input(format!(
+                "accessor {} refers to missing buffer {}",
+                accessor.index(),
+                buffer_index
+            ))
+        })
+}
+
+/// Dequantize a normalized integer vector, signed components are clamped to -1.
+fn normalized<T: Copy>(value: [T; 4], normalize: fn(T) -> f32) -> Vector4 {
+    let component = |index: usize| normalize(value[index]).max(-1.0);
+    Vector4::new(component(0), component(1), component(2), component(3))
+}
+
 #[derive(Debug)]
 pub struct GltfAnimationChannel {
     pub node_ref: GltfNodeRef,
@@ -276,7 +352,7 @@ impl GltfAnimation {
         data: &GltfData,
         _base_path: &Path,
         model: &GltfModel,
-    ) -> Rc<GltfAnimation> {
+    ) -> Result<Rc<GltfAnimation>> {
         use std::f32;
 
         let _buffers = &data.buffers;
@@ -293,7 +369,7 @@ impl GltfAnimation {
         let samplers: Vec<GltfAnimationSampler> = animation_ref
             .samplers()
             .map(|sampler_ref| GltfAnimationSampler::from_gltf(&sampler_ref, data))
-            .collect();
+            .collect::<Result<_>>()?;
 
         for sampler in &samplers {
             for input in &sampler.inputs {
@@ -335,20 +411,20 @@ impl GltfAnimation {
                     match channel_ref.target().property() {
                         gltf::animation::Property::Translation => {
                             target_channel.translation_sampler = Some(
-                                GltfAnimationSampler::from_gltf(&channel_ref.sampler(), data),
+                                GltfAnimationSampler::from_gltf(&channel_ref.sampler(), data)?,
                             )
                         }
                         gltf::animation::Property::Rotation => {
                             target_channel.rotation_sampler = Some(GltfAnimationSampler::from_gltf(
                                 &channel_ref.sampler(),
                                 data,
-                            ))
+                            )?)
                         }
                         gltf::animation::Property::Scale => {
                             target_channel.scale_sampler = Some(GltfAnimationSampler::from_gltf(
                                 &channel_ref.sampler(),
                                 data,
-                            ))
+                            )?)
                         }
                         _ => println!("Unimplemented: Found morph target channel"),
                     }
@@ -366,7 +442,7 @@ impl GltfAnimation {
             node_to_channel.insert(channel.node_ref.borrow().node_index, i);
         }
 
-        Rc::new(GltfAnimation {
+        Ok(Rc::new(GltfAnimation {
             index: animation_ref.index(),
             name: animation_name,
             samplers,
@@ -374,6 +450,42 @@ impl GltfAnimation {
             node_to_channel,
             start,
             end,
-        })
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalized_rotation_gltf;
+    use crate::GltfOptions;
+    use crate::GltfSource;
+    use crate::UriResolver;
+
+    #[test]
+    fn normalized_rotations_are_dequantized() {
+        let blobs = HashMap::new();
+        let data = GltfSource::from_slice(
+            &normalized_rotation_gltf(),
+            &UriResolver::Memory(&blobs),
+            GltfOptions::default(),
+        )
+        .and_then(GltfSource::import)
+        .unwrap();
+
+        let animation = data.document.animations().next().unwrap();
+        let expected = [
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+            Vector4::new(0.0, -1.0, 0.0, 0.0),
+        ];
+        for sampler_ref in animation.samplers() {
+            let sampler = GltfAnimationSampler::from_gltf(&sampler_ref, &data).unwrap();
+            assert_eq!(sampler.inputs.len(), 2);
+            assert!((sampler.inputs[1] - 1.0).abs() < 1e-6);
+            assert_eq!(sampler.outputs.len(), expected.len());
+            for (output, expected) in sampler.outputs.iter().zip(&expected) {
+                assert!((output - expected).norm() < 1e-6, "{:?}", output);
+            }
+        }
     }
 }
@@ -0,0 +1,62 @@
+use crate::Dimensions;
+use crate::GltfVertex;
+use crate::Matrix4;
+use crate::MeshAssetPart;
+use crate::Vector3;
+use crate::Vector4;
+
+/// Positions of the vertices referenced by an index range, each one once.
+fn range_positions(
+    vertices: &[GltfVertex],
+    indices: &[u32],
+    start: u32,
+    count: u32,
+) -> Vec<Vector3> {
+    let start = start as usize;
+    let mut range = indices[start..start + count as usize].to_vec();
+    range.sort_unstable();
+    range.dedup();
+    range
+        .into_iter()
+        .map(|index| Vector3::from(vertices[index as usize].position))
+        .collect()
+}
+
+/// Set the bounds of every part from the vertices it references.
+pub fn compute_part_bounds(vertices: &[GltfVertex], indices: &[u32], parts: &mut [MeshAssetPart]) {
+    for part in parts.iter_mut() {
+        let positions = range_positions(vertices, indices, part.index_start, part.index_count);
+        part.bounds = Dimensions::from_points(&positions);
+    }
+}
+
+/// Bounds of the parts of every node transformed by `node_transforms`, which
+/// match `node_parts` one to one, and the bounds of all nodes.
+pub fn compute_node_bounds(
+    vertices: &[GltfVertex],
+    indices: &[u32],
+    parts: &[MeshAssetPart],
+    node_parts: &[(String, Vec<u32>)],
+    node_transforms: &[Matrix4],
+) -> (Vec<Dimensions>, Dimensions) {
+    let mut all_positions = Vec::new();
+    let mut node_bounds = Vec::with_capacity(node_parts.len());
+    for ((_, part_indices), transform) in node_parts.iter().zip(node_transforms) {
+        let mut positions = Vec::new();
+        for &part_index in part_indices {
+            let part = &parts[part_index as usize];
+            positions.extend(
+                range_positions(vertices, indices, part.index_start, part.index_count)
+                    .into_iter()
+                    .map(|position| {
+                        let position =
+                            transform * Vector4::new(position.x, position.y, position.z, 1.0);
+                        Vector3::new(position.x, position.y, position.z)
+                    }),
+            );
+        }
+        node_bounds.push(Dimensions::from_points(&positions));
+        all_positions.extend(positions);
+    }
+    (node_bounds, Dimensions::from_points(&all_positions))
+}
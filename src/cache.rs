@@ -0,0 +1,387 @@
+use crate::serialized_mesh;
+use crate::Result;
+use crate::SerializedMesh;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::UNIX_EPOCH;
+
+/// Extension of cached mesh files, named `<identity>.mesh`.
+const CACHE_EXTENSION: &str = "mesh";
+
+/// Start of every cache file, bump it whenever their layout changes.
+const CACHE_MAGIC: &[u8; 8] = b"MESHC001";
+
+const DIGEST_SIZE: usize = 32;
+
+/// Default upper bound of the cache directory size.
+pub const DEFAULT_CACHE_SIZE: u64 = 1024 * 1024 * 1024;
+
+struct CacheEntry {
+    size: u64,
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct CacheState {
+    entries: HashMap<String, CacheEntry>,
+    size: u64,
+    tick: u64,
+}
+
+impl CacheState {
+    fn touch(&mut self, identity: &str, size: u64) {
+        self.tick += 1;
+        let last_used = self.tick;
+        if let Some(entry) = self.entries.get_mut(identity) {
+            self.size = self.size - entry.size + size;
+            entry.size = size;
+            entry.last_used = last_used;
+            return;
+        }
+        self.size += size;
+        self.entries
+            .insert(identity.to_string(), CacheEntry { size, last_used });
+    }
+
+    fn remove(&mut self, identity: &str) -> bool {
+        match self.entries.remove(identity) {
+            Some(entry) => {
+                self.size -= entry.size;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn least_recently_used(&self) -> Option<String> {
+        self.entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(identity, _)| identity.clone())
+    }
+}
+
+/// Counters of a `MeshCache`.
+#[derive(Debug, Clone, Copy)]
+pub struct CacheStats {
+    pub hits: usize,
+    pub misses: usize,
+    pub entries: usize,
+    pub size: u64,
+}
+
+/// Processed meshes stored on disk under their identity, each one as a
+/// `SerializedMesh` checked against a digest when loaded. The least recently
+/// used meshes are evicted once the directory grows past `max_size` bytes.
+pub struct MeshCache {
+    directory: PathBuf,
+    max_size: u64,
+    state: Mutex<CacheState>,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+    /// Number of files written, to name their temporary files.
+    writes: AtomicUsize,
+}
+
+impl MeshCache {
+    /// Open a cache directory, creating it when missing. Meshes left by a
+    /// previous run are kept, ordered by their modification time.
+    pub fn open(directory: &Path, max_size: u64) -> Result<MeshCache> {
+        fs::create_dir_all(directory)?;
+
+        let mut existing = Vec::new();
+        for entry in fs::read_dir(directory)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path.extension() != Some(OsStr::new(CACHE_EXTENSION)) {
+                continue;
+            }
+            let identity = match path.file_stem().and_then(OsStr::to_str) {
+                Some(identity) => identity.to_string(),
+                None => continue,
+            };
+            let metadata = entry.metadata()?;
+            let modified = metadata.modified().unwrap_or(UNIX_EPOCH);
+            existing.push((modified, identity, metadata.len()));
+        }
+        existing.sort();
+
+        let mut state = CacheState::default();
+        for (_, identity, size) in existing {
+            state.touch(&identity, size);
+        }
+
+        let cache = MeshCache {
+            directory: directory.to_path_buf(),
+            max_size,
+            state: Mutex::new(CacheState::default()),
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+            writes: AtomicUsize::new(0),
+        };
+        cache.evict(&mut state);
+        *cache.state.lock().unwrap() = state;
+        Ok(cache)
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    fn entry_path(&self, identity: &str) -> PathBuf {
+        self.directory
+            .join(identity)
+            .with_extension(CACHE_EXTENSION)
+    }
+
+    /// Look up the mesh stored for `identity`. A file that cannot be read, or
+    /// does not hold a valid mesh for `identity`, is removed and treated as a miss.
+    pub fn get(&self, identity: &str) -> Option<SerializedMesh> {
+        if !self.state.lock().unwrap().entries.contains_key(identity) {
+            return None;
+        }
+        let path = self.entry_path(identity);
+        let entry = fs::read(&path)
+            .ok()
+            .and_then(|file| Some((file.len() as u64, decode_entry(identity, &file)?)));
+
+        let mut state = self.state.lock().unwrap();
+        match entry {
+            Some((size, mesh)) => {
+                state.touch(identity, size);
+                Some(mesh)
+            }
+            None => {
+                // Drop entries whose file disappeared or changed behind our back
+                state.remove(identity);
+                let _ = fs::remove_file(&path);
+                None
+            }
+        }
+    }
+
+    /// Store the mesh of `identity`, evicting the least recently used meshes
+    /// when the cache grows too large.
+    pub fn insert(&self, identity: &str, mesh: &SerializedMesh) -> Result<()> {
+        let file = encode_entry(mesh);
+        let size = file.len() as u64;
+        if size > self.max_size {
+            return Ok(());
+        }
+
+        // Write to a temporary file of our own first, so readers and concurrent
+        // writers of the same mesh never see partial files
+        let path = self.entry_path(identity);
+        let write = self.writes.fetch_add(1, Ordering::Relaxed);
+        let temp_path =
+            self.directory
+                .join(format!("{}.{}-{}.tmp", identity, std::process::id(), write));
+        if let Err(err) = fs::write(&temp_path, &file).and_then(|_| fs::rename(&temp_path, &path)) {
+            let _ = fs::remove_file(&temp_path);
+            return Err(err.into());
+        }
+
+        let mut state = self.state.lock().unwrap();
+        state.touch(identity, size);
+        self.evict(&mut state);
+        Ok(())
+    }
+
+    /// Count meshes served from the cache.
+    pub fn record_hits(&self, count: usize) {
+        self.hits.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Count meshes that were processed because they were not all cached.
+    pub fn record_misses(&self, count: usize) {
+        self.misses.fetch_add(count, Ordering::Relaxed);
+    }
+
+    fn evict(&self, state: &mut CacheState) {
+        while state.size > self.max_size {
+            let identity = match state.least_recently_used() {
+                Some(identity) => identity,
+                None => break,
+            };
+            state.remove(&identity);
+            if let Err(err) = fs::remove_file(self.entry_path(&identity)) {
+                log::warn!("Failed to evict cached mesh '{}': {}", identity, err);
+            }
+        }
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let state = self.state.lock().unwrap();
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: state.entries.len(),
+            size: state.size,
+        }
+    }
+}
+
+/// Cache file contents: the magic, the SHA-256 of the rest of the file, the
+/// size of the header, the header and the mesh.
+fn encode_entry(mesh: &SerializedMesh) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(8 + mesh.header.len() + mesh.mesh.len());
+    payload.extend_from_slice(&(mesh.header.len() as u64).to_le_bytes());
+    payload.extend_from_slice(&mesh.header);
+    payload.extend_from_slice(&mesh.mesh);
+
+    let mut file = Vec::with_capacity(CACHE_MAGIC.len() + DIGEST_SIZE + payload.len());
+    file.extend_from_slice(CACHE_MAGIC);
+    file.extend_from_slice(&Sha256::digest(&payload));
+    file.extend_from_slice(&payload);
+    file
+}
+
+/// Read a cache file written by `encode_entry`, `None` when it is truncated,
+/// corrupted, from another version or holds another mesh than `identity`.
+fn decode_entry(identity: &str, file: &[u8]) -> Option<SerializedMesh> {
+    if !file.starts_with(CACHE_MAGIC) || file.len() < CACHE_MAGIC.len() + DIGEST_SIZE + 8 {
+        return None;
+    }
+    let (digest, payload) = file[CACHE_MAGIC.len()..].split_at(DIGEST_SIZE);
+    if Sha256::digest(payload).as_slice() != digest {
+        return None;
+    }
+
+    let mut header_size = [0u8; 8];
+    header_size.copy_from_slice(&payload[..8]);
+    let header_size = u64::from_le_bytes(header_size);
+    let payload = &payload[8..];
+    if header_size > payload.len() as u64 {
+        return None;
+    }
+    let (header, mesh) = payload.split_at(header_size as usize);
+    for buffer in &[header, mesh] {
+        if buffer.len() < flatbuffers::SIZE_UOFFSET
+            || serialized_mesh(buffer).identity() != Some(identity)
+        {
+            return None;
+        }
+    }
+
+    Some(SerializedMesh {
+        header: header.to_vec(),
+        mesh: mesh.to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::service::mesh::schema;
+    use crate::TempDir;
+    use std::sync::Arc;
+    use std::thread;
+
+    fn mesh(identity: &str) -> SerializedMesh {
+        let buffer = |data: &[u8]| {
+            let mut builder = flatbuffers::FlatBufferBuilder::new();
+            let name = Some(builder.create_string("mesh"));
+            let identity = Some(builder.create_string(identity));
+            let skinning_data = Some(builder.create_vector_direct(data));
+            let mesh = schema::Mesh::create(
+                &mut builder,
+                &schema::MeshArgs {
+                    name,
+                    identity,
+                    skinning_data,
+                    ..Default::default()
+                },
+            );
+            builder.finish(mesh, None);
+            builder.finished_data().to_vec()
+        };
+        SerializedMesh {
+            header: buffer(&[]),
+            mesh: buffer(&[7; 64]),
+        }
+    }
+
+    #[test]
+    fn meshes_round_trip() {
+        let directory = TempDir::new("cache-round-trip");
+        let cache = MeshCache::open(directory.path(), DEFAULT_CACHE_SIZE).unwrap();
+        assert_eq!(cache.get("a"), None);
+        cache.insert("a", &mesh("a")).unwrap();
+        assert_eq!(cache.get("a"), Some(mesh("a")));
+
+        // Entries left by a previous run are kept
+        let cache = MeshCache::open(directory.path(), DEFAULT_CACHE_SIZE).unwrap();
+        assert_eq!(cache.get("a"), Some(mesh("a")));
+    }
+
+    #[test]
+    fn least_recently_used_meshes_are_evicted() {
+        let directory = TempDir::new("cache-eviction");
+        let size = encode_entry(&mesh("a")).len() as u64;
+        let cache = MeshCache::open(directory.path(), size * 2).unwrap();
+        cache.insert("a", &mesh("a")).unwrap();
+        cache.insert("b", &mesh("b")).unwrap();
+        assert!(cache.get("a").is_some());
+
+        cache.insert("c", &mesh("c")).unwrap();
+        assert!(cache.get("b").is_none());
+        assert!(!cache.entry_path("b").exists());
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("c").is_some());
+        assert_eq!(cache.stats().entries, 2);
+        assert_eq!(cache.stats().size, size * 2);
+    }
+
+    #[test]
+    fn invalid_files_are_misses() {
+        let directory = TempDir::new("cache-invalid");
+        let cache = MeshCache::open(directory.path(), DEFAULT_CACHE_SIZE).unwrap();
+        let valid = encode_entry(&mesh("a"));
+
+        let mut corrupted = valid.clone();
+        *corrupted.last_mut().unwrap() ^= 1;
+        let mut other_version = valid.clone();
+        other_version[CACHE_MAGIC.len() - 1] ^= 1;
+        let truncated = valid[..valid.len() - 1].to_vec();
+        let other_mesh = encode_entry(&mesh("b"));
+
+        for file in &[corrupted, other_version, truncated, other_mesh, Vec::new()] {
+            cache.insert("a", &mesh("a")).unwrap();
+            fs::write(cache.entry_path("a"), file).unwrap();
+            assert_eq!(cache.get("a"), None);
+            assert!(!cache.entry_path("a").exists());
+            assert_eq!(cache.stats().entries, 0);
+        }
+    }
+
+    #[test]
+    fn concurrent_writers_of_a_mesh_do_not_interfere() {
+        let directory = TempDir::new("cache-concurrent");
+        let cache = Arc::new(MeshCache::open(directory.path(), DEFAULT_CACHE_SIZE).unwrap());
+        cache.insert("a", &mesh("a")).unwrap();
+
+        let writers: Vec<_> = (0..2)
+            .map(|_| {
+                let cache = Arc::clone(&cache);
+                thread::spawn(move || {
+                    for _ in 0..50 {
+                        cache.insert("a", &mesh("a")).unwrap();
+                        assert_eq!(cache.get("a"), Some(mesh("a")));
+                    }
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.join().unwrap();
+        }
+
+        // Only the mesh itself is left
+        assert_eq!(fs::read_dir(directory.path()).unwrap().count(), 1);
+        assert_eq!(cache.stats().entries, 1);
+    }
+}
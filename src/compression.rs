@@ -0,0 +1,320 @@
+use crate::generated::service::mesh::schema;
+use crate::Error;
+use crate::Result;
+use crate::StreamData;
+
+/// Largest element size sent through the meshopt vertex codec, as elements are
+/// passed to it as arrays of up to 32 words.
+const MAX_CODEC_VERTEX_SIZE: usize = 128;
+
+/// Compress the data of a stream: indices of triangle lists with the meshopt
+/// index codec when `triangle_list` is set, elements of a multiple of 4 bytes
+/// with the meshopt vertex codec and anything else with zlib. Streams that do
+/// not shrink are kept as they are.
+pub fn compress_stream(stream: &mut StreamData, triangle_list: bool) -> Result<()> {
+    if stream.compression != schema::StreamCompression::None || stream.data.is_empty() {
+        return Ok(());
+    }
+
+    let elements = stream.elements as usize;
+    let element_size = stream.data.len() / elements.max(1);
+    let (compression, compressed) =
+        if stream.type_ == schema::StreamType::Indices && triangle_list && elements % 3 == 0 {
+            (
+                schema::StreamCompression::MeshoptIndex,
+                encode_indices(stream)?,
+            )
+        } else if element_size % 4 == 0
+            && element_size <= MAX_CODEC_VERTEX_SIZE
+            && element_size * elements == stream.data.len()
+        {
+            (
+                schema::StreamCompression::MeshoptVertex,
+                encode_vertices(&stream.data, elements, element_size)?,
+            )
+        } else {
+            (
+                schema::StreamCompression::Zlib,
+                deflate::deflate_bytes_zlib(&stream.data),
+            )
+        };
+
+    if compressed.len() < stream.data.len() {
+        stream.compression = compression;
+        stream.decoded_size = stream.data.len() as u64;
+        stream.data = compressed;
+    }
+    Ok(())
+}
+
+fn encode_indices(stream: &StreamData) -> Result<Vec<u8>> {
+    let indices: Vec<u32> = if stream.format == schema::StreamFormat::Short {
+        stream
+            .data
+            .chunks(2)
+            .map(|bytes| u32::from(u16::from_le_bytes([bytes[0], bytes[1]])))
+            .collect()
+    } else {
+        stream
+            .data
+            .chunks(4)
+            .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .collect()
+    };
+    let vertex_count = indices.iter().max().map_or(0, |&max| max as usize + 1);
+
+    let encoded = meshopt::encode_index_buffer(&indices, vertex_count)
+        .map_err(|err| Error::process(err.to_string()))?;
+    if encoded.is_empty() {
+        return Err(Error::process("failed to encode index stream"));
+    }
+    Ok(encoded)
+}
+
+/// Run `$body` with `$vertex` bound to the array type of `$words` 4-byte words,
+/// as the meshopt vertex codec takes the element size from the element type.
+macro_rules! with_vertex_type {
+    ($words:expr, $vertex:ident => $body:expr, $($size:tt)*) => {
+        match $words {
+            $($size => {
+                type $vertex = [u32; $size];
+                $body
+            })*
+            words => Err(Error::process(format!(
+                "unsupported vertex size of {} bytes",
+                words * 4
+            ))),
+        }
+    };
+    ($words:expr, $vertex:ident => $body:expr) => {
+        with_vertex_type!($words, $vertex => $body,
+            1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16
+            17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32)
+    };
+}
+
+fn encode_vertices(data: &[u8], elements: usize, element_size: usize) -> Result<Vec<u8>> {
+    let words: Vec<u32> = data
+        .chunks(4)
+        .map(|bytes| u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .collect();
+
+    let encoded = with_vertex_type!(element_size / 4, Vertex => {
+        let vertices: Vec<Vertex> = words
+            .chunks(element_size / 4)
+            .map(|chunk| {
+                let mut vertex = Vertex::default();
+                vertex.copy_from_slice(chunk);
+                vertex
+            })
+            .collect();
+        debug_assert_eq!(vertices.len(), elements);
+        meshopt::encode_vertex_buffer(&vertices).map_err(|err| Error::process(err.to_string()))
+    })?;
+    if encoded.is_empty() {
+        return Err(Error::process("failed to encode vertex stream"));
+    }
+    Ok(encoded)
+}
+
+/// Decoded data of a manifest stream. The service never reads its own output,
+/// so this reference decoder only checks the codecs in tests.
+#[cfg(test)]
+pub fn decode_stream(stream: &schema::MeshStream<'_>) -> Result<Vec<u8>> {
+    let data = stream.data().unwrap_or_default();
+    let elements = stream.elements() as usize;
+    let decoded_size = stream.decoded_size() as usize;
+    let element_size = decoded_size / elements.max(1);
+
+    let decoded: Vec<u8> = match stream.compression() {
+        schema::StreamCompression::None => return Ok(data.to_vec()),
+        schema::StreamCompression::MeshoptVertex => {
+            if element_size % 4 != 0 || element_size > MAX_CODEC_VERTEX_SIZE {
+                return Err(Error::parse_msg("malformed vertex stream"));
+            }
+            with_vertex_type!(element_size / 4, Vertex => {
+                meshopt::decode_vertex_buffer::<Vertex>(data, elements)
+                    .map(|vertices| {
+                        vertices
+                            .iter()
+                            .flat_map(|vertex| vertex.iter())
+                            .flat_map(|word| word.to_ne_bytes().to_vec())
+                            .collect()
+                    })
+                    .map_err(|_| Error::parse_msg("malformed vertex stream"))
+            })?
+        }
+        schema::StreamCompression::MeshoptIndex => match element_size {
+            2 => meshopt::decode_index_buffer::<u16>(data, elements)
+                .map(|indices| {
+                    indices
+                        .iter()
+                        .flat_map(|index| index.to_le_bytes().to_vec())
+                        .collect()
+                })
+                .map_err(|_| Error::parse_msg("malformed index stream"))?,
+            4 => meshopt::decode_index_buffer::<u32>(data, elements)
+                .map(|indices| {
+                    indices
+                        .iter()
+                        .flat_map(|index| index.to_le_bytes().to_vec())
+                        .collect()
+                })
+                .map_err(|_| Error::parse_msg("malformed index stream"))?,
+            _ => return Err(Error::parse_msg("malformed index stream")),
+        },
+        schema::StreamCompression::Zlib => {
+            inflate::inflate_bytes_zlib(data).map_err(Error::parse_msg)?
+        }
+    };
+
+    if decoded.len() != decoded_size {
+        return Err(Error::parse_msg(format!(
+            "decoded {} bytes instead of {}",
+            decoded.len(),
+            decoded_size
+        )));
+    }
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_assets::grid_mesh;
+
+    /// Compress `stream` and decode it again, returning the compression used
+    /// with the original and decoded data.
+    fn round_trip(
+        mut stream: StreamData,
+        triangle_list: bool,
+    ) -> (schema::StreamCompression, Vec<u8>, Vec<u8>) {
+        let original = stream.data.clone();
+        compress_stream(&mut stream, triangle_list).unwrap();
+
+        let mut builder = flatbuffers::FlatBufferBuilder::new();
+        let data = Some(builder.create_vector(&stream.data));
+        let mesh_stream = schema::MeshStream::create(
+            &mut builder,
+            &schema::MeshStreamArgs {
+                type_: stream.type_,
+                format: stream.format,
+                elements: stream.elements,
+                data,
+                compression: stream.compression,
+                decoded_size: stream.decoded_size,
+                ..Default::default()
+            },
+        );
+        builder.finish(mesh_stream, None);
+        let buffer = builder.finished_data();
+
+        let decoded = decode_stream(&flatbuffers::get_root::<schema::MeshStream<'_>>(buffer));
+        (stream.compression, original, decoded.unwrap())
+    }
+
+    /// Triangles of little endian indices of `size` bytes, each rotated to
+    /// start at its smallest index as the index codec may rotate them.
+    fn triangles(data: &[u8], size: usize) -> Vec<[u32; 3]> {
+        let indices: Vec<u32> = data
+            .chunks(size)
+            .map(|bytes| {
+                bytes
+                    .iter()
+                    .rev()
+                    .fold(0, |index, &byte| index << 8 | u32::from(byte))
+            })
+            .collect();
+        indices
+            .chunks(3)
+            .map(|triangle| {
+                let first = (0..3).min_by_key(|&corner| triangle[corner]).unwrap();
+                [
+                    triangle[first],
+                    triangle[(first + 1) % 3],
+                    triangle[(first + 2) % 3],
+                ]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn index_streams_round_trip() {
+        let (_, indices) = grid_mesh(16);
+        let short: Vec<u16> = indices.iter().map(|&index| index as u16).collect();
+
+        let streams = vec![
+            StreamData::new(
+                schema::StreamType::Indices,
+                schema::StreamFormat::Short,
+                short,
+            ),
+            StreamData::new(
+                schema::StreamType::Indices,
+                schema::StreamFormat::Int,
+                indices,
+            ),
+        ];
+        for (stream, size) in streams.into_iter().zip(&[2, 4]) {
+            let (compression, original, decoded) = round_trip(stream, true);
+            assert_eq!(compression, schema::StreamCompression::MeshoptIndex);
+            assert_eq!(triangles(&decoded, *size), triangles(&original, *size));
+        }
+    }
+
+    #[test]
+    fn vertex_streams_round_trip() {
+        let (vertices, _) = grid_mesh(16);
+        let positions: Vec<[f32; 3]> = vertices.iter().map(|vertex| vertex.position).collect();
+        let wide: Vec<[u32; 32]> = (0..256u32)
+            .map(|index| {
+                let mut element = [0u32; 32];
+                element[0] = index;
+                element[31] = index / 4;
+                element
+            })
+            .collect();
+
+        let positions = StreamData::new(
+            schema::StreamType::Positions,
+            schema::StreamFormat::Vector3,
+            positions,
+        );
+        let (compression, original, decoded) = round_trip(positions, false);
+        assert_eq!(compression, schema::StreamCompression::MeshoptVertex);
+        assert_eq!(decoded, original);
+        let wide = StreamData::new(
+            schema::StreamType::Positions,
+            schema::StreamFormat::Interleaved,
+            wide,
+        );
+        let (compression, original, decoded) = round_trip(wide, false);
+        assert_eq!(compression, schema::StreamCompression::MeshoptVertex);
+        assert_eq!(decoded, original);
+    }
+
+    #[test]
+    fn indices_of_other_topologies_round_trip() {
+        // Without triangle lists, 4-byte indices take the vertex codec and
+        // 2-byte indices fall back to zlib
+        let (_, indices) = grid_mesh(16);
+        let short: Vec<u16> = indices.iter().map(|&index| index as u16).collect();
+
+        let indices = StreamData::new(
+            schema::StreamType::Indices,
+            schema::StreamFormat::Int,
+            indices,
+        );
+        let (compression, original, decoded) = round_trip(indices, false);
+        assert_eq!(compression, schema::StreamCompression::MeshoptVertex);
+        assert_eq!(decoded, original);
+        let short = StreamData::new(
+            schema::StreamType::Indices,
+            schema::StreamFormat::Short,
+            short,
+        );
+        let (compression, original, decoded) = round_trip(short, false);
+        assert_eq!(compression, schema::StreamCompression::Zlib);
+        assert_eq!(decoded, original);
+    }
+}
@@ -0,0 +1,221 @@
+use crate::flip_winding;
+use crate::Dimensions;
+use crate::Error;
+use crate::GltfAnimationSampler;
+use crate::GltfModel;
+use crate::GltfOptions;
+use crate::GltfVertex;
+use crate::Matrix3;
+use crate::Matrix4;
+use crate::Quaternion;
+use crate::Result;
+use crate::Topology;
+use crate::UnitQuaternion;
+use crate::Vector3;
+use crate::Vector4;
+use nalgebra::U3;
+use serde::Deserialize;
+use std::rc::Rc;
+
+/// Length unit of the exported meshes, glTF sources are in meters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LengthUnit {
+    Meters,
+    Centimeters,
+    Inches,
+}
+
+impl LengthUnit {
+    fn per_meter(self) -> f32 {
+        match self {
+            LengthUnit::Meters => 1.0,
+            LengthUnit::Centimeters => 100.0,
+            LengthUnit::Inches => 1.0 / 0.0254,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UpAxis {
+    Y,
+    Z,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Handedness {
+    Right,
+    Left,
+}
+
+/// Conversion from glTF coordinates (meters, Y up, right-handed) to the units
+/// and axes requested by the options.
+pub struct CoordinateConversion {
+    /// Signed permutation of the axes, a rotation or a reflection.
+    axes: Matrix3,
+    scale: f32,
+}
+
+impl CoordinateConversion {
+    pub fn new(options: &GltfOptions) -> CoordinateConversion {
+        #[rustfmt::skip]
+        let axes = match (options.up_axis, options.handedness) {
+            (UpAxis::Y, Handedness::Right) => Matrix3::identity(),
+            // Mirror the forward axis
+            (UpAxis::Y, Handedness::Left) => Matrix3::new(
+                1.0, 0.0, 0.0,
+                0.0, 1.0, 0.0,
+                0.0, 0.0, -1.0,
+            ),
+            // Rotate +Y onto +Z, glTF forward (+Z) becomes -Y
+            (UpAxis::Z, Handedness::Right) => Matrix3::new(
+                1.0, 0.0, 0.0,
+                0.0, 0.0, -1.0,
+                0.0, 1.0, 0.0,
+            ),
+            (UpAxis::Z, Handedness::Left) => Matrix3::new(
+                1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0,
+                0.0, 1.0, 0.0,
+            ),
+        };
+        CoordinateConversion {
+            axes,
+            scale: options.global_scale * options.units.per_meter(),
+        }
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.scale == 1.0 && self.axes == Matrix3::identity()
+    }
+
+    /// Reflections reverse the winding of triangles.
+    pub fn flips_winding(&self) -> bool {
+        self.axes.determinant() < 0.0
+    }
+
+    fn matrix(&self) -> Matrix4 {
+        let mut matrix = Matrix4::identity();
+        matrix
+            .fixed_slice_mut::<U3, U3>(0, 0)
+            .copy_from(&(self.axes * self.scale));
+        matrix
+    }
+
+    fn point(&self, point: Vector3) -> Vector3 {
+        self.axes * point * self.scale
+    }
+
+    /// Rotations conjugated by the axes, the vector part of a quaternion
+    /// rotates with the proper rotation part of the axes. Linear, so it also
+    /// applies to spline tangents.
+    fn quaternion(&self, quaternion: Vector4) -> Vector4 {
+        let proper = if self.flips_winding() {
+            -self.axes
+        } else {
+            self.axes
+        };
+        let vector = proper * Vector3::new(quaternion.x, quaternion.y, quaternion.z);
+        Vector4::new(vector.x, vector.y, vector.z, quaternion.w)
+    }
+
+    /// Scale factors follow their axes, valid for signed permutations.
+    fn scaling(&self, scale: Vector3) -> Vector3 {
+        self.axes.map(f32::abs) * scale
+    }
+
+    /// Conjugate a transform, so it applies to converted coordinates.
+    fn transform(&self, matrix: &Matrix4) -> Matrix4 {
+        let conversion = self.matrix();
+        let inverse = conversion.try_inverse().unwrap_or_else(Matrix4::identity);
+        conversion * matrix * inverse
+    }
+
+    fn vertex(&self, vertex: &mut GltfVertex) {
+        vertex.position = self.point(Vector3::from(vertex.position)).into();
+        vertex.normal = (self.axes * Vector3::from(vertex.normal)).into();
+        vertex.tangent = (self.axes * Vector3::from(vertex.tangent)).into();
+        vertex.bitangent = (self.axes * Vector3::from(vertex.bitangent)).into();
+    }
+
+    /// Bounds of the converted corners of `dimensions`.
+    pub fn dimensions(&self, dimensions: &Dimensions) -> Dimensions {
+        dimensions.transform(&self.matrix())
+    }
+}
+
+/// Convert the vertices, triangle winding, node transforms, inverse bind
+/// matrices and animation keys of a model.
+pub fn convert_model(model: &mut GltfModel, conversion: &CoordinateConversion) -> Result<()> {
+    if conversion.is_identity() {
+        return Ok(());
+    }
+
+    for vertex in &mut model.vertex_buffer {
+        conversion.vertex(vertex);
+    }
+    if conversion.flips_winding() {
+        for mesh in &model.meshes {
+            for primitive in &mesh.primitives {
+                if primitive.topology == Topology::Triangles {
+                    let start = primitive.index_start as usize;
+                    let end = start + primitive.index_count as usize;
+                    flip_winding(&mut model.index_buffer[start..end]);
+                }
+            }
+        }
+    }
+
+    for node in &model.linear_nodes {
+        let mut node = node.borrow_mut();
+        node.translation = conversion.point(node.translation);
+        let rotation = node.rotation.into_inner();
+        let rotation =
+            conversion.quaternion(Vector4::new(rotation.i, rotation.j, rotation.k, rotation.w));
+        node.rotation = UnitQuaternion::new_normalize(Quaternion::new(
+            rotation.w, rotation.x, rotation.y, rotation.z,
+        ));
+        node.scale = conversion.scaling(node.scale);
+    }
+
+    model.dimensions = conversion.dimensions(&model.dimensions);
+
+    for joint in &mut model.skeleton.joints {
+        joint.inverse_bind_matrix = conversion.transform(&joint.inverse_bind_matrix);
+    }
+
+    let mut animations = Vec::with_capacity(model.animations.len());
+    for animation in model.animations.drain(..) {
+        let mut animation = Rc::try_unwrap(animation)
+            .map_err(|_| Error::bug("animations must not be shared before conversion"))?;
+        for channel in &mut animation.channels {
+            convert_sampler(&mut channel.translation_sampler, |value| {
+                let point = conversion.point(Vector3::new(value.x, value.y, value.z));
+                Vector4::new(point.x, point.y, point.z, value.w)
+            });
+            convert_sampler(&mut channel.rotation_sampler, |value| {
+                conversion.quaternion(value)
+            });
+            convert_sampler(&mut channel.scale_sampler, |value| {
+                let scale = conversion.scaling(Vector3::new(value.x, value.y, value.z));
+                Vector4::new(scale.x, scale.y, scale.z, value.w)
+            });
+        }
+        animations.push(Rc::new(animation));
+    }
+    model.animations = animations;
+    Ok(())
+}
+
+fn convert_sampler<F: Fn(Vector4) -> Vector4>(
+    sampler: &mut Option<GltfAnimationSampler>,
+    convert: F,
+) {
+    if let Some(sampler) = sampler {
+        for value in &mut sampler.outputs {
+            *value = convert(*value);
+        }
+    }
+}
@@ -2,8 +2,12 @@
 #![allow(unused_variables)]
 
 //use crate::StdError;
+use crate::resolve_relative;
+use crate::Error;
 use crate::GltfOptions;
+use crate::IdentityHasher;
 use crate::Result;
+use std::collections::HashMap;
 use std::path::Path;
 use std::{fs, io};
 
@@ -38,12 +42,96 @@ impl MaterialFlags {
 }
 */
 
+/// A glTF document with its buffers and encoded images loaded, hashed but not
+/// imported yet, so cached meshes can be found without decoding anything.
+#[derive(Debug)]
+pub struct GltfSource {
+    pub options: GltfOptions,
+    gltf: gltf::Gltf,
+    buffers: GltfBuffers,
+    /// Encoded contents of every image, in document order.
+    images: Vec<Vec<u8>>,
+    /// Hash of the source document and every external buffer and image it references.
+    pub source_digest: Vec<u8>,
+}
+
+impl GltfSource {
+    /// Load a glTF or GLB file from disk, resolving external resources next to it.
+    pub fn from_path(path: &Path, options: GltfOptions) -> Result<GltfSource> {
+        let data = read_to_end(path)?;
+        GltfSource::from_slice(&data, &UriResolver::File(path), options)
+    }
+
+    /// Load a glTF or GLB document from memory, resolving external resources with `resolver`.
+    pub fn from_slice(
+        data: &[u8],
+        resolver: &UriResolver<'_>,
+        options: GltfOptions,
+    ) -> Result<GltfSource> {
+        let mut digest = IdentityHasher::new();
+        digest.write("document", data);
+
+        let (gltf, buffers) = _import(data, resolver)?;
+        for buffer in gltf.buffers() {
+            if let gltf::buffer::Source::Uri(uri) = buffer.source() {
+                if !uri.starts_with("data:") {
+                    digest.write("buffer", buffers.buffer(&buffer).unwrap_or_default());
+                }
+            }
+        }
+
+        let images = load_images(&gltf, &buffers, resolver, &mut digest)?;
+        Ok(GltfSource {
+            options,
+            gltf,
+            buffers,
+            images,
+            source_digest: digest.finish(),
+        })
+    }
+
+    pub fn document(&self) -> &gltf::Document {
+        &self.gltf.document
+    }
+
+    /// Decode the images and hand the document over for processing.
+    pub fn import(self) -> Result<GltfData> {
+        let images = self
+            .images
+            .iter()
+            .map(|encoded| {
+                let decoded = image::load_from_memory(encoded)?.to_rgba();
+                Ok(gltf::image::Data {
+                    width: decoded.width(),
+                    height: decoded.height(),
+                    format: gltf::image::Format::R8G8B8A8,
+                    pixels: decoded.into_raw(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(GltfData {
+            options: self.options,
+            document: self.gltf.document,
+            buffers: self
+                .buffers
+                .take()
+                .into_iter()
+                .map(gltf::buffer::Data)
+                .collect(),
+            images,
+            source_digest: self.source_digest,
+        })
+    }
+}
+
 #[derive(Debug)]
 pub struct GltfData {
     pub options: GltfOptions,
     pub document: gltf::Document,
     pub buffers: Vec<gltf::buffer::Data>,
     pub images: Vec<gltf::image::Data>,
+    /// Hash of the source document and every external buffer and image it references.
+    pub source_digest: Vec<u8>,
 }
 
 impl GltfData {
@@ -57,16 +145,45 @@ impl GltfData {
 
     /// Obtain the contents of a loaded buffer view.
     pub fn view(&self, view: &gltf::buffer::View<'_>) -> Option<&[u8]> {
-        self.buffer(&view.buffer()).map(|data| {
-            let begin = view.offset();
-            let end = begin + view.length();
-            &data[begin..end]
-        })
+        let begin = view.offset();
+        let end = begin + view.length();
+        self.buffer(&view.buffer())
+            .and_then(|data| data.get(begin..end))
     }
 }
 
 pub type GltfIndex = usize;
 
+/// Resolves the external buffers and images a glTF document references by URI.
+#[derive(Debug)]
+pub enum UriResolver<'a> {
+    /// Load from disk, relative to the path of the glTF file. URIs may not
+    /// leave the directory of the file.
+    File(&'a Path),
+
+    /// Load from blobs held in memory, keyed by the URI as written in the document.
+    Memory(&'a HashMap<String, Vec<u8>>),
+}
+
+impl<'a> UriResolver<'a> {
+    pub fn load(&self, uri: &str) -> Result<Vec<u8>> {
+        if uri.starts_with("data:") {
+            return parse_data_uri(uri);
+        }
+
+        match *self {
+            UriResolver::File(base_path) => {
+                let directory = base_path.parent().unwrap_or(Path::new("./"));
+                load_data(resolve_relative(directory, &percent_decode(uri)?)?)
+            }
+            UriResolver::Memory(blobs) => blobs
+                .get(uri)
+                .cloned()
+                .ok_or_else(|| Error::invalid_input(format!("no data supplied for uri '{}'", uri))),
+        }
+    }
+}
+
 pub fn read_to_end<P>(path: P) -> Result<Vec<u8>>
 where
     P: AsRef<Path>,
@@ -80,74 +197,37 @@ where
     Ok(data)
 }
 
-#[derive(Debug)]
-pub enum ImageFormat {
-    Png,
-    Jpeg,
-}
-
-impl ImageFormat {
-    fn from_mime_type(mime: &str) -> Self {
-        match mime {
-            "image/jpeg" => ImageFormat::Jpeg,
-            "image/png" => ImageFormat::Png,
-            _ => unreachable!(),
-        }
-    }
-}
-
-pub fn get_image_data(
-    image: &gltf::Image<'_>,
+/// Load the encoded contents of every image referenced by the document, adding
+/// external images to `digest`.
+fn load_images(
+    gltf: &gltf::Gltf,
     buffers: &GltfBuffers,
-    base_path: &Path,
-) -> Result<(Vec<u8>, ImageFormat)> {
+    resolver: &UriResolver<'_>,
+    digest: &mut IdentityHasher,
+) -> Result<Vec<Vec<u8>>> {
     use gltf::image::Source;
-    match image.source() {
-        Source::View { view, mime_type } => {
-            let data = buffers
-                .view(&view)
-                .expect("`view` of image data points to a buffer which does not exist");
-            Ok((data.to_vec(), ImageFormat::from_mime_type(mime_type)))
-        }
-
-        Source::Uri { uri, mime_type } => {
-            if uri.starts_with("data:") {
-                let data = parse_data_uri(uri)?;
-                if let Some(ty) = mime_type {
-                    Ok((data, ImageFormat::from_mime_type(ty)))
-                } else {
-                    let mimetype = uri
-                        .split(',')
-                        .nth(0)
-                        .expect("Unreachable: `split` will always return at least one element")
-                        .split(':')
-                        .nth(1)
-                        .expect("URI does not contain ':'")
-                        .split(';')
-                        .nth(0)
-                        .expect("Unreachable: `split` will always return at least one element");
-                    Ok((data, ImageFormat::from_mime_type(mimetype)))
-                }
-            } else {
-                let path = base_path.parent().unwrap_or(Path::new("./")).join(uri);
-                let data = load_data(&path)?;
-                if let Some(ty) = mime_type {
-                    Ok((data, ImageFormat::from_mime_type(ty)))
-                } else {
-                    let ext = path
-                        .extension()
-                        .and_then(|s| s.to_str())
-                        .map_or("".to_string(), |s| s.to_ascii_lowercase());
-                    let format = match &ext[..] {
-                        "jpg" | "jpeg" => ImageFormat::Jpeg,
-                        "png" => ImageFormat::Png,
-                        _ => unreachable!(),
-                    };
-                    Ok((data, format))
+    gltf.images()
+        .map(|image_ref| match image_ref.source() {
+            Source::View { view, .. } => {
+                buffers
+                    .view(&view)
+                    .map(|data| data.to_vec())
+                    .ok_or_else(|| {
+                        Error::invalid_input(format!(
+                            "view of image {} is outside of its buffer",
+                            image_ref.index()
+                        ))
+                    })
+            }
+            Source::Uri { uri, .. } => {
+                let data = resolver.load(uri)?;
+                if !uri.starts_with("data:") {
+                    digest.write("image", &data);
                 }
+                Ok(data)
             }
-        }
-    }
+        })
+        .collect()
 }
 
 /// Buffer data returned from `import`.
@@ -163,11 +243,10 @@ impl GltfBuffers {
 
     /// Obtain the contents of a loaded buffer view.
     pub fn view(&self, view: &gltf::buffer::View<'_>) -> Option<&[u8]> {
-        self.buffer(&view.buffer()).map(|data| {
-            let begin = view.offset();
-            let end = begin + view.length();
-            &data[begin..end]
-        })
+        let begin = view.offset();
+        let end = begin + view.length();
+        self.buffer(&view.buffer())
+            .and_then(|data| data.get(begin..end))
     }
 
     /// Take the loaded buffer data.
@@ -177,7 +256,7 @@ impl GltfBuffers {
 }
 
 fn load_external_buffers(
-    base_path: &Path,
+    resolver: &UriResolver<'_>,
     gltf: &gltf::Gltf,
     mut bin: Option<Vec<u8>>,
 ) -> Result<Vec<Vec<u8>>> {
@@ -185,23 +264,22 @@ fn load_external_buffers(
     let mut buffers = vec![];
     for (index, buffer) in gltf.buffers().enumerate() {
         let data = match buffer.source() {
-            Source::Uri(uri) => {
-                if uri.starts_with("data:") {
-                    parse_data_uri(uri)?
-                } else {
-                    let path = base_path.parent().unwrap_or(Path::new("./")).join(uri);
-                    load_data(&path)?
-                }
-            }
-            Source::Bin => bin
-                .take()
-                .expect("`BIN` section of binary glTF file is empty or used by another buffer"),
+            Source::Uri(uri) => resolver.load(uri)?,
+            Source::Bin => bin.take().ok_or_else(|| {
+                Error::invalid_input(format!(
+                    "buffer {} refers to a missing or already used BIN chunk",
+                    index
+                ))
+            })?,
         };
 
         if data.len() < buffer.length() {
-            //let path = json::Path::new().field("buffers").index(index);
-            //return Err(error::Error::BufferLength(path).into());
-            panic!("invalid buffer length!");
+            return Err(Error::invalid_input(format!(
+                "buffer {} holds {} bytes instead of {}",
+                index,
+                data.len(),
+                buffer.length()
+            )));
         }
         buffers.push(data);
     }
@@ -221,13 +299,13 @@ where
     Ok(v)
 }
 
-fn import_standard(data: &[u8], base_path: &Path) -> Result<(gltf::Gltf, GltfBuffers)> {
+fn import_standard(data: &[u8], resolver: &UriResolver<'_>) -> Result<(gltf::Gltf, GltfBuffers)> {
     let gltf = gltf::Gltf::from_slice(data)?;
-    let buffers = GltfBuffers(load_external_buffers(base_path, &gltf, None)?);
+    let buffers = GltfBuffers(load_external_buffers(resolver, &gltf, None)?);
     Ok((gltf, buffers))
 }
 
-fn import_binary(data: &[u8], base_path: &Path) -> Result<(gltf::Gltf, GltfBuffers)> {
+fn import_binary(data: &[u8], resolver: &UriResolver<'_>) -> Result<(gltf::Gltf, GltfBuffers)> {
     let gltf::binary::Glb {
         header: _,
         json,
@@ -235,24 +313,133 @@ fn import_binary(data: &[u8], base_path: &Path) -> Result<(gltf::Gltf, GltfBuffe
     } = gltf::binary::Glb::from_slice(data)?;
     let gltf = gltf::Gltf::from_slice(&json)?;
     let bin = bin.map(|x| x.to_vec());
-    let buffers = GltfBuffers(load_external_buffers(base_path, &gltf, bin)?);
+    let buffers = GltfBuffers(load_external_buffers(resolver, &gltf, bin)?);
     Ok((gltf, buffers))
 }
 
-pub fn _import<P>(data: &[u8], path: P) -> Result<(gltf::Gltf, GltfBuffers)>
-where
-    P: AsRef<Path>,
-{
-    let path = path.as_ref();
+pub fn _import(data: &[u8], resolver: &UriResolver<'_>) -> Result<(gltf::Gltf, GltfBuffers)> {
     if data.starts_with(b"glTF") {
-        import_binary(&data, path)
+        import_binary(&data, resolver)
     } else {
-        import_standard(&data, path)
+        import_standard(&data, resolver)
     }
 }
 
+/// Decode the `%XX` escapes of a relative URI into the path they stand for.
+fn percent_decode(uri: &str) -> Result<String> {
+    let bytes = uri.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let byte = uri
+                .get(index + 1..index + 3)
+                .filter(|hex| hex.bytes().all(|byte| byte.is_ascii_hexdigit()))
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .ok_or_else(|| Error::invalid_input(format!("invalid escape in uri '{}'", uri)))?;
+            decoded.push(byte);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8(decoded)
+        .map_err(|_| Error::invalid_input(format!("uri '{}' is not valid UTF-8", uri)))
+}
+
 fn parse_data_uri(uri: &str) -> Result<Vec<u8>> {
-    let encoded = uri.split(",").nth(1).expect("URI does not contain ','");
+    let encoded = uri
+        .splitn(2, ',')
+        .nth(1)
+        .ok_or_else(|| Error::invalid_input("data URI does not contain ','"))?;
     let decoded = base64::decode(&encoded)?;
     Ok(decoded)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external_triangle_gltf;
+    use crate::ErrorKind;
+    use crate::TempDir;
+
+    fn assert_invalid_input<T: std::fmt::Debug>(result: Result<T>) {
+        let err = result.expect_err("invalid input");
+        if let ErrorKind::InvalidInput(_) = *err.kind() {
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn memory_resolver_loads_blobs_and_data_uris() {
+        let mut blobs = HashMap::new();
+        blobs.insert("triangle.bin".to_string(), vec![1, 2, 3]);
+        let resolver = UriResolver::Memory(&blobs);
+        assert_eq!(resolver.load("triangle.bin").unwrap(), vec![1, 2, 3]);
+        let uri = "data:application/octet-stream;base64,AQID";
+        assert_eq!(resolver.load(uri).unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn memory_resolver_rejects_missing_buffer() {
+        let blobs = HashMap::new();
+        assert_invalid_input(UriResolver::Memory(&blobs).load("triangle.bin"));
+
+        let (document, _) = external_triangle_gltf();
+        let resolver = UriResolver::Memory(&blobs);
+        assert_invalid_input(GltfSource::from_slice(
+            &document,
+            &resolver,
+            GltfOptions::default(),
+        ));
+    }
+
+    #[test]
+    fn memory_resolver_rejects_short_buffer() {
+        let (document, buffer) = external_triangle_gltf();
+        let mut blobs = HashMap::new();
+        blobs.insert("triangle.bin".to_string(), buffer[..10].to_vec());
+        assert_invalid_input(GltfSource::from_slice(
+            &document,
+            &UriResolver::Memory(&blobs),
+            GltfOptions::default(),
+        ));
+    }
+
+    #[test]
+    fn data_uri_without_comma_is_rejected() {
+        let blobs = HashMap::new();
+        let resolver = UriResolver::Memory(&blobs);
+        assert_invalid_input(resolver.load("data:application/octet-stream;base64"));
+    }
+
+    #[test]
+    fn file_resolver_decodes_uris_and_stays_in_the_asset_directory() {
+        let root = TempDir::new("file-resolver");
+        let assets = root.path().join("assets");
+        std::fs::create_dir(&assets).unwrap();
+        std::fs::write(assets.join("tri angle.bin"), [1, 2, 3]).unwrap();
+        std::fs::write(root.path().join("secret.bin"), [4, 5, 6]).unwrap();
+
+        let document = assets.join("triangle.gltf");
+        let resolver = UriResolver::File(&document);
+        assert_eq!(resolver.load("tri%20angle.bin").unwrap(), vec![1, 2, 3]);
+
+        let secret = root.path().join("secret.bin");
+        for uri in &[
+            "../secret.bin",
+            "%2E%2E/secret.bin",
+            "assets/../../secret.bin",
+            secret.to_str().unwrap(),
+        ] {
+            let err = resolver.load(uri).expect_err("escaping uri");
+            if let ErrorKind::Path(_) = *err.kind() {
+            } else {
+                panic!("unexpected error for '{}': {}", uri, err);
+            }
+        }
+        assert_invalid_input(resolver.load("tri%2gangle.bin"));
+    }
+}
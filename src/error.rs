@@ -28,6 +28,10 @@ impl Error {
         Error::from(ErrorKind::Parse(msg.as_ref().to_string()))
     }
 
+    pub fn invalid_input<T: AsRef<str>>(msg: T) -> Error {
+        Error::from(ErrorKind::InvalidInput(msg.as_ref().to_string()))
+    }
+
     pub fn bug<T: AsRef<str>>(msg: T) -> Error {
         Error::from(ErrorKind::Bug(msg.as_ref().to_string()))
     }
@@ -40,6 +44,10 @@ impl Error {
         Error::from(ErrorKind::Config(msg.as_ref().to_string()))
     }
 
+    pub fn scene_index(index: usize, count: usize) -> Error {
+        Error::from(ErrorKind::SceneIndex { index, count })
+    }
+
     pub fn number<E: Fail>(err: E) -> Error {
         Error::from(err.context(ErrorKind::Number))
     }
@@ -82,6 +90,9 @@ pub enum ErrorKind {
     /// An error that occurred while parsing a data source
     Parse(String),
 
+    /// A source asset or one of its resources is malformed or missing.
+    InvalidInput(String),
+
     /// An error that occurred while working with a file path.
     Path(PathBuf),
 
@@ -94,6 +105,9 @@ pub enum ErrorKind {
     /// An error occurred while reading/writing a configuration
     Config(String),
 
+    /// A requested scene does not exist in the source asset.
+    SceneIndex { index: usize, count: usize },
+
     /// An unexpected I/O error occurred.
     Io,
 
@@ -124,6 +138,7 @@ impl fmt::Display for ErrorKind {
         match *self {
             ErrorKind::Memory(ref msg) => write!(f, "memory error: {}", msg),
             ErrorKind::Parse(ref msg) => write!(f, "parse error: {}", msg),
+            ErrorKind::InvalidInput(ref msg) => write!(f, "invalid input: {}", msg),
             ErrorKind::Path(ref path) => write!(f, "{}", path.display()),
             ErrorKind::Bug(ref msg) => {
                 let report = "Please report this bug with a backtrace at \
@@ -132,6 +147,11 @@ impl fmt::Display for ErrorKind {
             }
             ErrorKind::Process(ref msg) => write!(f, "process error: {}", msg),
             ErrorKind::Config(ref msg) => write!(f, "config error: {}", msg),
+            ErrorKind::SceneIndex { index, count } => write!(
+                f,
+                "scene index {} is too high - file has only {} scene(s)",
+                index, count
+            ),
             ErrorKind::Io => write!(f, "I/O error"),
             ErrorKind::Grpc => write!(f, "gRPC error"),
             ErrorKind::Number => write!(f, "error parsing number"),
@@ -166,6 +186,12 @@ impl From<gltf::Error> for Error {
     }
 }
 
+impl From<image::ImageError> for Error {
+    fn from(err: image::ImageError) -> Self {
+        Error::parse(err)
+    }
+}
+
 impl From<base64::DecodeError> for Error {
     fn from(err: base64::DecodeError) -> Self {
         Error::parse(err)
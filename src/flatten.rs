@@ -0,0 +1,127 @@
+use crate::GltfVertex;
+use crate::Matrix3;
+use crate::Matrix4;
+use crate::Topology;
+use crate::Vector3;
+use crate::Vector4;
+use nalgebra::U3;
+use std::collections::HashMap;
+
+/// A node transform baked into vertices.
+pub struct VertexTransform {
+    matrix: Matrix4,
+    /// Upper 3x3 of the matrix, transforms tangents and bitangents.
+    linear: Matrix3,
+    /// Inverse-transpose of the upper 3x3, transforms normals.
+    normal: Matrix3,
+    /// Mirroring transforms reverse the winding of triangles.
+    flips_winding: bool,
+}
+
+impl VertexTransform {
+    pub fn new(matrix: &Matrix4) -> VertexTransform {
+        let linear: Matrix3 = matrix.fixed_slice::<U3, U3>(0, 0).into_owned();
+        // Degenerate transforms keep normals usable rather than failing
+        let normal = linear
+            .try_inverse()
+            .map_or(linear, |inverse| inverse.transpose());
+        VertexTransform {
+            matrix: *matrix,
+            linear,
+            normal,
+            flips_winding: linear.determinant() < 0.0,
+        }
+    }
+
+    /// Transform the position, normal, tangent and bitangent of a vertex. The
+    /// bitangent is rebuilt orthogonal to the normal and tangent, keeping the
+    /// handedness of the transformed tangent frame.
+    pub fn apply(&self, vertex: &mut GltfVertex) {
+        let position = Vector4::new(
+            vertex.position[0],
+            vertex.position[1],
+            vertex.position[2],
+            1.0,
+        );
+        let position = self.matrix * position;
+        vertex.position = [position.x, position.y, position.z];
+
+        let normal = normalize(self.normal * Vector3::from(vertex.normal));
+        let tangent = normalize(self.linear * Vector3::from(vertex.tangent));
+        let bitangent = self.linear * Vector3::from(vertex.bitangent);
+
+        let cross = normal.cross(&tangent);
+        let bitangent = if cross.norm_squared() > 0.0 {
+            let sign = if cross.dot(&bitangent) < 0.0 {
+                -1.0
+            } else {
+                1.0
+            };
+            normalize(cross * sign)
+        } else {
+            normalize(bitangent)
+        };
+
+        vertex.normal = normal.into();
+        vertex.tangent = tangent.into();
+        vertex.bitangent = bitangent.into();
+    }
+}
+
+fn normalize(vector: Vector3) -> Vector3 {
+    let length = vector.norm();
+    if length > 0.0 {
+        vector / length
+    } else {
+        vector
+    }
+}
+
+/// Append a copy of the vertices and triangles of an index range, so another
+/// instance can be baked into it. Returns the start of the copied indices.
+pub fn duplicate_range(
+    vertices: &mut Vec<GltfVertex>,
+    indices: &mut Vec<u32>,
+    start: u32,
+    count: u32,
+) -> u32 {
+    let copy_start = indices.len() as u32;
+    let mut remap: HashMap<u32, u32> = HashMap::new();
+    for position in start..start + count {
+        let index = indices[position as usize];
+        let copy = *remap.entry(index).or_insert_with(|| {
+            vertices.push(vertices[index as usize]);
+            vertices.len() as u32 - 1
+        });
+        indices.push(copy);
+    }
+    copy_start
+}
+
+/// Bake `transform` into the vertices of an index range of `topology`. The
+/// vertices must not be referenced by any other range.
+pub fn bake_range(
+    vertices: &mut [GltfVertex],
+    indices: &mut [u32],
+    topology: Topology,
+    transform: &VertexTransform,
+) {
+    let mut unique = indices.to_vec();
+    unique.sort_unstable();
+    unique.dedup();
+    for index in unique {
+        transform.apply(&mut vertices[index as usize]);
+    }
+    if transform.flips_winding && topology == Topology::Triangles {
+        flip_winding(indices);
+    }
+}
+
+/// Reverse the winding of every triangle of an index range.
+pub fn flip_winding(indices: &mut [u32]) {
+    for triangle in indices.chunks_mut(3) {
+        if triangle.len() == 3 {
+            triangle.swap(1, 2);
+        }
+    }
+}
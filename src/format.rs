@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 
+use crate::Dimensions;
 use crate::Matrix4;
 //use crate::Vector2;
 //use crate::Vector3;
@@ -20,6 +21,24 @@ pub enum AnimationType {
     Mesh,
 }
 
+/// Primitives of the index range of a part.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Topology {
+    Triangles,
+    Lines,
+    Points,
+}
+
+impl Topology {
+    pub fn primitive_size(self) -> u32 {
+        match self {
+            Topology::Triangles => 3,
+            Topology::Lines => 2,
+            Topology::Points => 1,
+        }
+    }
+}
+
 pub const MAX_BONE_INFLUENCES: usize = 16;
 
 #[derive(Debug, Default)]
@@ -50,6 +69,15 @@ pub struct MeshAssetPart {
     pub base_transform: Matrix4,
     pub name: Option<String>, // todo: need this to pair with animation channel;
     pub animation_type: AnimationType,
+    pub meshlet_start: u32,
+    pub meshlet_count: u32,
+    /// Added to the indices of the part, set when the index stream is built.
+    pub base_vertex: u32,
+    /// Transform of every node drawing the part when instancing.
+    pub instance_transforms: Vec<Matrix4>,
+    /// Bounds of the vertices of the part, set once they are final.
+    pub bounds: Dimensions,
+    pub topology: Topology,
 }
 
 #[derive(Debug, Default)]
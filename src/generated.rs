@@ -44,10 +44,25 @@ pub mod service {
                 Vector2 = 4,
                 Int = 5,
                 Int3 = 6,
+                Meshlet = 7,
+                Half2 = 8,
+                Half4 = 9,
+                Snorm8x4 = 10,
+                Unorm8x4 = 11,
+                Snorm16x2 = 12,
+                Snorm16x4 = 13,
+                Unorm16x2 = 14,
+                Unorm16x4 = 15,
+                Octahedral8 = 16,
+                Octahedral16 = 17,
+                Snorm1010102 = 18,
+                Unorm1010102 = 19,
+                Short = 20,
+                Interleaved = 21,
             }
 
             const ENUM_MIN_STREAM_FORMAT: i8 = 0;
-            const ENUM_MAX_STREAM_FORMAT: i8 = 6;
+            const ENUM_MAX_STREAM_FORMAT: i8 = 21;
 
             impl<'a> flatbuffers::Follow<'a> for StreamFormat {
                 type Inner = Self;
@@ -81,7 +96,7 @@ pub mod service {
             }
 
             #[allow(non_camel_case_types)]
-            const ENUM_VALUES_STREAM_FORMAT: [StreamFormat; 7] = [
+            const ENUM_VALUES_STREAM_FORMAT: [StreamFormat; 22] = [
                 StreamFormat::Invalid,
                 StreamFormat::Float,
                 StreamFormat::Vector4,
@@ -89,11 +104,47 @@ pub mod service {
                 StreamFormat::Vector2,
                 StreamFormat::Int,
                 StreamFormat::Int3,
+                StreamFormat::Meshlet,
+                StreamFormat::Half2,
+                StreamFormat::Half4,
+                StreamFormat::Snorm8x4,
+                StreamFormat::Unorm8x4,
+                StreamFormat::Snorm16x2,
+                StreamFormat::Snorm16x4,
+                StreamFormat::Unorm16x2,
+                StreamFormat::Unorm16x4,
+                StreamFormat::Octahedral8,
+                StreamFormat::Octahedral16,
+                StreamFormat::Snorm1010102,
+                StreamFormat::Unorm1010102,
+                StreamFormat::Short,
+                StreamFormat::Interleaved,
             ];
 
             #[allow(non_camel_case_types)]
-            const ENUM_NAMES_STREAM_FORMAT: [&'static str; 7] = [
-                "Invalid", "Float", "Vector4", "Vector3", "Vector2", "Int", "Int3",
+            const ENUM_NAMES_STREAM_FORMAT: [&'static str; 22] = [
+                "Invalid",
+                "Float",
+                "Vector4",
+                "Vector3",
+                "Vector2",
+                "Int",
+                "Int3",
+                "Meshlet",
+                "Half2",
+                "Half4",
+                "Snorm8x4",
+                "Unorm8x4",
+                "Snorm16x2",
+                "Snorm16x4",
+                "Unorm16x2",
+                "Unorm16x4",
+                "Octahedral8",
+                "Octahedral16",
+                "Snorm1010102",
+                "Unorm1010102",
+                "Short",
+                "Interleaved",
             ];
 
             pub fn enum_name_stream_format(e: StreamFormat) -> &'static str {
@@ -112,10 +163,14 @@ pub mod service {
                 TextureCoordinates = 4,
                 Colors = 5,
                 Indices = 6,
+                Meshlets = 7,
+                MeshletVertices = 8,
+                MeshletTriangles = 9,
+                Interleaved = 10,
             }
 
             const ENUM_MIN_STREAM_TYPE: i8 = 0;
-            const ENUM_MAX_STREAM_TYPE: i8 = 6;
+            const ENUM_MAX_STREAM_TYPE: i8 = 10;
 
             impl<'a> flatbuffers::Follow<'a> for StreamType {
                 type Inner = Self;
@@ -149,7 +204,7 @@ pub mod service {
             }
 
             #[allow(non_camel_case_types)]
-            const ENUM_VALUES_STREAM_TYPE: [StreamType; 7] = [
+            const ENUM_VALUES_STREAM_TYPE: [StreamType; 11] = [
                 StreamType::Positions,
                 StreamType::Normals,
                 StreamType::Tangents,
@@ -157,10 +212,14 @@ pub mod service {
                 StreamType::TextureCoordinates,
                 StreamType::Colors,
                 StreamType::Indices,
+                StreamType::Meshlets,
+                StreamType::MeshletVertices,
+                StreamType::MeshletTriangles,
+                StreamType::Interleaved,
             ];
 
             #[allow(non_camel_case_types)]
-            const ENUM_NAMES_STREAM_TYPE: [&'static str; 7] = [
+            const ENUM_NAMES_STREAM_TYPE: [&'static str; 11] = [
                 "Positions",
                 "Normals",
                 "Tangents",
@@ -168,6 +227,10 @@ pub mod service {
                 "TextureCoordinates",
                 "Colors",
                 "Indices",
+                "Meshlets",
+                "MeshletVertices",
+                "MeshletTriangles",
+                "Interleaved",
             ];
 
             pub fn enum_name_stream_type(e: StreamType) -> &'static str {
@@ -175,6 +238,177 @@ pub mod service {
                 ENUM_NAMES_STREAM_TYPE[index]
             }
 
+            #[allow(non_camel_case_types)]
+            #[repr(i8)]
+            #[derive(Clone, Copy, PartialEq, Debug)]
+            pub enum StreamCompression {
+                None = 0,
+                MeshoptVertex = 1,
+                MeshoptIndex = 2,
+                Zlib = 3,
+            }
+
+            const ENUM_MIN_STREAM_COMPRESSION: i8 = 0;
+            const ENUM_MAX_STREAM_COMPRESSION: i8 = 3;
+
+            impl<'a> flatbuffers::Follow<'a> for StreamCompression {
+                type Inner = Self;
+                #[inline]
+                fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                    flatbuffers::read_scalar_at::<Self>(buf, loc)
+                }
+            }
+
+            impl flatbuffers::EndianScalar for StreamCompression {
+                #[inline]
+                fn to_little_endian(self) -> Self {
+                    let n = i8::to_le(self as i8);
+                    let p = &n as *const i8 as *const StreamCompression;
+                    unsafe { *p }
+                }
+                #[inline]
+                fn from_little_endian(self) -> Self {
+                    let n = i8::from_le(self as i8);
+                    let p = &n as *const i8 as *const StreamCompression;
+                    unsafe { *p }
+                }
+            }
+
+            impl flatbuffers::Push for StreamCompression {
+                type Output = StreamCompression;
+                #[inline]
+                fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+                    flatbuffers::emplace_scalar::<StreamCompression>(dst, *self);
+                }
+            }
+
+            #[allow(non_camel_case_types)]
+            const ENUM_VALUES_STREAM_COMPRESSION: [StreamCompression; 4] = [
+                StreamCompression::None,
+                StreamCompression::MeshoptVertex,
+                StreamCompression::MeshoptIndex,
+                StreamCompression::Zlib,
+            ];
+
+            #[allow(non_camel_case_types)]
+            const ENUM_NAMES_STREAM_COMPRESSION: [&'static str; 4] =
+                ["None", "MeshoptVertex", "MeshoptIndex", "Zlib"];
+
+            pub fn enum_name_stream_compression(e: StreamCompression) -> &'static str {
+                let index: usize = e as usize;
+                ENUM_NAMES_STREAM_COMPRESSION[index]
+            }
+
+            #[allow(non_camel_case_types)]
+            #[repr(i8)]
+            #[derive(Clone, Copy, PartialEq, Debug)]
+            pub enum AlphaMode {
+                Opaque = 0,
+                Mask = 1,
+                Blend = 2,
+            }
+
+            const ENUM_MIN_ALPHA_MODE: i8 = 0;
+            const ENUM_MAX_ALPHA_MODE: i8 = 2;
+
+            impl<'a> flatbuffers::Follow<'a> for AlphaMode {
+                type Inner = Self;
+                #[inline]
+                fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                    flatbuffers::read_scalar_at::<Self>(buf, loc)
+                }
+            }
+
+            impl flatbuffers::EndianScalar for AlphaMode {
+                #[inline]
+                fn to_little_endian(self) -> Self {
+                    let n = i8::to_le(self as i8);
+                    let p = &n as *const i8 as *const AlphaMode;
+                    unsafe { *p }
+                }
+                #[inline]
+                fn from_little_endian(self) -> Self {
+                    let n = i8::from_le(self as i8);
+                    let p = &n as *const i8 as *const AlphaMode;
+                    unsafe { *p }
+                }
+            }
+
+            impl flatbuffers::Push for AlphaMode {
+                type Output = AlphaMode;
+                #[inline]
+                fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+                    flatbuffers::emplace_scalar::<AlphaMode>(dst, *self);
+                }
+            }
+
+            #[allow(non_camel_case_types)]
+            const ENUM_VALUES_ALPHA_MODE: [AlphaMode; 3] =
+                [AlphaMode::Opaque, AlphaMode::Mask, AlphaMode::Blend];
+
+            #[allow(non_camel_case_types)]
+            const ENUM_NAMES_ALPHA_MODE: [&'static str; 3] = ["Opaque", "Mask", "Blend"];
+
+            pub fn enum_name_alpha_mode(e: AlphaMode) -> &'static str {
+                let index: usize = e as usize;
+                ENUM_NAMES_ALPHA_MODE[index]
+            }
+
+            #[allow(non_camel_case_types)]
+            #[repr(i8)]
+            #[derive(Clone, Copy, PartialEq, Debug)]
+            pub enum Topology {
+                Triangles = 0,
+                Lines = 1,
+                Points = 2,
+            }
+
+            const ENUM_MIN_TOPOLOGY: i8 = 0;
+            const ENUM_MAX_TOPOLOGY: i8 = 2;
+
+            impl<'a> flatbuffers::Follow<'a> for Topology {
+                type Inner = Self;
+                #[inline]
+                fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                    flatbuffers::read_scalar_at::<Self>(buf, loc)
+                }
+            }
+
+            impl flatbuffers::EndianScalar for Topology {
+                #[inline]
+                fn to_little_endian(self) -> Self {
+                    let n = i8::to_le(self as i8);
+                    let p = &n as *const i8 as *const Topology;
+                    unsafe { *p }
+                }
+                #[inline]
+                fn from_little_endian(self) -> Self {
+                    let n = i8::from_le(self as i8);
+                    let p = &n as *const i8 as *const Topology;
+                    unsafe { *p }
+                }
+            }
+
+            impl flatbuffers::Push for Topology {
+                type Output = Topology;
+                #[inline]
+                fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+                    flatbuffers::emplace_scalar::<Topology>(dst, *self);
+                }
+            }
+
+            #[allow(non_camel_case_types)]
+            const ENUM_VALUES_TOPOLOGY: [Topology; 3] =
+                [Topology::Triangles, Topology::Lines, Topology::Points];
+
+            #[allow(non_camel_case_types)]
+            const ENUM_NAMES_TOPOLOGY: [&'static str; 3] = ["Triangles", "Lines", "Points"];
+
+            pub fn enum_name_topology(e: Topology) -> &'static str {
+                let index: usize = e as usize;
+                ENUM_NAMES_TOPOLOGY[index]
+            }
+
             #[allow(non_camel_case_types)]
             #[repr(i8)]
             #[derive(Clone, Copy, PartialEq, Debug)]
@@ -184,64 +418,2169 @@ pub mod service {
                 Skinned = 2,
             }
 
-            const ENUM_MIN_ANIMATION_TYPE: i8 = 0;
-            const ENUM_MAX_ANIMATION_TYPE: i8 = 2;
+            const ENUM_MIN_ANIMATION_TYPE: i8 = 0;
+            const ENUM_MAX_ANIMATION_TYPE: i8 = 2;
+
+            impl<'a> flatbuffers::Follow<'a> for AnimationType {
+                type Inner = Self;
+                #[inline]
+                fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                    flatbuffers::read_scalar_at::<Self>(buf, loc)
+                }
+            }
+
+            impl flatbuffers::EndianScalar for AnimationType {
+                #[inline]
+                fn to_little_endian(self) -> Self {
+                    let n = i8::to_le(self as i8);
+                    let p = &n as *const i8 as *const AnimationType;
+                    unsafe { *p }
+                }
+                #[inline]
+                fn from_little_endian(self) -> Self {
+                    let n = i8::from_le(self as i8);
+                    let p = &n as *const i8 as *const AnimationType;
+                    unsafe { *p }
+                }
+            }
+
+            impl flatbuffers::Push for AnimationType {
+                type Output = AnimationType;
+                #[inline]
+                fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+                    flatbuffers::emplace_scalar::<AnimationType>(dst, *self);
+                }
+            }
+
+            #[allow(non_camel_case_types)]
+            const ENUM_VALUES_ANIMATION_TYPE: [AnimationType; 3] = [
+                AnimationType::None,
+                AnimationType::Rigid,
+                AnimationType::Skinned,
+            ];
+
+            #[allow(non_camel_case_types)]
+            const ENUM_NAMES_ANIMATION_TYPE: [&'static str; 3] = ["None", "Rigid", "Skinned"];
+
+            pub fn enum_name_animation_type(e: AnimationType) -> &'static str {
+                let index: usize = e as usize;
+                ENUM_NAMES_ANIMATION_TYPE[index]
+            }
+
+            #[allow(non_camel_case_types)]
+            #[repr(i8)]
+            #[derive(Clone, Copy, PartialEq, Debug)]
+            pub enum Interpolation {
+                Linear = 0,
+                Step = 1,
+                CatmullRomSpline = 2,
+                CubicSpline = 3,
+            }
+
+            const ENUM_MIN_INTERPOLATION: i8 = 0;
+            const ENUM_MAX_INTERPOLATION: i8 = 3;
+
+            impl<'a> flatbuffers::Follow<'a> for Interpolation {
+                type Inner = Self;
+                #[inline]
+                fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                    flatbuffers::read_scalar_at::<Self>(buf, loc)
+                }
+            }
+
+            impl flatbuffers::EndianScalar for Interpolation {
+                #[inline]
+                fn to_little_endian(self) -> Self {
+                    let n = i8::to_le(self as i8);
+                    let p = &n as *const i8 as *const Interpolation;
+                    unsafe { *p }
+                }
+                #[inline]
+                fn from_little_endian(self) -> Self {
+                    let n = i8::from_le(self as i8);
+                    let p = &n as *const i8 as *const Interpolation;
+                    unsafe { *p }
+                }
+            }
+
+            impl flatbuffers::Push for Interpolation {
+                type Output = Interpolation;
+                #[inline]
+                fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+                    flatbuffers::emplace_scalar::<Interpolation>(dst, *self);
+                }
+            }
+
+            #[allow(non_camel_case_types)]
+            const ENUM_VALUES_INTERPOLATION: [Interpolation; 4] = [
+                Interpolation::Linear,
+                Interpolation::Step,
+                Interpolation::CatmullRomSpline,
+                Interpolation::CubicSpline,
+            ];
+
+            #[allow(non_camel_case_types)]
+            const ENUM_NAMES_INTERPOLATION: [&'static str; 4] =
+                ["Linear", "Step", "CatmullRomSpline", "CubicSpline"];
+
+            pub fn enum_name_interpolation(e: Interpolation) -> &'static str {
+                let index: usize = e as usize;
+                ENUM_NAMES_INTERPOLATION[index]
+            }
+
+            pub enum AnimationSamplerOffset {}
+            #[derive(Copy, Clone, Debug, PartialEq)]
+
+            pub struct AnimationSampler<'a> {
+                pub _tab: flatbuffers::Table<'a>,
+            }
+
+            impl<'a> flatbuffers::Follow<'a> for AnimationSampler<'a> {
+                type Inner = AnimationSampler<'a>;
+                #[inline]
+                fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                    Self {
+                        _tab: flatbuffers::Table { buf: buf, loc: loc },
+                    }
+                }
+            }
+
+            impl<'a> AnimationSampler<'a> {
+                #[inline]
+                pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+                    AnimationSampler { _tab: table }
+                }
+                #[allow(unused_mut)]
+                pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+                    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+                    args: &'args AnimationSamplerArgs<'args>,
+                ) -> flatbuffers::WIPOffset<AnimationSampler<'bldr>> {
+                    let mut builder = AnimationSamplerBuilder::new(_fbb);
+                    if let Some(x) = args.values {
+                        builder.add_values(x);
+                    }
+                    if let Some(x) = args.times {
+                        builder.add_times(x);
+                    }
+                    builder.add_interpolation(args.interpolation);
+                    builder.finish()
+                }
+
+                pub const VT_INTERPOLATION: flatbuffers::VOffsetT = 4;
+                pub const VT_TIMES: flatbuffers::VOffsetT = 6;
+                pub const VT_VALUES: flatbuffers::VOffsetT = 8;
+
+                #[inline]
+                pub fn interpolation(&self) -> Interpolation {
+                    self._tab
+                        .get::<Interpolation>(
+                            AnimationSampler::VT_INTERPOLATION,
+                            Some(Interpolation::Linear),
+                        )
+                        .unwrap()
+                }
+                #[inline]
+                pub fn times(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                            AnimationSampler::VT_TIMES,
+                            None,
+                        )
+                }
+                #[inline]
+                pub fn values(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                            AnimationSampler::VT_VALUES,
+                            None,
+                        )
+                }
+            }
+
+            pub struct AnimationSamplerArgs<'a> {
+                pub interpolation: Interpolation,
+                pub times: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+                pub values: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+            }
+            impl<'a> Default for AnimationSamplerArgs<'a> {
+                #[inline]
+                fn default() -> Self {
+                    AnimationSamplerArgs {
+                        interpolation: Interpolation::Linear,
+                        times: None,
+                        values: None,
+                    }
+                }
+            }
+            pub struct AnimationSamplerBuilder<'a: 'b, 'b> {
+                fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+            }
+            impl<'a: 'b, 'b> AnimationSamplerBuilder<'a, 'b> {
+                #[inline]
+                pub fn add_interpolation(&mut self, interpolation: Interpolation) {
+                    self.fbb_.push_slot::<Interpolation>(
+                        AnimationSampler::VT_INTERPOLATION,
+                        interpolation,
+                        Interpolation::Linear,
+                    );
+                }
+                #[inline]
+                pub fn add_times(
+                    &mut self,
+                    times: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>,
+                ) {
+                    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                        AnimationSampler::VT_TIMES,
+                        times,
+                    );
+                }
+                #[inline]
+                pub fn add_values(
+                    &mut self,
+                    values: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>,
+                ) {
+                    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                        AnimationSampler::VT_VALUES,
+                        values,
+                    );
+                }
+                #[inline]
+                pub fn new(
+                    _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                ) -> AnimationSamplerBuilder<'a, 'b> {
+                    let start = _fbb.start_table();
+                    AnimationSamplerBuilder {
+                        fbb_: _fbb,
+                        start_: start,
+                    }
+                }
+                #[inline]
+                pub fn finish(self) -> flatbuffers::WIPOffset<AnimationSampler<'a>> {
+                    let o = self.fbb_.end_table(self.start_);
+                    flatbuffers::WIPOffset::new(o.value())
+                }
+            }
+
+            pub enum AnimationChannelOffset {}
+            #[derive(Copy, Clone, Debug, PartialEq)]
+
+            pub struct AnimationChannel<'a> {
+                pub _tab: flatbuffers::Table<'a>,
+            }
+
+            impl<'a> flatbuffers::Follow<'a> for AnimationChannel<'a> {
+                type Inner = AnimationChannel<'a>;
+                #[inline]
+                fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                    Self {
+                        _tab: flatbuffers::Table { buf: buf, loc: loc },
+                    }
+                }
+            }
+
+            impl<'a> AnimationChannel<'a> {
+                #[inline]
+                pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+                    AnimationChannel { _tab: table }
+                }
+                #[allow(unused_mut)]
+                pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+                    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+                    args: &'args AnimationChannelArgs<'args>,
+                ) -> flatbuffers::WIPOffset<AnimationChannel<'bldr>> {
+                    let mut builder = AnimationChannelBuilder::new(_fbb);
+                    if let Some(x) = args.scale {
+                        builder.add_scale(x);
+                    }
+                    if let Some(x) = args.rotation {
+                        builder.add_rotation(x);
+                    }
+                    if let Some(x) = args.translation {
+                        builder.add_translation(x);
+                    }
+                    builder.add_joint(args.joint);
+                    if let Some(x) = args.node {
+                        builder.add_node(x);
+                    }
+                    builder.finish()
+                }
+
+                pub const VT_NODE: flatbuffers::VOffsetT = 4;
+                pub const VT_JOINT: flatbuffers::VOffsetT = 6;
+                pub const VT_TRANSLATION: flatbuffers::VOffsetT = 8;
+                pub const VT_ROTATION: flatbuffers::VOffsetT = 10;
+                pub const VT_SCALE: flatbuffers::VOffsetT = 12;
+
+                #[inline]
+                pub fn node(&self) -> Option<&'a str> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<&str>>(AnimationChannel::VT_NODE, None)
+                }
+                #[inline]
+                pub fn joint(&self) -> i32 {
+                    self._tab
+                        .get::<i32>(AnimationChannel::VT_JOINT, Some(-1))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn translation(&self) -> Option<AnimationSampler<'a>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<AnimationSampler<'a>>>(
+                            AnimationChannel::VT_TRANSLATION,
+                            None,
+                        )
+                }
+                #[inline]
+                pub fn rotation(&self) -> Option<AnimationSampler<'a>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<AnimationSampler<'a>>>(
+                            AnimationChannel::VT_ROTATION,
+                            None,
+                        )
+                }
+                #[inline]
+                pub fn scale(&self) -> Option<AnimationSampler<'a>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<AnimationSampler<'a>>>(
+                            AnimationChannel::VT_SCALE,
+                            None,
+                        )
+                }
+            }
+
+            pub struct AnimationChannelArgs<'a> {
+                pub node: Option<flatbuffers::WIPOffset<&'a str>>,
+                pub joint: i32,
+                pub translation: Option<flatbuffers::WIPOffset<AnimationSampler<'a>>>,
+                pub rotation: Option<flatbuffers::WIPOffset<AnimationSampler<'a>>>,
+                pub scale: Option<flatbuffers::WIPOffset<AnimationSampler<'a>>>,
+            }
+            impl<'a> Default for AnimationChannelArgs<'a> {
+                #[inline]
+                fn default() -> Self {
+                    AnimationChannelArgs {
+                        node: None,
+                        joint: -1,
+                        translation: None,
+                        rotation: None,
+                        scale: None,
+                    }
+                }
+            }
+            pub struct AnimationChannelBuilder<'a: 'b, 'b> {
+                fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+            }
+            impl<'a: 'b, 'b> AnimationChannelBuilder<'a, 'b> {
+                #[inline]
+                pub fn add_node(&mut self, node: flatbuffers::WIPOffset<&'b str>) {
+                    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                        AnimationChannel::VT_NODE,
+                        node,
+                    );
+                }
+                #[inline]
+                pub fn add_joint(&mut self, joint: i32) {
+                    self.fbb_
+                        .push_slot::<i32>(AnimationChannel::VT_JOINT, joint, -1);
+                }
+                #[inline]
+                pub fn add_translation(
+                    &mut self,
+                    translation: flatbuffers::WIPOffset<AnimationSampler<'b>>,
+                ) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<AnimationSampler>>(
+                            AnimationChannel::VT_TRANSLATION,
+                            translation,
+                        );
+                }
+                #[inline]
+                pub fn add_rotation(
+                    &mut self,
+                    rotation: flatbuffers::WIPOffset<AnimationSampler<'b>>,
+                ) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<AnimationSampler>>(
+                            AnimationChannel::VT_ROTATION,
+                            rotation,
+                        );
+                }
+                #[inline]
+                pub fn add_scale(&mut self, scale: flatbuffers::WIPOffset<AnimationSampler<'b>>) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<AnimationSampler>>(
+                            AnimationChannel::VT_SCALE,
+                            scale,
+                        );
+                }
+                #[inline]
+                pub fn new(
+                    _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                ) -> AnimationChannelBuilder<'a, 'b> {
+                    let start = _fbb.start_table();
+                    AnimationChannelBuilder {
+                        fbb_: _fbb,
+                        start_: start,
+                    }
+                }
+                #[inline]
+                pub fn finish(self) -> flatbuffers::WIPOffset<AnimationChannel<'a>> {
+                    let o = self.fbb_.end_table(self.start_);
+                    flatbuffers::WIPOffset::new(o.value())
+                }
+            }
+
+            pub enum AnimationOffset {}
+            #[derive(Copy, Clone, Debug, PartialEq)]
+
+            pub struct Animation<'a> {
+                pub _tab: flatbuffers::Table<'a>,
+            }
+
+            impl<'a> flatbuffers::Follow<'a> for Animation<'a> {
+                type Inner = Animation<'a>;
+                #[inline]
+                fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                    Self {
+                        _tab: flatbuffers::Table { buf: buf, loc: loc },
+                    }
+                }
+            }
+
+            impl<'a> Animation<'a> {
+                #[inline]
+                pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+                    Animation { _tab: table }
+                }
+                #[allow(unused_mut)]
+                pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+                    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+                    args: &'args AnimationArgs<'args>,
+                ) -> flatbuffers::WIPOffset<Animation<'bldr>> {
+                    let mut builder = AnimationBuilder::new(_fbb);
+                    if let Some(x) = args.channels {
+                        builder.add_channels(x);
+                    }
+                    builder.add_duration(args.duration);
+                    builder.add_start(args.start);
+                    if let Some(x) = args.name {
+                        builder.add_name(x);
+                    }
+                    builder.finish()
+                }
+
+                pub const VT_NAME: flatbuffers::VOffsetT = 4;
+                pub const VT_START: flatbuffers::VOffsetT = 6;
+                pub const VT_DURATION: flatbuffers::VOffsetT = 8;
+                pub const VT_CHANNELS: flatbuffers::VOffsetT = 10;
+
+                #[inline]
+                pub fn name(&self) -> Option<&'a str> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<&str>>(Animation::VT_NAME, None)
+                }
+                #[inline]
+                pub fn start(&self) -> f32 {
+                    self._tab
+                        .get::<f32>(Animation::VT_START, Some(0.0))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn duration(&self) -> f32 {
+                    self._tab
+                        .get::<f32>(Animation::VT_DURATION, Some(0.0))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn channels(
+                    &self,
+                ) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<AnimationChannel<'a>>>>
+                {
+                    self._tab.get::<flatbuffers::ForwardsUOffset<
+                        flatbuffers::Vector<flatbuffers::ForwardsUOffset<AnimationChannel<'a>>>,
+                    >>(Animation::VT_CHANNELS, None)
+                }
+            }
+
+            pub struct AnimationArgs<'a> {
+                pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+                pub start: f32,
+                pub duration: f32,
+                pub channels: Option<
+                    flatbuffers::WIPOffset<
+                        flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationChannel<'a>>>,
+                    >,
+                >,
+            }
+            impl<'a> Default for AnimationArgs<'a> {
+                #[inline]
+                fn default() -> Self {
+                    AnimationArgs {
+                        name: None,
+                        start: 0.0,
+                        duration: 0.0,
+                        channels: None,
+                    }
+                }
+            }
+            pub struct AnimationBuilder<'a: 'b, 'b> {
+                fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+            }
+            impl<'a: 'b, 'b> AnimationBuilder<'a, 'b> {
+                #[inline]
+                pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<_>>(Animation::VT_NAME, name);
+                }
+                #[inline]
+                pub fn add_start(&mut self, start: f32) {
+                    self.fbb_.push_slot::<f32>(Animation::VT_START, start, 0.0);
+                }
+                #[inline]
+                pub fn add_duration(&mut self, duration: f32) {
+                    self.fbb_
+                        .push_slot::<f32>(Animation::VT_DURATION, duration, 0.0);
+                }
+                #[inline]
+                pub fn add_channels(
+                    &mut self,
+                    channels: flatbuffers::WIPOffset<
+                        flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<AnimationChannel<'b>>>,
+                    >,
+                ) {
+                    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                        Animation::VT_CHANNELS,
+                        channels,
+                    );
+                }
+                #[inline]
+                pub fn new(
+                    _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                ) -> AnimationBuilder<'a, 'b> {
+                    let start = _fbb.start_table();
+                    AnimationBuilder {
+                        fbb_: _fbb,
+                        start_: start,
+                    }
+                }
+                #[inline]
+                pub fn finish(self) -> flatbuffers::WIPOffset<Animation<'a>> {
+                    let o = self.fbb_.end_table(self.start_);
+                    flatbuffers::WIPOffset::new(o.value())
+                }
+            }
+
+            pub enum MeshStreamOffset {}
+            #[derive(Copy, Clone, Debug, PartialEq)]
+
+            pub struct MeshStream<'a> {
+                pub _tab: flatbuffers::Table<'a>,
+            }
+
+            impl<'a> flatbuffers::Follow<'a> for MeshStream<'a> {
+                type Inner = MeshStream<'a>;
+                #[inline]
+                fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                    Self {
+                        _tab: flatbuffers::Table { buf: buf, loc: loc },
+                    }
+                }
+            }
+
+            impl<'a> MeshStream<'a> {
+                #[inline]
+                pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+                    MeshStream { _tab: table }
+                }
+                #[allow(unused_mut)]
+                pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+                    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+                    args: &'args MeshStreamArgs<'args>,
+                ) -> flatbuffers::WIPOffset<MeshStream<'bldr>> {
+                    let mut builder = MeshStreamBuilder::new(_fbb);
+                    builder.add_decoded_size(args.decoded_size);
+                    builder.add_elements(args.elements);
+                    if let Some(x) = args.attributes {
+                        builder.add_attributes(x);
+                    }
+                    if let Some(x) = args.scale {
+                        builder.add_scale(x);
+                    }
+                    if let Some(x) = args.offset {
+                        builder.add_offset(x);
+                    }
+                    if let Some(x) = args.data {
+                        builder.add_data(x);
+                    }
+                    builder.add_compression(args.compression);
+                    builder.add_format(args.format);
+                    builder.add_type_(args.type_);
+                    builder.finish()
+                }
+
+                pub const VT_TYPE_: flatbuffers::VOffsetT = 4;
+                pub const VT_FORMAT: flatbuffers::VOffsetT = 6;
+                pub const VT_ELEMENTS: flatbuffers::VOffsetT = 8;
+                pub const VT_DATA: flatbuffers::VOffsetT = 10;
+                pub const VT_OFFSET: flatbuffers::VOffsetT = 12;
+                pub const VT_SCALE: flatbuffers::VOffsetT = 14;
+                pub const VT_ATTRIBUTES: flatbuffers::VOffsetT = 16;
+                pub const VT_COMPRESSION: flatbuffers::VOffsetT = 18;
+                pub const VT_DECODED_SIZE: flatbuffers::VOffsetT = 20;
+
+                #[inline]
+                pub fn type_(&self) -> StreamType {
+                    self._tab
+                        .get::<StreamType>(MeshStream::VT_TYPE_, Some(StreamType::Positions))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn format(&self) -> StreamFormat {
+                    self._tab
+                        .get::<StreamFormat>(MeshStream::VT_FORMAT, Some(StreamFormat::Invalid))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn elements(&self) -> u64 {
+                    self._tab
+                        .get::<u64>(MeshStream::VT_ELEMENTS, Some(0))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn data(&self) -> Option<&'a [u8]> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                            MeshStream::VT_DATA,
+                            None,
+                        )
+                        .map(|v| v.safe_slice())
+                }
+                #[inline]
+                pub fn offset(&self) -> Option<Vector3<'a>> {
+                    self._tab.get::<flatbuffers::ForwardsUOffset<Vector3<'a>>>(
+                        MeshStream::VT_OFFSET,
+                        None,
+                    )
+                }
+                #[inline]
+                pub fn scale(&self) -> Option<Vector3<'a>> {
+                    self._tab.get::<flatbuffers::ForwardsUOffset<Vector3<'a>>>(
+                        MeshStream::VT_SCALE,
+                        None,
+                    )
+                }
+                #[inline]
+                pub fn attributes(
+                    &self,
+                ) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<VertexAttribute<'a>>>>
+                {
+                    self._tab.get::<flatbuffers::ForwardsUOffset<
+                        flatbuffers::Vector<flatbuffers::ForwardsUOffset<VertexAttribute<'a>>>,
+                    >>(MeshStream::VT_ATTRIBUTES, None)
+                }
+                #[inline]
+                pub fn compression(&self) -> StreamCompression {
+                    self._tab
+                        .get::<StreamCompression>(
+                            MeshStream::VT_COMPRESSION,
+                            Some(StreamCompression::None),
+                        )
+                        .unwrap()
+                }
+                #[inline]
+                pub fn decoded_size(&self) -> u64 {
+                    self._tab
+                        .get::<u64>(MeshStream::VT_DECODED_SIZE, Some(0))
+                        .unwrap()
+                }
+            }
+
+            pub struct MeshStreamArgs<'a> {
+                pub type_: StreamType,
+                pub format: StreamFormat,
+                pub elements: u64,
+                pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+                pub offset: Option<flatbuffers::WIPOffset<Vector3<'a>>>,
+                pub scale: Option<flatbuffers::WIPOffset<Vector3<'a>>>,
+                pub attributes: Option<
+                    flatbuffers::WIPOffset<
+                        flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<VertexAttribute<'a>>>,
+                    >,
+                >,
+                pub compression: StreamCompression,
+                pub decoded_size: u64,
+            }
+            impl<'a> Default for MeshStreamArgs<'a> {
+                #[inline]
+                fn default() -> Self {
+                    MeshStreamArgs {
+                        type_: StreamType::Positions,
+                        format: StreamFormat::Invalid,
+                        elements: 0,
+                        data: None,
+                        offset: None,
+                        scale: None,
+                        attributes: None,
+                        compression: StreamCompression::None,
+                        decoded_size: 0,
+                    }
+                }
+            }
+            pub struct MeshStreamBuilder<'a: 'b, 'b> {
+                fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+            }
+            impl<'a: 'b, 'b> MeshStreamBuilder<'a, 'b> {
+                #[inline]
+                pub fn add_type_(&mut self, type_: StreamType) {
+                    self.fbb_.push_slot::<StreamType>(
+                        MeshStream::VT_TYPE_,
+                        type_,
+                        StreamType::Positions,
+                    );
+                }
+                #[inline]
+                pub fn add_format(&mut self, format: StreamFormat) {
+                    self.fbb_.push_slot::<StreamFormat>(
+                        MeshStream::VT_FORMAT,
+                        format,
+                        StreamFormat::Invalid,
+                    );
+                }
+                #[inline]
+                pub fn add_elements(&mut self, elements: u64) {
+                    self.fbb_
+                        .push_slot::<u64>(MeshStream::VT_ELEMENTS, elements, 0);
+                }
+                #[inline]
+                pub fn add_data(
+                    &mut self,
+                    data: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>,
+                ) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<_>>(MeshStream::VT_DATA, data);
+                }
+                #[inline]
+                pub fn add_offset(&mut self, offset: flatbuffers::WIPOffset<Vector3<'b>>) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<Vector3>>(
+                            MeshStream::VT_OFFSET,
+                            offset,
+                        );
+                }
+                #[inline]
+                pub fn add_scale(&mut self, scale: flatbuffers::WIPOffset<Vector3<'b>>) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<Vector3>>(
+                            MeshStream::VT_SCALE,
+                            scale,
+                        );
+                }
+                #[inline]
+                pub fn add_attributes(
+                    &mut self,
+                    attributes: flatbuffers::WIPOffset<
+                        flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<VertexAttribute<'b>>>,
+                    >,
+                ) {
+                    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                        MeshStream::VT_ATTRIBUTES,
+                        attributes,
+                    );
+                }
+                #[inline]
+                pub fn add_compression(&mut self, compression: StreamCompression) {
+                    self.fbb_.push_slot::<StreamCompression>(
+                        MeshStream::VT_COMPRESSION,
+                        compression,
+                        StreamCompression::None,
+                    );
+                }
+                #[inline]
+                pub fn add_decoded_size(&mut self, decoded_size: u64) {
+                    self.fbb_
+                        .push_slot::<u64>(MeshStream::VT_DECODED_SIZE, decoded_size, 0);
+                }
+                #[inline]
+                pub fn new(
+                    _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                ) -> MeshStreamBuilder<'a, 'b> {
+                    let start = _fbb.start_table();
+                    MeshStreamBuilder {
+                        fbb_: _fbb,
+                        start_: start,
+                    }
+                }
+                #[inline]
+                pub fn finish(self) -> flatbuffers::WIPOffset<MeshStream<'a>> {
+                    let o = self.fbb_.end_table(self.start_);
+                    flatbuffers::WIPOffset::new(o.value())
+                }
+            }
+
+            pub enum VertexAttributeOffset {}
+            #[derive(Copy, Clone, Debug, PartialEq)]
+
+            pub struct VertexAttribute<'a> {
+                pub _tab: flatbuffers::Table<'a>,
+            }
+
+            impl<'a> flatbuffers::Follow<'a> for VertexAttribute<'a> {
+                type Inner = VertexAttribute<'a>;
+                #[inline]
+                fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                    Self {
+                        _tab: flatbuffers::Table { buf: buf, loc: loc },
+                    }
+                }
+            }
+
+            impl<'a> VertexAttribute<'a> {
+                #[inline]
+                pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+                    VertexAttribute { _tab: table }
+                }
+                #[allow(unused_mut)]
+                pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+                    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+                    args: &'args VertexAttributeArgs<'args>,
+                ) -> flatbuffers::WIPOffset<VertexAttribute<'bldr>> {
+                    let mut builder = VertexAttributeBuilder::new(_fbb);
+                    if let Some(x) = args.decode_scale {
+                        builder.add_decode_scale(x);
+                    }
+                    if let Some(x) = args.decode_offset {
+                        builder.add_decode_offset(x);
+                    }
+                    builder.add_stride(args.stride);
+                    builder.add_offset(args.offset);
+                    builder.add_format(args.format);
+                    builder.add_semantic(args.semantic);
+                    builder.finish()
+                }
+
+                pub const VT_SEMANTIC: flatbuffers::VOffsetT = 4;
+                pub const VT_FORMAT: flatbuffers::VOffsetT = 6;
+                pub const VT_OFFSET: flatbuffers::VOffsetT = 8;
+                pub const VT_STRIDE: flatbuffers::VOffsetT = 10;
+                pub const VT_DECODE_OFFSET: flatbuffers::VOffsetT = 12;
+                pub const VT_DECODE_SCALE: flatbuffers::VOffsetT = 14;
+
+                #[inline]
+                pub fn semantic(&self) -> StreamType {
+                    self._tab
+                        .get::<StreamType>(
+                            VertexAttribute::VT_SEMANTIC,
+                            Some(StreamType::Positions),
+                        )
+                        .unwrap()
+                }
+                #[inline]
+                pub fn format(&self) -> StreamFormat {
+                    self._tab
+                        .get::<StreamFormat>(
+                            VertexAttribute::VT_FORMAT,
+                            Some(StreamFormat::Invalid),
+                        )
+                        .unwrap()
+                }
+                #[inline]
+                pub fn offset(&self) -> u32 {
+                    self._tab
+                        .get::<u32>(VertexAttribute::VT_OFFSET, Some(0))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn stride(&self) -> u32 {
+                    self._tab
+                        .get::<u32>(VertexAttribute::VT_STRIDE, Some(0))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn decode_offset(&self) -> Option<Vector3<'a>> {
+                    self._tab.get::<flatbuffers::ForwardsUOffset<Vector3<'a>>>(
+                        VertexAttribute::VT_DECODE_OFFSET,
+                        None,
+                    )
+                }
+                #[inline]
+                pub fn decode_scale(&self) -> Option<Vector3<'a>> {
+                    self._tab.get::<flatbuffers::ForwardsUOffset<Vector3<'a>>>(
+                        VertexAttribute::VT_DECODE_SCALE,
+                        None,
+                    )
+                }
+            }
+
+            pub struct VertexAttributeArgs<'a> {
+                pub semantic: StreamType,
+                pub format: StreamFormat,
+                pub offset: u32,
+                pub stride: u32,
+                pub decode_offset: Option<flatbuffers::WIPOffset<Vector3<'a>>>,
+                pub decode_scale: Option<flatbuffers::WIPOffset<Vector3<'a>>>,
+            }
+            impl<'a> Default for VertexAttributeArgs<'a> {
+                #[inline]
+                fn default() -> Self {
+                    VertexAttributeArgs {
+                        semantic: StreamType::Positions,
+                        format: StreamFormat::Invalid,
+                        offset: 0,
+                        stride: 0,
+                        decode_offset: None,
+                        decode_scale: None,
+                    }
+                }
+            }
+            pub struct VertexAttributeBuilder<'a: 'b, 'b> {
+                fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+            }
+            impl<'a: 'b, 'b> VertexAttributeBuilder<'a, 'b> {
+                #[inline]
+                pub fn add_semantic(&mut self, semantic: StreamType) {
+                    self.fbb_.push_slot::<StreamType>(
+                        VertexAttribute::VT_SEMANTIC,
+                        semantic,
+                        StreamType::Positions,
+                    );
+                }
+                #[inline]
+                pub fn add_format(&mut self, format: StreamFormat) {
+                    self.fbb_.push_slot::<StreamFormat>(
+                        VertexAttribute::VT_FORMAT,
+                        format,
+                        StreamFormat::Invalid,
+                    );
+                }
+                #[inline]
+                pub fn add_offset(&mut self, offset: u32) {
+                    self.fbb_
+                        .push_slot::<u32>(VertexAttribute::VT_OFFSET, offset, 0);
+                }
+                #[inline]
+                pub fn add_stride(&mut self, stride: u32) {
+                    self.fbb_
+                        .push_slot::<u32>(VertexAttribute::VT_STRIDE, stride, 0);
+                }
+                #[inline]
+                pub fn add_decode_offset(
+                    &mut self,
+                    decode_offset: flatbuffers::WIPOffset<Vector3<'b>>,
+                ) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<Vector3>>(
+                            VertexAttribute::VT_DECODE_OFFSET,
+                            decode_offset,
+                        );
+                }
+                #[inline]
+                pub fn add_decode_scale(
+                    &mut self,
+                    decode_scale: flatbuffers::WIPOffset<Vector3<'b>>,
+                ) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<Vector3>>(
+                            VertexAttribute::VT_DECODE_SCALE,
+                            decode_scale,
+                        );
+                }
+                #[inline]
+                pub fn new(
+                    _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                ) -> VertexAttributeBuilder<'a, 'b> {
+                    let start = _fbb.start_table();
+                    VertexAttributeBuilder {
+                        fbb_: _fbb,
+                        start_: start,
+                    }
+                }
+                #[inline]
+                pub fn finish(self) -> flatbuffers::WIPOffset<VertexAttribute<'a>> {
+                    let o = self.fbb_.end_table(self.start_);
+                    flatbuffers::WIPOffset::new(o.value())
+                }
+            }
+
+            pub enum MaterialTextureOffset {}
+            #[derive(Copy, Clone, Debug, PartialEq)]
+
+            pub struct MaterialTexture<'a> {
+                pub _tab: flatbuffers::Table<'a>,
+            }
+
+            impl<'a> flatbuffers::Follow<'a> for MaterialTexture<'a> {
+                type Inner = MaterialTexture<'a>;
+                #[inline]
+                fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                    Self {
+                        _tab: flatbuffers::Table { buf: buf, loc: loc },
+                    }
+                }
+            }
+
+            impl<'a> MaterialTexture<'a> {
+                #[inline]
+                pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+                    MaterialTexture { _tab: table }
+                }
+                #[allow(unused_mut)]
+                pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+                    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+                    args: &'args MaterialTextureArgs<'args>,
+                ) -> flatbuffers::WIPOffset<MaterialTexture<'bldr>> {
+                    let mut builder = MaterialTextureBuilder::new(_fbb);
+                    builder.add_tex_coord(args.tex_coord);
+                    if let Some(x) = args.image {
+                        builder.add_image(x);
+                    }
+                    builder.finish()
+                }
+
+                pub const VT_IMAGE: flatbuffers::VOffsetT = 4;
+                pub const VT_TEX_COORD: flatbuffers::VOffsetT = 6;
+
+                #[inline]
+                pub fn image(&self) -> Option<&'a str> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<&str>>(MaterialTexture::VT_IMAGE, None)
+                }
+                #[inline]
+                pub fn tex_coord(&self) -> u32 {
+                    self._tab
+                        .get::<u32>(MaterialTexture::VT_TEX_COORD, Some(0))
+                        .unwrap()
+                }
+            }
+
+            pub struct MaterialTextureArgs<'a> {
+                pub image: Option<flatbuffers::WIPOffset<&'a str>>,
+                pub tex_coord: u32,
+            }
+            impl<'a> Default for MaterialTextureArgs<'a> {
+                #[inline]
+                fn default() -> Self {
+                    MaterialTextureArgs {
+                        image: None,
+                        tex_coord: 0,
+                    }
+                }
+            }
+            pub struct MaterialTextureBuilder<'a: 'b, 'b> {
+                fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+            }
+            impl<'a: 'b, 'b> MaterialTextureBuilder<'a, 'b> {
+                #[inline]
+                pub fn add_image(&mut self, image: flatbuffers::WIPOffset<&'b str>) {
+                    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                        MaterialTexture::VT_IMAGE,
+                        image,
+                    );
+                }
+                #[inline]
+                pub fn add_tex_coord(&mut self, tex_coord: u32) {
+                    self.fbb_
+                        .push_slot::<u32>(MaterialTexture::VT_TEX_COORD, tex_coord, 0);
+                }
+                #[inline]
+                pub fn new(
+                    _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                ) -> MaterialTextureBuilder<'a, 'b> {
+                    let start = _fbb.start_table();
+                    MaterialTextureBuilder {
+                        fbb_: _fbb,
+                        start_: start,
+                    }
+                }
+                #[inline]
+                pub fn finish(self) -> flatbuffers::WIPOffset<MaterialTexture<'a>> {
+                    let o = self.fbb_.end_table(self.start_);
+                    flatbuffers::WIPOffset::new(o.value())
+                }
+            }
+
+            pub enum MeshMaterialOffset {}
+            #[derive(Copy, Clone, Debug, PartialEq)]
+
+            pub struct MeshMaterial<'a> {
+                pub _tab: flatbuffers::Table<'a>,
+            }
+
+            impl<'a> flatbuffers::Follow<'a> for MeshMaterial<'a> {
+                type Inner = MeshMaterial<'a>;
+                #[inline]
+                fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                    Self {
+                        _tab: flatbuffers::Table { buf: buf, loc: loc },
+                    }
+                }
+            }
+
+            impl<'a> MeshMaterial<'a> {
+                #[inline]
+                pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+                    MeshMaterial { _tab: table }
+                }
+                #[allow(unused_mut)]
+                pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+                    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+                    args: &'args MeshMaterialArgs<'args>,
+                ) -> flatbuffers::WIPOffset<MeshMaterial<'bldr>> {
+                    let mut builder = MeshMaterialBuilder::new(_fbb);
+                    if let Some(x) = args.emissive_texture {
+                        builder.add_emissive_texture(x);
+                    }
+                    if let Some(x) = args.occlusion_texture {
+                        builder.add_occlusion_texture(x);
+                    }
+                    if let Some(x) = args.normal_texture {
+                        builder.add_normal_texture(x);
+                    }
+                    if let Some(x) = args.metallic_roughness_texture {
+                        builder.add_metallic_roughness_texture(x);
+                    }
+                    if let Some(x) = args.base_color_texture {
+                        builder.add_base_color_texture(x);
+                    }
+                    builder.add_alpha_cutoff(args.alpha_cutoff);
+                    builder.add_occlusion_strength(args.occlusion_strength);
+                    builder.add_normal_scale(args.normal_scale);
+                    if let Some(x) = args.emissive {
+                        builder.add_emissive(x);
+                    }
+                    builder.add_metallic(args.metallic);
+                    builder.add_alpha(args.alpha);
+                    builder.add_roughness(args.roughness);
+                    if let Some(x) = args.albedo_tint {
+                        builder.add_albedo_tint(x);
+                    }
+                    if let Some(x) = args.material {
+                        builder.add_material(x);
+                    }
+                    if let Some(x) = args.name {
+                        builder.add_name(x);
+                    }
+                    builder.add_double_sided(args.double_sided);
+                    builder.add_alpha_mode(args.alpha_mode);
+                    builder.finish()
+                }
+
+                pub const VT_NAME: flatbuffers::VOffsetT = 4;
+                pub const VT_MATERIAL: flatbuffers::VOffsetT = 6;
+                pub const VT_ALBEDO_TINT: flatbuffers::VOffsetT = 8;
+                pub const VT_ROUGHNESS: flatbuffers::VOffsetT = 10;
+                pub const VT_ALPHA: flatbuffers::VOffsetT = 12;
+                pub const VT_METALLIC: flatbuffers::VOffsetT = 14;
+                pub const VT_EMISSIVE: flatbuffers::VOffsetT = 16;
+                pub const VT_NORMAL_SCALE: flatbuffers::VOffsetT = 18;
+                pub const VT_OCCLUSION_STRENGTH: flatbuffers::VOffsetT = 20;
+                pub const VT_ALPHA_MODE: flatbuffers::VOffsetT = 22;
+                pub const VT_ALPHA_CUTOFF: flatbuffers::VOffsetT = 24;
+                pub const VT_DOUBLE_SIDED: flatbuffers::VOffsetT = 26;
+                pub const VT_BASE_COLOR_TEXTURE: flatbuffers::VOffsetT = 28;
+                pub const VT_METALLIC_ROUGHNESS_TEXTURE: flatbuffers::VOffsetT = 30;
+                pub const VT_NORMAL_TEXTURE: flatbuffers::VOffsetT = 32;
+                pub const VT_OCCLUSION_TEXTURE: flatbuffers::VOffsetT = 34;
+                pub const VT_EMISSIVE_TEXTURE: flatbuffers::VOffsetT = 36;
+
+                #[inline]
+                pub fn name(&self) -> Option<&'a str> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<&str>>(MeshMaterial::VT_NAME, None)
+                }
+                #[inline]
+                pub fn material(&self) -> Option<&'a str> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<&str>>(MeshMaterial::VT_MATERIAL, None)
+                }
+                #[inline]
+                pub fn albedo_tint(&self) -> Option<Vector3<'a>> {
+                    self._tab.get::<flatbuffers::ForwardsUOffset<Vector3<'a>>>(
+                        MeshMaterial::VT_ALBEDO_TINT,
+                        None,
+                    )
+                }
+                #[inline]
+                pub fn roughness(&self) -> f32 {
+                    self._tab
+                        .get::<f32>(MeshMaterial::VT_ROUGHNESS, Some(0.0))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn alpha(&self) -> f32 {
+                    self._tab
+                        .get::<f32>(MeshMaterial::VT_ALPHA, Some(1.0))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn metallic(&self) -> f32 {
+                    self._tab
+                        .get::<f32>(MeshMaterial::VT_METALLIC, Some(1.0))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn emissive(&self) -> Option<Vector3<'a>> {
+                    self._tab.get::<flatbuffers::ForwardsUOffset<Vector3<'a>>>(
+                        MeshMaterial::VT_EMISSIVE,
+                        None,
+                    )
+                }
+                #[inline]
+                pub fn normal_scale(&self) -> f32 {
+                    self._tab
+                        .get::<f32>(MeshMaterial::VT_NORMAL_SCALE, Some(1.0))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn occlusion_strength(&self) -> f32 {
+                    self._tab
+                        .get::<f32>(MeshMaterial::VT_OCCLUSION_STRENGTH, Some(1.0))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn alpha_mode(&self) -> AlphaMode {
+                    self._tab
+                        .get::<AlphaMode>(MeshMaterial::VT_ALPHA_MODE, Some(AlphaMode::Opaque))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn alpha_cutoff(&self) -> f32 {
+                    self._tab
+                        .get::<f32>(MeshMaterial::VT_ALPHA_CUTOFF, Some(0.5))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn double_sided(&self) -> bool {
+                    self._tab
+                        .get::<bool>(MeshMaterial::VT_DOUBLE_SIDED, Some(false))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn base_color_texture(&self) -> Option<MaterialTexture<'a>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<MaterialTexture<'a>>>(
+                            MeshMaterial::VT_BASE_COLOR_TEXTURE,
+                            None,
+                        )
+                }
+                #[inline]
+                pub fn metallic_roughness_texture(&self) -> Option<MaterialTexture<'a>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<MaterialTexture<'a>>>(
+                            MeshMaterial::VT_METALLIC_ROUGHNESS_TEXTURE,
+                            None,
+                        )
+                }
+                #[inline]
+                pub fn normal_texture(&self) -> Option<MaterialTexture<'a>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<MaterialTexture<'a>>>(
+                            MeshMaterial::VT_NORMAL_TEXTURE,
+                            None,
+                        )
+                }
+                #[inline]
+                pub fn occlusion_texture(&self) -> Option<MaterialTexture<'a>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<MaterialTexture<'a>>>(
+                            MeshMaterial::VT_OCCLUSION_TEXTURE,
+                            None,
+                        )
+                }
+                #[inline]
+                pub fn emissive_texture(&self) -> Option<MaterialTexture<'a>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<MaterialTexture<'a>>>(
+                            MeshMaterial::VT_EMISSIVE_TEXTURE,
+                            None,
+                        )
+                }
+            }
+
+            pub struct MeshMaterialArgs<'a> {
+                pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+                pub material: Option<flatbuffers::WIPOffset<&'a str>>,
+                pub albedo_tint: Option<flatbuffers::WIPOffset<Vector3<'a>>>,
+                pub roughness: f32,
+                pub alpha: f32,
+                pub metallic: f32,
+                pub emissive: Option<flatbuffers::WIPOffset<Vector3<'a>>>,
+                pub normal_scale: f32,
+                pub occlusion_strength: f32,
+                pub alpha_mode: AlphaMode,
+                pub alpha_cutoff: f32,
+                pub double_sided: bool,
+                pub base_color_texture: Option<flatbuffers::WIPOffset<MaterialTexture<'a>>>,
+                pub metallic_roughness_texture: Option<flatbuffers::WIPOffset<MaterialTexture<'a>>>,
+                pub normal_texture: Option<flatbuffers::WIPOffset<MaterialTexture<'a>>>,
+                pub occlusion_texture: Option<flatbuffers::WIPOffset<MaterialTexture<'a>>>,
+                pub emissive_texture: Option<flatbuffers::WIPOffset<MaterialTexture<'a>>>,
+            }
+            impl<'a> Default for MeshMaterialArgs<'a> {
+                #[inline]
+                fn default() -> Self {
+                    MeshMaterialArgs {
+                        name: None,
+                        material: None,
+                        albedo_tint: None,
+                        roughness: 0.0,
+                        alpha: 1.0,
+                        metallic: 1.0,
+                        emissive: None,
+                        normal_scale: 1.0,
+                        occlusion_strength: 1.0,
+                        alpha_mode: AlphaMode::Opaque,
+                        alpha_cutoff: 0.5,
+                        double_sided: false,
+                        base_color_texture: None,
+                        metallic_roughness_texture: None,
+                        normal_texture: None,
+                        occlusion_texture: None,
+                        emissive_texture: None,
+                    }
+                }
+            }
+            pub struct MeshMaterialBuilder<'a: 'b, 'b> {
+                fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+            }
+            impl<'a: 'b, 'b> MeshMaterialBuilder<'a, 'b> {
+                #[inline]
+                pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<_>>(MeshMaterial::VT_NAME, name);
+                }
+                #[inline]
+                pub fn add_material(&mut self, material: flatbuffers::WIPOffset<&'b str>) {
+                    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                        MeshMaterial::VT_MATERIAL,
+                        material,
+                    );
+                }
+                #[inline]
+                pub fn add_albedo_tint(
+                    &mut self,
+                    albedo_tint: flatbuffers::WIPOffset<Vector3<'b>>,
+                ) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<Vector3>>(
+                            MeshMaterial::VT_ALBEDO_TINT,
+                            albedo_tint,
+                        );
+                }
+                #[inline]
+                pub fn add_roughness(&mut self, roughness: f32) {
+                    self.fbb_
+                        .push_slot::<f32>(MeshMaterial::VT_ROUGHNESS, roughness, 0.0);
+                }
+                #[inline]
+                pub fn add_alpha(&mut self, alpha: f32) {
+                    self.fbb_
+                        .push_slot::<f32>(MeshMaterial::VT_ALPHA, alpha, 1.0);
+                }
+                #[inline]
+                pub fn add_metallic(&mut self, metallic: f32) {
+                    self.fbb_
+                        .push_slot::<f32>(MeshMaterial::VT_METALLIC, metallic, 1.0);
+                }
+                #[inline]
+                pub fn add_emissive(&mut self, emissive: flatbuffers::WIPOffset<Vector3<'b>>) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<Vector3>>(
+                            MeshMaterial::VT_EMISSIVE,
+                            emissive,
+                        );
+                }
+                #[inline]
+                pub fn add_normal_scale(&mut self, normal_scale: f32) {
+                    self.fbb_
+                        .push_slot::<f32>(MeshMaterial::VT_NORMAL_SCALE, normal_scale, 1.0);
+                }
+                #[inline]
+                pub fn add_occlusion_strength(&mut self, occlusion_strength: f32) {
+                    self.fbb_.push_slot::<f32>(
+                        MeshMaterial::VT_OCCLUSION_STRENGTH,
+                        occlusion_strength,
+                        1.0,
+                    );
+                }
+                #[inline]
+                pub fn add_alpha_mode(&mut self, alpha_mode: AlphaMode) {
+                    self.fbb_.push_slot::<AlphaMode>(
+                        MeshMaterial::VT_ALPHA_MODE,
+                        alpha_mode,
+                        AlphaMode::Opaque,
+                    );
+                }
+                #[inline]
+                pub fn add_alpha_cutoff(&mut self, alpha_cutoff: f32) {
+                    self.fbb_
+                        .push_slot::<f32>(MeshMaterial::VT_ALPHA_CUTOFF, alpha_cutoff, 0.5);
+                }
+                #[inline]
+                pub fn add_double_sided(&mut self, double_sided: bool) {
+                    self.fbb_
+                        .push_slot::<bool>(MeshMaterial::VT_DOUBLE_SIDED, double_sided, false);
+                }
+                #[inline]
+                pub fn add_base_color_texture(
+                    &mut self,
+                    base_color_texture: flatbuffers::WIPOffset<MaterialTexture<'b>>,
+                ) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<MaterialTexture>>(
+                            MeshMaterial::VT_BASE_COLOR_TEXTURE,
+                            base_color_texture,
+                        );
+                }
+                #[inline]
+                pub fn add_metallic_roughness_texture(
+                    &mut self,
+                    metallic_roughness_texture: flatbuffers::WIPOffset<MaterialTexture<'b>>,
+                ) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<MaterialTexture>>(
+                            MeshMaterial::VT_METALLIC_ROUGHNESS_TEXTURE,
+                            metallic_roughness_texture,
+                        );
+                }
+                #[inline]
+                pub fn add_normal_texture(
+                    &mut self,
+                    normal_texture: flatbuffers::WIPOffset<MaterialTexture<'b>>,
+                ) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<MaterialTexture>>(
+                            MeshMaterial::VT_NORMAL_TEXTURE,
+                            normal_texture,
+                        );
+                }
+                #[inline]
+                pub fn add_occlusion_texture(
+                    &mut self,
+                    occlusion_texture: flatbuffers::WIPOffset<MaterialTexture<'b>>,
+                ) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<MaterialTexture>>(
+                            MeshMaterial::VT_OCCLUSION_TEXTURE,
+                            occlusion_texture,
+                        );
+                }
+                #[inline]
+                pub fn add_emissive_texture(
+                    &mut self,
+                    emissive_texture: flatbuffers::WIPOffset<MaterialTexture<'b>>,
+                ) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<MaterialTexture>>(
+                            MeshMaterial::VT_EMISSIVE_TEXTURE,
+                            emissive_texture,
+                        );
+                }
+                #[inline]
+                pub fn new(
+                    _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                ) -> MeshMaterialBuilder<'a, 'b> {
+                    let start = _fbb.start_table();
+                    MeshMaterialBuilder {
+                        fbb_: _fbb,
+                        start_: start,
+                    }
+                }
+                #[inline]
+                pub fn finish(self) -> flatbuffers::WIPOffset<MeshMaterial<'a>> {
+                    let o = self.fbb_.end_table(self.start_);
+                    flatbuffers::WIPOffset::new(o.value())
+                }
+            }
+
+            pub enum MeshPartOffset {}
+            #[derive(Copy, Clone, Debug, PartialEq)]
+
+            pub struct MeshPart<'a> {
+                pub _tab: flatbuffers::Table<'a>,
+            }
+
+            impl<'a> flatbuffers::Follow<'a> for MeshPart<'a> {
+                type Inner = MeshPart<'a>;
+                #[inline]
+                fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                    Self {
+                        _tab: flatbuffers::Table { buf: buf, loc: loc },
+                    }
+                }
+            }
+
+            impl<'a> MeshPart<'a> {
+                #[inline]
+                pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+                    MeshPart { _tab: table }
+                }
+                #[allow(unused_mut)]
+                pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+                    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+                    args: &'args MeshPartArgs<'args>,
+                ) -> flatbuffers::WIPOffset<MeshPart<'bldr>> {
+                    let mut builder = MeshPartBuilder::new(_fbb);
+                    if let Some(x) = args.bounds {
+                        builder.add_bounds(x);
+                    }
+                    if let Some(x) = args.instance_transforms {
+                        builder.add_instance_transforms(x);
+                    }
+                    builder.add_base_vertex(args.base_vertex);
+                    builder.add_meshlet_count(args.meshlet_count);
+                    builder.add_meshlet_start(args.meshlet_start);
+                    if let Some(x) = args.name {
+                        builder.add_name(x);
+                    }
+                    if let Some(x) = args.base_transform {
+                        builder.add_base_transform(x);
+                    }
+                    builder.add_node_index(args.node_index);
+                    builder.add_material_index(args.material_index);
+                    builder.add_index_count(args.index_count);
+                    builder.add_index_start(args.index_start);
+                    builder.add_topology(args.topology);
+                    builder.add_animation_type(args.animation_type);
+                    builder.finish()
+                }
+
+                pub const VT_INDEX_START: flatbuffers::VOffsetT = 4;
+                pub const VT_INDEX_COUNT: flatbuffers::VOffsetT = 6;
+                pub const VT_MATERIAL_INDEX: flatbuffers::VOffsetT = 8;
+                pub const VT_NODE_INDEX: flatbuffers::VOffsetT = 10;
+                pub const VT_BASE_TRANSFORM: flatbuffers::VOffsetT = 12;
+                pub const VT_NAME: flatbuffers::VOffsetT = 14;
+                pub const VT_ANIMATION_TYPE: flatbuffers::VOffsetT = 16;
+                pub const VT_MESHLET_START: flatbuffers::VOffsetT = 18;
+                pub const VT_MESHLET_COUNT: flatbuffers::VOffsetT = 20;
+                pub const VT_BASE_VERTEX: flatbuffers::VOffsetT = 22;
+                pub const VT_INSTANCE_TRANSFORMS: flatbuffers::VOffsetT = 24;
+                pub const VT_BOUNDS: flatbuffers::VOffsetT = 26;
+                pub const VT_TOPOLOGY: flatbuffers::VOffsetT = 28;
+
+                #[inline]
+                pub fn index_start(&self) -> u32 {
+                    self._tab
+                        .get::<u32>(MeshPart::VT_INDEX_START, Some(0))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn index_count(&self) -> u32 {
+                    self._tab
+                        .get::<u32>(MeshPart::VT_INDEX_COUNT, Some(0))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn material_index(&self) -> i32 {
+                    self._tab
+                        .get::<i32>(MeshPart::VT_MATERIAL_INDEX, Some(0))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn node_index(&self) -> i32 {
+                    self._tab
+                        .get::<i32>(MeshPart::VT_NODE_INDEX, Some(0))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn base_transform(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                            MeshPart::VT_BASE_TRANSFORM,
+                            None,
+                        )
+                }
+                #[inline]
+                pub fn name(&self) -> Option<&'a str> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<&str>>(MeshPart::VT_NAME, None)
+                }
+                #[inline]
+                pub fn animation_type(&self) -> AnimationType {
+                    self._tab
+                        .get::<AnimationType>(
+                            MeshPart::VT_ANIMATION_TYPE,
+                            Some(AnimationType::None),
+                        )
+                        .unwrap()
+                }
+                #[inline]
+                pub fn meshlet_start(&self) -> u32 {
+                    self._tab
+                        .get::<u32>(MeshPart::VT_MESHLET_START, Some(0))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn meshlet_count(&self) -> u32 {
+                    self._tab
+                        .get::<u32>(MeshPart::VT_MESHLET_COUNT, Some(0))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn base_vertex(&self) -> u32 {
+                    self._tab
+                        .get::<u32>(MeshPart::VT_BASE_VERTEX, Some(0))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn instance_transforms(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                            MeshPart::VT_INSTANCE_TRANSFORMS,
+                            None,
+                        )
+                }
+                #[inline]
+                pub fn bounds(&self) -> Option<Bounds<'a>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<Bounds<'a>>>(MeshPart::VT_BOUNDS, None)
+                }
+                #[inline]
+                pub fn topology(&self) -> Topology {
+                    self._tab
+                        .get::<Topology>(MeshPart::VT_TOPOLOGY, Some(Topology::Triangles))
+                        .unwrap()
+                }
+            }
+
+            pub struct MeshPartArgs<'a> {
+                pub index_start: u32,
+                pub index_count: u32,
+                pub material_index: i32,
+                pub node_index: i32,
+                pub base_transform: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+                pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+                pub animation_type: AnimationType,
+                pub meshlet_start: u32,
+                pub meshlet_count: u32,
+                pub base_vertex: u32,
+                pub instance_transforms:
+                    Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+                pub bounds: Option<flatbuffers::WIPOffset<Bounds<'a>>>,
+                pub topology: Topology,
+            }
+            impl<'a> Default for MeshPartArgs<'a> {
+                #[inline]
+                fn default() -> Self {
+                    MeshPartArgs {
+                        index_start: 0,
+                        index_count: 0,
+                        material_index: 0,
+                        node_index: 0,
+                        base_transform: None,
+                        name: None,
+                        animation_type: AnimationType::None,
+                        meshlet_start: 0,
+                        meshlet_count: 0,
+                        base_vertex: 0,
+                        instance_transforms: None,
+                        bounds: None,
+                        topology: Topology::Triangles,
+                    }
+                }
+            }
+            pub struct MeshPartBuilder<'a: 'b, 'b> {
+                fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+            }
+            impl<'a: 'b, 'b> MeshPartBuilder<'a, 'b> {
+                #[inline]
+                pub fn add_index_start(&mut self, index_start: u32) {
+                    self.fbb_
+                        .push_slot::<u32>(MeshPart::VT_INDEX_START, index_start, 0);
+                }
+                #[inline]
+                pub fn add_index_count(&mut self, index_count: u32) {
+                    self.fbb_
+                        .push_slot::<u32>(MeshPart::VT_INDEX_COUNT, index_count, 0);
+                }
+                #[inline]
+                pub fn add_material_index(&mut self, material_index: i32) {
+                    self.fbb_
+                        .push_slot::<i32>(MeshPart::VT_MATERIAL_INDEX, material_index, 0);
+                }
+                #[inline]
+                pub fn add_node_index(&mut self, node_index: i32) {
+                    self.fbb_
+                        .push_slot::<i32>(MeshPart::VT_NODE_INDEX, node_index, 0);
+                }
+                #[inline]
+                pub fn add_base_transform(
+                    &mut self,
+                    base_transform: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>,
+                ) {
+                    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                        MeshPart::VT_BASE_TRANSFORM,
+                        base_transform,
+                    );
+                }
+                #[inline]
+                pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<_>>(MeshPart::VT_NAME, name);
+                }
+                #[inline]
+                pub fn add_animation_type(&mut self, animation_type: AnimationType) {
+                    self.fbb_.push_slot::<AnimationType>(
+                        MeshPart::VT_ANIMATION_TYPE,
+                        animation_type,
+                        AnimationType::None,
+                    );
+                }
+                #[inline]
+                pub fn add_meshlet_start(&mut self, meshlet_start: u32) {
+                    self.fbb_
+                        .push_slot::<u32>(MeshPart::VT_MESHLET_START, meshlet_start, 0);
+                }
+                #[inline]
+                pub fn add_meshlet_count(&mut self, meshlet_count: u32) {
+                    self.fbb_
+                        .push_slot::<u32>(MeshPart::VT_MESHLET_COUNT, meshlet_count, 0);
+                }
+                #[inline]
+                pub fn add_base_vertex(&mut self, base_vertex: u32) {
+                    self.fbb_
+                        .push_slot::<u32>(MeshPart::VT_BASE_VERTEX, base_vertex, 0);
+                }
+                #[inline]
+                pub fn add_instance_transforms(
+                    &mut self,
+                    instance_transforms: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>,
+                ) {
+                    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                        MeshPart::VT_INSTANCE_TRANSFORMS,
+                        instance_transforms,
+                    );
+                }
+                #[inline]
+                pub fn add_bounds(&mut self, bounds: flatbuffers::WIPOffset<Bounds<'b>>) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<Bounds>>(
+                            MeshPart::VT_BOUNDS,
+                            bounds,
+                        );
+                }
+                #[inline]
+                pub fn add_topology(&mut self, topology: Topology) {
+                    self.fbb_.push_slot::<Topology>(
+                        MeshPart::VT_TOPOLOGY,
+                        topology,
+                        Topology::Triangles,
+                    );
+                }
+                #[inline]
+                pub fn new(
+                    _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                ) -> MeshPartBuilder<'a, 'b> {
+                    let start = _fbb.start_table();
+                    MeshPartBuilder {
+                        fbb_: _fbb,
+                        start_: start,
+                    }
+                }
+                #[inline]
+                pub fn finish(self) -> flatbuffers::WIPOffset<MeshPart<'a>> {
+                    let o = self.fbb_.end_table(self.start_);
+                    flatbuffers::WIPOffset::new(o.value())
+                }
+            }
+
+            pub enum NodePartsOffset {}
+            #[derive(Copy, Clone, Debug, PartialEq)]
+
+            pub struct NodeParts<'a> {
+                pub _tab: flatbuffers::Table<'a>,
+            }
+
+            impl<'a> flatbuffers::Follow<'a> for NodeParts<'a> {
+                type Inner = NodeParts<'a>;
+                #[inline]
+                fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                    Self {
+                        _tab: flatbuffers::Table { buf: buf, loc: loc },
+                    }
+                }
+            }
+
+            impl<'a> NodeParts<'a> {
+                #[inline]
+                pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+                    NodeParts { _tab: table }
+                }
+                #[allow(unused_mut)]
+                pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+                    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+                    args: &'args NodePartsArgs<'args>,
+                ) -> flatbuffers::WIPOffset<NodeParts<'bldr>> {
+                    let mut builder = NodePartsBuilder::new(_fbb);
+                    if let Some(x) = args.bounds {
+                        builder.add_bounds(x);
+                    }
+                    if let Some(x) = args.parts {
+                        builder.add_parts(x);
+                    }
+                    if let Some(x) = args.name {
+                        builder.add_name(x);
+                    }
+                    builder.finish()
+                }
+
+                pub const VT_NAME: flatbuffers::VOffsetT = 4;
+                pub const VT_PARTS: flatbuffers::VOffsetT = 6;
+                pub const VT_BOUNDS: flatbuffers::VOffsetT = 8;
+
+                #[inline]
+                pub fn name(&self) -> Option<&'a str> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<&str>>(NodeParts::VT_NAME, None)
+                }
+                #[inline]
+                pub fn parts(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                            NodeParts::VT_PARTS,
+                            None,
+                        )
+                }
+                #[inline]
+                pub fn bounds(&self) -> Option<Bounds<'a>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<Bounds<'a>>>(NodeParts::VT_BOUNDS, None)
+                }
+            }
+
+            pub struct NodePartsArgs<'a> {
+                pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+                pub parts: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+                pub bounds: Option<flatbuffers::WIPOffset<Bounds<'a>>>,
+            }
+            impl<'a> Default for NodePartsArgs<'a> {
+                #[inline]
+                fn default() -> Self {
+                    NodePartsArgs {
+                        name: None,
+                        parts: None,
+                        bounds: None,
+                    }
+                }
+            }
+            pub struct NodePartsBuilder<'a: 'b, 'b> {
+                fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+            }
+            impl<'a: 'b, 'b> NodePartsBuilder<'a, 'b> {
+                #[inline]
+                pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<_>>(NodeParts::VT_NAME, name);
+                }
+                #[inline]
+                pub fn add_parts(
+                    &mut self,
+                    parts: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+                ) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<_>>(NodeParts::VT_PARTS, parts);
+                }
+                #[inline]
+                pub fn add_bounds(&mut self, bounds: flatbuffers::WIPOffset<Bounds<'b>>) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<Bounds>>(
+                            NodeParts::VT_BOUNDS,
+                            bounds,
+                        );
+                }
+                #[inline]
+                pub fn new(
+                    _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                ) -> NodePartsBuilder<'a, 'b> {
+                    let start = _fbb.start_table();
+                    NodePartsBuilder {
+                        fbb_: _fbb,
+                        start_: start,
+                    }
+                }
+                #[inline]
+                pub fn finish(self) -> flatbuffers::WIPOffset<NodeParts<'a>> {
+                    let o = self.fbb_.end_table(self.start_);
+                    flatbuffers::WIPOffset::new(o.value())
+                }
+            }
+
+            pub enum SkeletonOffset {}
+            #[derive(Copy, Clone, Debug, PartialEq)]
+
+            pub struct Skeleton<'a> {
+                pub _tab: flatbuffers::Table<'a>,
+            }
+
+            impl<'a> flatbuffers::Follow<'a> for Skeleton<'a> {
+                type Inner = Skeleton<'a>;
+                #[inline]
+                fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                    Self {
+                        _tab: flatbuffers::Table { buf: buf, loc: loc },
+                    }
+                }
+            }
+
+            impl<'a> Skeleton<'a> {
+                #[inline]
+                pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+                    Skeleton { _tab: table }
+                }
+                #[allow(unused_mut)]
+                pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+                    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+                    args: &'args SkeletonArgs<'args>,
+                ) -> flatbuffers::WIPOffset<Skeleton<'bldr>> {
+                    let mut builder = SkeletonBuilder::new(_fbb);
+                    builder.add_root(args.root);
+                    if let Some(x) = args.inverse_bind_matrices {
+                        builder.add_inverse_bind_matrices(x);
+                    }
+                    if let Some(x) = args.joint_parents {
+                        builder.add_joint_parents(x);
+                    }
+                    if let Some(x) = args.joint_names {
+                        builder.add_joint_names(x);
+                    }
+                    builder.finish()
+                }
+
+                pub const VT_JOINT_NAMES: flatbuffers::VOffsetT = 4;
+                pub const VT_JOINT_PARENTS: flatbuffers::VOffsetT = 6;
+                pub const VT_INVERSE_BIND_MATRICES: flatbuffers::VOffsetT = 8;
+                pub const VT_ROOT: flatbuffers::VOffsetT = 10;
+
+                #[inline]
+                pub fn joint_names(
+                    &self,
+                ) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<&'a str>>>
+                {
+                    self._tab.get::<flatbuffers::ForwardsUOffset<
+                        flatbuffers::Vector<flatbuffers::ForwardsUOffset<&'a str>>,
+                    >>(Skeleton::VT_JOINT_NAMES, None)
+                }
+                #[inline]
+                pub fn joint_parents(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                            Skeleton::VT_JOINT_PARENTS,
+                            None,
+                        )
+                }
+                #[inline]
+                pub fn inverse_bind_matrices(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                            Skeleton::VT_INVERSE_BIND_MATRICES,
+                            None,
+                        )
+                }
+                #[inline]
+                pub fn root(&self) -> i32 {
+                    self._tab.get::<i32>(Skeleton::VT_ROOT, Some(-1)).unwrap()
+                }
+            }
+
+            pub struct SkeletonArgs<'a> {
+                pub joint_names: Option<
+                    flatbuffers::WIPOffset<
+                        flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+                    >,
+                >,
+                pub joint_parents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+                pub inverse_bind_matrices:
+                    Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+                pub root: i32,
+            }
+            impl<'a> Default for SkeletonArgs<'a> {
+                #[inline]
+                fn default() -> Self {
+                    SkeletonArgs {
+                        joint_names: None,
+                        joint_parents: None,
+                        inverse_bind_matrices: None,
+                        root: -1,
+                    }
+                }
+            }
+            pub struct SkeletonBuilder<'a: 'b, 'b> {
+                fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+            }
+            impl<'a: 'b, 'b> SkeletonBuilder<'a, 'b> {
+                #[inline]
+                pub fn add_joint_names(
+                    &mut self,
+                    joint_names: flatbuffers::WIPOffset<
+                        flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+                    >,
+                ) {
+                    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                        Skeleton::VT_JOINT_NAMES,
+                        joint_names,
+                    );
+                }
+                #[inline]
+                pub fn add_joint_parents(
+                    &mut self,
+                    joint_parents: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>,
+                ) {
+                    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                        Skeleton::VT_JOINT_PARENTS,
+                        joint_parents,
+                    );
+                }
+                #[inline]
+                pub fn add_inverse_bind_matrices(
+                    &mut self,
+                    inverse_bind_matrices: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>,
+                ) {
+                    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                        Skeleton::VT_INVERSE_BIND_MATRICES,
+                        inverse_bind_matrices,
+                    );
+                }
+                #[inline]
+                pub fn add_root(&mut self, root: i32) {
+                    self.fbb_.push_slot::<i32>(Skeleton::VT_ROOT, root, -1);
+                }
+                #[inline]
+                pub fn new(
+                    _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                ) -> SkeletonBuilder<'a, 'b> {
+                    let start = _fbb.start_table();
+                    SkeletonBuilder {
+                        fbb_: _fbb,
+                        start_: start,
+                    }
+                }
+                #[inline]
+                pub fn finish(self) -> flatbuffers::WIPOffset<Skeleton<'a>> {
+                    let o = self.fbb_.end_table(self.start_);
+                    flatbuffers::WIPOffset::new(o.value())
+                }
+            }
+
+            pub enum Vector3Offset {}
+            #[derive(Copy, Clone, Debug, PartialEq)]
 
-            impl<'a> flatbuffers::Follow<'a> for AnimationType {
-                type Inner = Self;
+            pub struct Vector3<'a> {
+                pub _tab: flatbuffers::Table<'a>,
+            }
+
+            impl<'a> flatbuffers::Follow<'a> for Vector3<'a> {
+                type Inner = Vector3<'a>;
                 #[inline]
                 fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-                    flatbuffers::read_scalar_at::<Self>(buf, loc)
+                    Self {
+                        _tab: flatbuffers::Table { buf: buf, loc: loc },
+                    }
                 }
             }
 
-            impl flatbuffers::EndianScalar for AnimationType {
+            impl<'a> Vector3<'a> {
                 #[inline]
-                fn to_little_endian(self) -> Self {
-                    let n = i8::to_le(self as i8);
-                    let p = &n as *const i8 as *const AnimationType;
-                    unsafe { *p }
+                pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+                    Vector3 { _tab: table }
+                }
+                #[allow(unused_mut)]
+                pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+                    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+                    args: &'args Vector3Args,
+                ) -> flatbuffers::WIPOffset<Vector3<'bldr>> {
+                    let mut builder = Vector3Builder::new(_fbb);
+                    builder.add_z(args.z);
+                    builder.add_y(args.y);
+                    builder.add_x(args.x);
+                    builder.finish()
                 }
+
+                pub const VT_X: flatbuffers::VOffsetT = 4;
+                pub const VT_Y: flatbuffers::VOffsetT = 6;
+                pub const VT_Z: flatbuffers::VOffsetT = 8;
+
                 #[inline]
-                fn from_little_endian(self) -> Self {
-                    let n = i8::from_le(self as i8);
-                    let p = &n as *const i8 as *const AnimationType;
-                    unsafe { *p }
+                pub fn x(&self) -> f32 {
+                    self._tab.get::<f32>(Vector3::VT_X, Some(0.0)).unwrap()
+                }
+                #[inline]
+                pub fn y(&self) -> f32 {
+                    self._tab.get::<f32>(Vector3::VT_Y, Some(0.0)).unwrap()
+                }
+                #[inline]
+                pub fn z(&self) -> f32 {
+                    self._tab.get::<f32>(Vector3::VT_Z, Some(0.0)).unwrap()
                 }
             }
 
-            impl flatbuffers::Push for AnimationType {
-                type Output = AnimationType;
+            pub struct Vector3Args {
+                pub x: f32,
+                pub y: f32,
+                pub z: f32,
+            }
+            impl<'a> Default for Vector3Args {
                 #[inline]
-                fn push(&self, dst: &mut [u8], _rest: &[u8]) {
-                    flatbuffers::emplace_scalar::<AnimationType>(dst, *self);
+                fn default() -> Self {
+                    Vector3Args {
+                        x: 0.0,
+                        y: 0.0,
+                        z: 0.0,
+                    }
                 }
             }
-
-            #[allow(non_camel_case_types)]
-            const ENUM_VALUES_ANIMATION_TYPE: [AnimationType; 3] = [
-                AnimationType::None,
-                AnimationType::Rigid,
-                AnimationType::Skinned,
-            ];
-
-            #[allow(non_camel_case_types)]
-            const ENUM_NAMES_ANIMATION_TYPE: [&'static str; 3] = ["None", "Rigid", "Skinned"];
-
-            pub fn enum_name_animation_type(e: AnimationType) -> &'static str {
-                let index: usize = e as usize;
-                ENUM_NAMES_ANIMATION_TYPE[index]
+            pub struct Vector3Builder<'a: 'b, 'b> {
+                fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+            }
+            impl<'a: 'b, 'b> Vector3Builder<'a, 'b> {
+                #[inline]
+                pub fn add_x(&mut self, x: f32) {
+                    self.fbb_.push_slot::<f32>(Vector3::VT_X, x, 0.0);
+                }
+                #[inline]
+                pub fn add_y(&mut self, y: f32) {
+                    self.fbb_.push_slot::<f32>(Vector3::VT_Y, y, 0.0);
+                }
+                #[inline]
+                pub fn add_z(&mut self, z: f32) {
+                    self.fbb_.push_slot::<f32>(Vector3::VT_Z, z, 0.0);
+                }
+                #[inline]
+                pub fn new(
+                    _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+                ) -> Vector3Builder<'a, 'b> {
+                    let start = _fbb.start_table();
+                    Vector3Builder {
+                        fbb_: _fbb,
+                        start_: start,
+                    }
+                }
+                #[inline]
+                pub fn finish(self) -> flatbuffers::WIPOffset<Vector3<'a>> {
+                    let o = self.fbb_.end_table(self.start_);
+                    flatbuffers::WIPOffset::new(o.value())
+                }
             }
 
-            pub enum AnimationOffset {}
+            pub enum BoundsOffset {}
             #[derive(Copy, Clone, Debug, PartialEq)]
 
-            pub struct Animation<'a> {
+            pub struct Bounds<'a> {
                 pub _tab: flatbuffers::Table<'a>,
             }
 
-            impl<'a> flatbuffers::Follow<'a> for Animation<'a> {
-                type Inner = Animation<'a>;
+            impl<'a> flatbuffers::Follow<'a> for Bounds<'a> {
+                type Inner = Bounds<'a>;
                 #[inline]
                 fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
                     Self {
@@ -250,59 +2589,134 @@ pub mod service {
                 }
             }
 
-            impl<'a> Animation<'a> {
+            impl<'a> Bounds<'a> {
                 #[inline]
                 pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-                    Animation { _tab: table }
+                    Bounds { _tab: table }
                 }
                 #[allow(unused_mut)]
                 pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
                     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-                    _args: &'args AnimationArgs,
-                ) -> flatbuffers::WIPOffset<Animation<'bldr>> {
-                    let mut builder = AnimationBuilder::new(_fbb);
+                    args: &'args BoundsArgs<'args>,
+                ) -> flatbuffers::WIPOffset<Bounds<'bldr>> {
+                    let mut builder = BoundsBuilder::new(_fbb);
+                    builder.add_sphere_radius(args.sphere_radius);
+                    if let Some(x) = args.sphere_center {
+                        builder.add_sphere_center(x);
+                    }
+                    if let Some(x) = args.max {
+                        builder.add_max(x);
+                    }
+                    if let Some(x) = args.min {
+                        builder.add_min(x);
+                    }
                     builder.finish()
                 }
+
+                pub const VT_MIN: flatbuffers::VOffsetT = 4;
+                pub const VT_MAX: flatbuffers::VOffsetT = 6;
+                pub const VT_SPHERE_CENTER: flatbuffers::VOffsetT = 8;
+                pub const VT_SPHERE_RADIUS: flatbuffers::VOffsetT = 10;
+
+                #[inline]
+                pub fn min(&self) -> Option<Vector3<'a>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<Vector3<'a>>>(Bounds::VT_MIN, None)
+                }
+                #[inline]
+                pub fn max(&self) -> Option<Vector3<'a>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<Vector3<'a>>>(Bounds::VT_MAX, None)
+                }
+                #[inline]
+                pub fn sphere_center(&self) -> Option<Vector3<'a>> {
+                    self._tab.get::<flatbuffers::ForwardsUOffset<Vector3<'a>>>(
+                        Bounds::VT_SPHERE_CENTER,
+                        None,
+                    )
+                }
+                #[inline]
+                pub fn sphere_radius(&self) -> f32 {
+                    self._tab
+                        .get::<f32>(Bounds::VT_SPHERE_RADIUS, Some(0.0))
+                        .unwrap()
+                }
             }
 
-            pub struct AnimationArgs {}
-            impl<'a> Default for AnimationArgs {
+            pub struct BoundsArgs<'a> {
+                pub min: Option<flatbuffers::WIPOffset<Vector3<'a>>>,
+                pub max: Option<flatbuffers::WIPOffset<Vector3<'a>>>,
+                pub sphere_center: Option<flatbuffers::WIPOffset<Vector3<'a>>>,
+                pub sphere_radius: f32,
+            }
+            impl<'a> Default for BoundsArgs<'a> {
                 #[inline]
                 fn default() -> Self {
-                    AnimationArgs {}
+                    BoundsArgs {
+                        min: None,
+                        max: None,
+                        sphere_center: None,
+                        sphere_radius: 0.0,
+                    }
                 }
             }
-            pub struct AnimationBuilder<'a: 'b, 'b> {
+            pub struct BoundsBuilder<'a: 'b, 'b> {
                 fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
                 start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
             }
-            impl<'a: 'b, 'b> AnimationBuilder<'a, 'b> {
+            impl<'a: 'b, 'b> BoundsBuilder<'a, 'b> {
+                #[inline]
+                pub fn add_min(&mut self, min: flatbuffers::WIPOffset<Vector3<'b>>) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<Vector3>>(Bounds::VT_MIN, min);
+                }
+                #[inline]
+                pub fn add_max(&mut self, max: flatbuffers::WIPOffset<Vector3<'b>>) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<Vector3>>(Bounds::VT_MAX, max);
+                }
+                #[inline]
+                pub fn add_sphere_center(
+                    &mut self,
+                    sphere_center: flatbuffers::WIPOffset<Vector3<'b>>,
+                ) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<Vector3>>(
+                            Bounds::VT_SPHERE_CENTER,
+                            sphere_center,
+                        );
+                }
+                #[inline]
+                pub fn add_sphere_radius(&mut self, sphere_radius: f32) {
+                    self.fbb_
+                        .push_slot::<f32>(Bounds::VT_SPHERE_RADIUS, sphere_radius, 0.0);
+                }
                 #[inline]
                 pub fn new(
                     _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-                ) -> AnimationBuilder<'a, 'b> {
+                ) -> BoundsBuilder<'a, 'b> {
                     let start = _fbb.start_table();
-                    AnimationBuilder {
+                    BoundsBuilder {
                         fbb_: _fbb,
                         start_: start,
                     }
                 }
                 #[inline]
-                pub fn finish(self) -> flatbuffers::WIPOffset<Animation<'a>> {
+                pub fn finish(self) -> flatbuffers::WIPOffset<Bounds<'a>> {
                     let o = self.fbb_.end_table(self.start_);
                     flatbuffers::WIPOffset::new(o.value())
                 }
             }
 
-            pub enum MeshStreamOffset {}
+            pub enum OptimizationStatsOffset {}
             #[derive(Copy, Clone, Debug, PartialEq)]
 
-            pub struct MeshStream<'a> {
+            pub struct OptimizationStats<'a> {
                 pub _tab: flatbuffers::Table<'a>,
             }
 
-            impl<'a> flatbuffers::Follow<'a> for MeshStream<'a> {
-                type Inner = MeshStream<'a>;
+            impl<'a> flatbuffers::Follow<'a> for OptimizationStats<'a> {
+                type Inner = OptimizationStats<'a>;
                 #[inline]
                 fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
                     Self {
@@ -311,137 +2725,123 @@ pub mod service {
                 }
             }
 
-            impl<'a> MeshStream<'a> {
+            impl<'a> OptimizationStats<'a> {
                 #[inline]
                 pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-                    MeshStream { _tab: table }
+                    OptimizationStats { _tab: table }
                 }
                 #[allow(unused_mut)]
                 pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
                     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-                    args: &'args MeshStreamArgs<'args>,
-                ) -> flatbuffers::WIPOffset<MeshStream<'bldr>> {
-                    let mut builder = MeshStreamBuilder::new(_fbb);
-                    builder.add_elements(args.elements);
-                    if let Some(x) = args.data {
-                        builder.add_data(x);
-                    }
-                    builder.add_format(args.format);
-                    builder.add_type_(args.type_);
+                    args: &'args OptimizationStatsArgs,
+                ) -> flatbuffers::WIPOffset<OptimizationStats<'bldr>> {
+                    let mut builder = OptimizationStatsBuilder::new(_fbb);
+                    builder.add_atvr_after(args.atvr_after);
+                    builder.add_acmr_after(args.acmr_after);
+                    builder.add_atvr_before(args.atvr_before);
+                    builder.add_acmr_before(args.acmr_before);
                     builder.finish()
                 }
 
-                pub const VT_TYPE_: flatbuffers::VOffsetT = 4;
-                pub const VT_FORMAT: flatbuffers::VOffsetT = 6;
-                pub const VT_ELEMENTS: flatbuffers::VOffsetT = 8;
-                pub const VT_DATA: flatbuffers::VOffsetT = 10;
+                pub const VT_ACMR_BEFORE: flatbuffers::VOffsetT = 4;
+                pub const VT_ATVR_BEFORE: flatbuffers::VOffsetT = 6;
+                pub const VT_ACMR_AFTER: flatbuffers::VOffsetT = 8;
+                pub const VT_ATVR_AFTER: flatbuffers::VOffsetT = 10;
 
                 #[inline]
-                pub fn type_(&self) -> StreamType {
+                pub fn acmr_before(&self) -> f32 {
                     self._tab
-                        .get::<StreamType>(MeshStream::VT_TYPE_, Some(StreamType::Positions))
+                        .get::<f32>(OptimizationStats::VT_ACMR_BEFORE, Some(0.0))
                         .unwrap()
                 }
                 #[inline]
-                pub fn format(&self) -> StreamFormat {
+                pub fn atvr_before(&self) -> f32 {
                     self._tab
-                        .get::<StreamFormat>(MeshStream::VT_FORMAT, Some(StreamFormat::Invalid))
+                        .get::<f32>(OptimizationStats::VT_ATVR_BEFORE, Some(0.0))
                         .unwrap()
                 }
                 #[inline]
-                pub fn elements(&self) -> u64 {
+                pub fn acmr_after(&self) -> f32 {
                     self._tab
-                        .get::<u64>(MeshStream::VT_ELEMENTS, Some(0))
+                        .get::<f32>(OptimizationStats::VT_ACMR_AFTER, Some(0.0))
                         .unwrap()
                 }
                 #[inline]
-                pub fn data(&self) -> Option<&'a [u8]> {
+                pub fn atvr_after(&self) -> f32 {
                     self._tab
-                        .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
-                            MeshStream::VT_DATA,
-                            None,
-                        )
-                        .map(|v| v.safe_slice())
+                        .get::<f32>(OptimizationStats::VT_ATVR_AFTER, Some(0.0))
+                        .unwrap()
                 }
             }
 
-            pub struct MeshStreamArgs<'a> {
-                pub type_: StreamType,
-                pub format: StreamFormat,
-                pub elements: u64,
-                pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+            pub struct OptimizationStatsArgs {
+                pub acmr_before: f32,
+                pub atvr_before: f32,
+                pub acmr_after: f32,
+                pub atvr_after: f32,
             }
-            impl<'a> Default for MeshStreamArgs<'a> {
+            impl<'a> Default for OptimizationStatsArgs {
                 #[inline]
                 fn default() -> Self {
-                    MeshStreamArgs {
-                        type_: StreamType::Positions,
-                        format: StreamFormat::Invalid,
-                        elements: 0,
-                        data: None,
+                    OptimizationStatsArgs {
+                        acmr_before: 0.0,
+                        atvr_before: 0.0,
+                        acmr_after: 0.0,
+                        atvr_after: 0.0,
                     }
                 }
             }
-            pub struct MeshStreamBuilder<'a: 'b, 'b> {
+            pub struct OptimizationStatsBuilder<'a: 'b, 'b> {
                 fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
                 start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
             }
-            impl<'a: 'b, 'b> MeshStreamBuilder<'a, 'b> {
+            impl<'a: 'b, 'b> OptimizationStatsBuilder<'a, 'b> {
                 #[inline]
-                pub fn add_type_(&mut self, type_: StreamType) {
-                    self.fbb_.push_slot::<StreamType>(
-                        MeshStream::VT_TYPE_,
-                        type_,
-                        StreamType::Positions,
-                    );
+                pub fn add_acmr_before(&mut self, acmr_before: f32) {
+                    self.fbb_
+                        .push_slot::<f32>(OptimizationStats::VT_ACMR_BEFORE, acmr_before, 0.0);
                 }
                 #[inline]
-                pub fn add_format(&mut self, format: StreamFormat) {
-                    self.fbb_.push_slot::<StreamFormat>(
-                        MeshStream::VT_FORMAT,
-                        format,
-                        StreamFormat::Invalid,
-                    );
+                pub fn add_atvr_before(&mut self, atvr_before: f32) {
+                    self.fbb_
+                        .push_slot::<f32>(OptimizationStats::VT_ATVR_BEFORE, atvr_before, 0.0);
                 }
                 #[inline]
-                pub fn add_elements(&mut self, elements: u64) {
+                pub fn add_acmr_after(&mut self, acmr_after: f32) {
                     self.fbb_
-                        .push_slot::<u64>(MeshStream::VT_ELEMENTS, elements, 0);
+                        .push_slot::<f32>(OptimizationStats::VT_ACMR_AFTER, acmr_after, 0.0);
                 }
                 #[inline]
-                pub fn add_data(
-                    &mut self,
-                    data: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>,
-                ) {
+                pub fn add_atvr_after(&mut self, atvr_after: f32) {
                     self.fbb_
-                        .push_slot_always::<flatbuffers::WIPOffset<_>>(MeshStream::VT_DATA, data);
+                        .push_slot::<f32>(OptimizationStats::VT_ATVR_AFTER, atvr_after, 0.0);
                 }
                 #[inline]
                 pub fn new(
                     _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-                ) -> MeshStreamBuilder<'a, 'b> {
+                ) -> OptimizationStatsBuilder<'a, 'b> {
                     let start = _fbb.start_table();
-                    MeshStreamBuilder {
+                    OptimizationStatsBuilder {
                         fbb_: _fbb,
                         start_: start,
                     }
                 }
                 #[inline]
-                pub fn finish(self) -> flatbuffers::WIPOffset<MeshStream<'a>> {
+                pub fn finish(self) -> flatbuffers::WIPOffset<OptimizationStats<'a>> {
                     let o = self.fbb_.end_table(self.start_);
                     flatbuffers::WIPOffset::new(o.value())
                 }
             }
 
-            pub enum MeshMaterialOffset {}
+            pub enum MeshLodPartOffset {}
             #[derive(Copy, Clone, Debug, PartialEq)]
 
-            pub struct MeshMaterial<'a> {
+            pub struct MeshLodPart<'a> {
                 pub _tab: flatbuffers::Table<'a>,
             }
 
-            impl<'a> flatbuffers::Follow<'a> for MeshMaterial<'a> {
-                type Inner = MeshMaterial<'a>;
+            impl<'a> flatbuffers::Follow<'a> for MeshLodPart<'a> {
+                type Inner = MeshLodPart<'a>;
                 #[inline]
                 fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
                     Self {
@@ -450,136 +2850,108 @@ pub mod service {
                 }
             }
 
-            impl<'a> MeshMaterial<'a> {
+            impl<'a> MeshLodPart<'a> {
                 #[inline]
                 pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-                    MeshMaterial { _tab: table }
+                    MeshLodPart { _tab: table }
                 }
                 #[allow(unused_mut)]
                 pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
                     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-                    args: &'args MeshMaterialArgs<'args>,
-                ) -> flatbuffers::WIPOffset<MeshMaterial<'bldr>> {
-                    let mut builder = MeshMaterialBuilder::new(_fbb);
-                    builder.add_roughness(args.roughness);
-                    if let Some(x) = args.albedo_tint {
-                        builder.add_albedo_tint(x);
-                    }
-                    if let Some(x) = args.material {
-                        builder.add_material(x);
-                    }
-                    if let Some(x) = args.name {
-                        builder.add_name(x);
-                    }
+                    args: &'args MeshLodPartArgs,
+                ) -> flatbuffers::WIPOffset<MeshLodPart<'bldr>> {
+                    let mut builder = MeshLodPartBuilder::new(_fbb);
+                    builder.add_error(args.error);
+                    builder.add_index_count(args.index_count);
+                    builder.add_index_start(args.index_start);
                     builder.finish()
                 }
 
-                pub const VT_NAME: flatbuffers::VOffsetT = 4;
-                pub const VT_MATERIAL: flatbuffers::VOffsetT = 6;
-                pub const VT_ALBEDO_TINT: flatbuffers::VOffsetT = 8;
-                pub const VT_ROUGHNESS: flatbuffers::VOffsetT = 10;
-
-                #[inline]
-                pub fn name(&self) -> Option<&'a str> {
-                    self._tab
-                        .get::<flatbuffers::ForwardsUOffset<&str>>(MeshMaterial::VT_NAME, None)
-                }
+                pub const VT_INDEX_START: flatbuffers::VOffsetT = 4;
+                pub const VT_INDEX_COUNT: flatbuffers::VOffsetT = 6;
+                pub const VT_ERROR: flatbuffers::VOffsetT = 8;
+
                 #[inline]
-                pub fn material(&self) -> Option<&'a str> {
+                pub fn index_start(&self) -> u32 {
                     self._tab
-                        .get::<flatbuffers::ForwardsUOffset<&str>>(MeshMaterial::VT_MATERIAL, None)
+                        .get::<u32>(MeshLodPart::VT_INDEX_START, Some(0))
+                        .unwrap()
                 }
                 #[inline]
-                pub fn albedo_tint(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+                pub fn index_count(&self) -> u32 {
                     self._tab
-                        .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
-                            MeshMaterial::VT_ALBEDO_TINT,
-                            None,
-                        )
+                        .get::<u32>(MeshLodPart::VT_INDEX_COUNT, Some(0))
+                        .unwrap()
                 }
                 #[inline]
-                pub fn roughness(&self) -> f32 {
+                pub fn error(&self) -> f32 {
                     self._tab
-                        .get::<f32>(MeshMaterial::VT_ROUGHNESS, Some(0.0))
+                        .get::<f32>(MeshLodPart::VT_ERROR, Some(0.0))
                         .unwrap()
                 }
             }
 
-            pub struct MeshMaterialArgs<'a> {
-                pub name: Option<flatbuffers::WIPOffset<&'a str>>,
-                pub material: Option<flatbuffers::WIPOffset<&'a str>>,
-                pub albedo_tint: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
-                pub roughness: f32,
+            pub struct MeshLodPartArgs {
+                pub index_start: u32,
+                pub index_count: u32,
+                pub error: f32,
             }
-            impl<'a> Default for MeshMaterialArgs<'a> {
+            impl<'a> Default for MeshLodPartArgs {
                 #[inline]
                 fn default() -> Self {
-                    MeshMaterialArgs {
-                        name: None,
-                        material: None,
-                        albedo_tint: None,
-                        roughness: 0.0,
+                    MeshLodPartArgs {
+                        index_start: 0,
+                        index_count: 0,
+                        error: 0.0,
                     }
                 }
             }
-            pub struct MeshMaterialBuilder<'a: 'b, 'b> {
+            pub struct MeshLodPartBuilder<'a: 'b, 'b> {
                 fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
                 start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
             }
-            impl<'a: 'b, 'b> MeshMaterialBuilder<'a, 'b> {
+            impl<'a: 'b, 'b> MeshLodPartBuilder<'a, 'b> {
                 #[inline]
-                pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+                pub fn add_index_start(&mut self, index_start: u32) {
                     self.fbb_
-                        .push_slot_always::<flatbuffers::WIPOffset<_>>(MeshMaterial::VT_NAME, name);
-                }
-                #[inline]
-                pub fn add_material(&mut self, material: flatbuffers::WIPOffset<&'b str>) {
-                    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-                        MeshMaterial::VT_MATERIAL,
-                        material,
-                    );
+                        .push_slot::<u32>(MeshLodPart::VT_INDEX_START, index_start, 0);
                 }
                 #[inline]
-                pub fn add_albedo_tint(
-                    &mut self,
-                    albedo_tint: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>,
-                ) {
-                    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-                        MeshMaterial::VT_ALBEDO_TINT,
-                        albedo_tint,
-                    );
+                pub fn add_index_count(&mut self, index_count: u32) {
+                    self.fbb_
+                        .push_slot::<u32>(MeshLodPart::VT_INDEX_COUNT, index_count, 0);
                 }
                 #[inline]
-                pub fn add_roughness(&mut self, roughness: f32) {
+                pub fn add_error(&mut self, error: f32) {
                     self.fbb_
-                        .push_slot::<f32>(MeshMaterial::VT_ROUGHNESS, roughness, 0.0);
+                        .push_slot::<f32>(MeshLodPart::VT_ERROR, error, 0.0);
                 }
                 #[inline]
                 pub fn new(
                     _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-                ) -> MeshMaterialBuilder<'a, 'b> {
+                ) -> MeshLodPartBuilder<'a, 'b> {
                     let start = _fbb.start_table();
-                    MeshMaterialBuilder {
+                    MeshLodPartBuilder {
                         fbb_: _fbb,
                         start_: start,
                     }
                 }
                 #[inline]
-                pub fn finish(self) -> flatbuffers::WIPOffset<MeshMaterial<'a>> {
+                pub fn finish(self) -> flatbuffers::WIPOffset<MeshLodPart<'a>> {
                     let o = self.fbb_.end_table(self.start_);
                     flatbuffers::WIPOffset::new(o.value())
                 }
             }
 
-            pub enum MeshPartOffset {}
+            pub enum MeshLodOffset {}
             #[derive(Copy, Clone, Debug, PartialEq)]
 
-            pub struct MeshPart<'a> {
+            pub struct MeshLod<'a> {
                 pub _tab: flatbuffers::Table<'a>,
             }
 
-            impl<'a> flatbuffers::Follow<'a> for MeshPart<'a> {
-                type Inner = MeshPart<'a>;
+            impl<'a> flatbuffers::Follow<'a> for MeshLod<'a> {
+                type Inner = MeshLod<'a>;
                 #[inline]
                 fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
                     Self {
@@ -588,170 +2960,117 @@ pub mod service {
                 }
             }
 
-            impl<'a> MeshPart<'a> {
+            impl<'a> MeshLod<'a> {
                 #[inline]
                 pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-                    MeshPart { _tab: table }
+                    MeshLod { _tab: table }
                 }
                 #[allow(unused_mut)]
                 pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
                     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-                    args: &'args MeshPartArgs<'args>,
-                ) -> flatbuffers::WIPOffset<MeshPart<'bldr>> {
-                    let mut builder = MeshPartBuilder::new(_fbb);
-                    if let Some(x) = args.name {
-                        builder.add_name(x);
-                    }
-                    if let Some(x) = args.base_transform {
-                        builder.add_base_transform(x);
+                    args: &'args MeshLodArgs<'args>,
+                ) -> flatbuffers::WIPOffset<MeshLod<'bldr>> {
+                    let mut builder = MeshLodBuilder::new(_fbb);
+                    if let Some(x) = args.parts {
+                        builder.add_parts(x);
                     }
-                    builder.add_node_index(args.node_index);
-                    builder.add_material_index(args.material_index);
-                    builder.add_index_count(args.index_count);
-                    builder.add_index_start(args.index_start);
-                    builder.add_animation_type(args.animation_type);
+                    builder.add_error(args.error);
+                    builder.add_max_error(args.max_error);
+                    builder.add_ratio(args.ratio);
                     builder.finish()
                 }
 
-                pub const VT_INDEX_START: flatbuffers::VOffsetT = 4;
-                pub const VT_INDEX_COUNT: flatbuffers::VOffsetT = 6;
-                pub const VT_MATERIAL_INDEX: flatbuffers::VOffsetT = 8;
-                pub const VT_NODE_INDEX: flatbuffers::VOffsetT = 10;
-                pub const VT_BASE_TRANSFORM: flatbuffers::VOffsetT = 12;
-                pub const VT_NAME: flatbuffers::VOffsetT = 14;
-                pub const VT_ANIMATION_TYPE: flatbuffers::VOffsetT = 16;
+                pub const VT_RATIO: flatbuffers::VOffsetT = 4;
+                pub const VT_MAX_ERROR: flatbuffers::VOffsetT = 6;
+                pub const VT_ERROR: flatbuffers::VOffsetT = 8;
+                pub const VT_PARTS: flatbuffers::VOffsetT = 10;
 
                 #[inline]
-                pub fn index_start(&self) -> u32 {
-                    self._tab
-                        .get::<u32>(MeshPart::VT_INDEX_START, Some(0))
-                        .unwrap()
-                }
-                #[inline]
-                pub fn index_count(&self) -> u32 {
-                    self._tab
-                        .get::<u32>(MeshPart::VT_INDEX_COUNT, Some(0))
-                        .unwrap()
-                }
-                #[inline]
-                pub fn material_index(&self) -> i32 {
-                    self._tab
-                        .get::<i32>(MeshPart::VT_MATERIAL_INDEX, Some(0))
-                        .unwrap()
+                pub fn ratio(&self) -> f32 {
+                    self._tab.get::<f32>(MeshLod::VT_RATIO, Some(0.0)).unwrap()
                 }
                 #[inline]
-                pub fn node_index(&self) -> i32 {
+                pub fn max_error(&self) -> f32 {
                     self._tab
-                        .get::<i32>(MeshPart::VT_NODE_INDEX, Some(0))
+                        .get::<f32>(MeshLod::VT_MAX_ERROR, Some(0.0))
                         .unwrap()
                 }
                 #[inline]
-                pub fn base_transform(&self) -> Option<flatbuffers::Vector<'a, f32>> {
-                    self._tab
-                        .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
-                            MeshPart::VT_BASE_TRANSFORM,
-                            None,
-                        )
-                }
-                #[inline]
-                pub fn name(&self) -> Option<&'a str> {
-                    self._tab
-                        .get::<flatbuffers::ForwardsUOffset<&str>>(MeshPart::VT_NAME, None)
+                pub fn error(&self) -> f32 {
+                    self._tab.get::<f32>(MeshLod::VT_ERROR, Some(0.0)).unwrap()
                 }
                 #[inline]
-                pub fn animation_type(&self) -> AnimationType {
-                    self._tab
-                        .get::<AnimationType>(
-                            MeshPart::VT_ANIMATION_TYPE,
-                            Some(AnimationType::None),
-                        )
-                        .unwrap()
+                pub fn parts(
+                    &self,
+                ) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<MeshLodPart<'a>>>>
+                {
+                    self._tab.get::<flatbuffers::ForwardsUOffset<
+                        flatbuffers::Vector<flatbuffers::ForwardsUOffset<MeshLodPart<'a>>>,
+                    >>(MeshLod::VT_PARTS, None)
                 }
             }
 
-            pub struct MeshPartArgs<'a> {
-                pub index_start: u32,
-                pub index_count: u32,
-                pub material_index: i32,
-                pub node_index: i32,
-                pub base_transform: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
-                pub name: Option<flatbuffers::WIPOffset<&'a str>>,
-                pub animation_type: AnimationType,
+            pub struct MeshLodArgs<'a> {
+                pub ratio: f32,
+                pub max_error: f32,
+                pub error: f32,
+                pub parts: Option<
+                    flatbuffers::WIPOffset<
+                        flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MeshLodPart<'a>>>,
+                    >,
+                >,
             }
-            impl<'a> Default for MeshPartArgs<'a> {
+            impl<'a> Default for MeshLodArgs<'a> {
                 #[inline]
                 fn default() -> Self {
-                    MeshPartArgs {
-                        index_start: 0,
-                        index_count: 0,
-                        material_index: 0,
-                        node_index: 0,
-                        base_transform: None,
-                        name: None,
-                        animation_type: AnimationType::None,
+                    MeshLodArgs {
+                        ratio: 0.0,
+                        max_error: 0.0,
+                        error: 0.0,
+                        parts: None,
                     }
                 }
             }
-            pub struct MeshPartBuilder<'a: 'b, 'b> {
+            pub struct MeshLodBuilder<'a: 'b, 'b> {
                 fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
                 start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
             }
-            impl<'a: 'b, 'b> MeshPartBuilder<'a, 'b> {
-                #[inline]
-                pub fn add_index_start(&mut self, index_start: u32) {
-                    self.fbb_
-                        .push_slot::<u32>(MeshPart::VT_INDEX_START, index_start, 0);
-                }
+            impl<'a: 'b, 'b> MeshLodBuilder<'a, 'b> {
                 #[inline]
-                pub fn add_index_count(&mut self, index_count: u32) {
-                    self.fbb_
-                        .push_slot::<u32>(MeshPart::VT_INDEX_COUNT, index_count, 0);
+                pub fn add_ratio(&mut self, ratio: f32) {
+                    self.fbb_.push_slot::<f32>(MeshLod::VT_RATIO, ratio, 0.0);
                 }
                 #[inline]
-                pub fn add_material_index(&mut self, material_index: i32) {
+                pub fn add_max_error(&mut self, max_error: f32) {
                     self.fbb_
-                        .push_slot::<i32>(MeshPart::VT_MATERIAL_INDEX, material_index, 0);
+                        .push_slot::<f32>(MeshLod::VT_MAX_ERROR, max_error, 0.0);
                 }
                 #[inline]
-                pub fn add_node_index(&mut self, node_index: i32) {
-                    self.fbb_
-                        .push_slot::<i32>(MeshPart::VT_NODE_INDEX, node_index, 0);
+                pub fn add_error(&mut self, error: f32) {
+                    self.fbb_.push_slot::<f32>(MeshLod::VT_ERROR, error, 0.0);
                 }
                 #[inline]
-                pub fn add_base_transform(
+                pub fn add_parts(
                     &mut self,
-                    base_transform: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>,
+                    parts: flatbuffers::WIPOffset<
+                        flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<MeshLodPart<'b>>>,
+                    >,
                 ) {
-                    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-                        MeshPart::VT_BASE_TRANSFORM,
-                        base_transform,
-                    );
-                }
-                #[inline]
-                pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
                     self.fbb_
-                        .push_slot_always::<flatbuffers::WIPOffset<_>>(MeshPart::VT_NAME, name);
-                }
-                #[inline]
-                pub fn add_animation_type(&mut self, animation_type: AnimationType) {
-                    self.fbb_.push_slot::<AnimationType>(
-                        MeshPart::VT_ANIMATION_TYPE,
-                        animation_type,
-                        AnimationType::None,
-                    );
+                        .push_slot_always::<flatbuffers::WIPOffset<_>>(MeshLod::VT_PARTS, parts);
                 }
                 #[inline]
                 pub fn new(
                     _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-                ) -> MeshPartBuilder<'a, 'b> {
+                ) -> MeshLodBuilder<'a, 'b> {
                     let start = _fbb.start_table();
-                    MeshPartBuilder {
+                    MeshLodBuilder {
                         fbb_: _fbb,
                         start_: start,
                     }
                 }
                 #[inline]
-                pub fn finish(self) -> flatbuffers::WIPOffset<MeshPart<'a>> {
+                pub fn finish(self) -> flatbuffers::WIPOffset<MeshLod<'a>> {
                     let o = self.fbb_.end_table(self.start_);
                     flatbuffers::WIPOffset::new(o.value())
                 }
@@ -785,6 +3104,22 @@ pub mod service {
                     args: &'args MeshArgs<'args>,
                 ) -> flatbuffers::WIPOffset<Mesh<'bldr>> {
                     let mut builder = MeshBuilder::new(_fbb);
+                    if let Some(x) = args.bounds {
+                        builder.add_bounds(x);
+                    }
+                    if let Some(x) = args.lods {
+                        builder.add_lods(x);
+                    }
+                    if let Some(x) = args.optimization {
+                        builder.add_optimization(x);
+                    }
+                    builder.add_skinning_influences(args.skinning_influences);
+                    if let Some(x) = args.skeleton {
+                        builder.add_skeleton(x);
+                    }
+                    if let Some(x) = args.node_parts {
+                        builder.add_node_parts(x);
+                    }
                     if let Some(x) = args.bounding_max {
                         builder.add_bounding_max(x);
                     }
@@ -824,6 +3159,12 @@ pub mod service {
                 pub const VT_ANIMATIONS: flatbuffers::VOffsetT = 16;
                 pub const VT_BOUNDING_MIN: flatbuffers::VOffsetT = 18;
                 pub const VT_BOUNDING_MAX: flatbuffers::VOffsetT = 20;
+                pub const VT_NODE_PARTS: flatbuffers::VOffsetT = 22;
+                pub const VT_SKELETON: flatbuffers::VOffsetT = 24;
+                pub const VT_SKINNING_INFLUENCES: flatbuffers::VOffsetT = 26;
+                pub const VT_OPTIMIZATION: flatbuffers::VOffsetT = 28;
+                pub const VT_LODS: flatbuffers::VOffsetT = 30;
+                pub const VT_BOUNDS: flatbuffers::VOffsetT = 32;
 
                 #[inline]
                 pub fn name(&self) -> Option<&'a str> {
@@ -881,21 +3222,61 @@ pub mod service {
                     >>(Mesh::VT_ANIMATIONS, None)
                 }
                 #[inline]
-                pub fn bounding_min(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+                pub fn bounding_min(&self) -> Option<Vector3<'a>> {
+                    self._tab.get::<flatbuffers::ForwardsUOffset<Vector3<'a>>>(
+                        Mesh::VT_BOUNDING_MIN,
+                        None,
+                    )
+                }
+                #[inline]
+                pub fn bounding_max(&self) -> Option<Vector3<'a>> {
+                    self._tab.get::<flatbuffers::ForwardsUOffset<Vector3<'a>>>(
+                        Mesh::VT_BOUNDING_MAX,
+                        None,
+                    )
+                }
+                #[inline]
+                pub fn node_parts(
+                    &self,
+                ) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<NodeParts<'a>>>>
+                {
+                    self._tab.get::<flatbuffers::ForwardsUOffset<
+                        flatbuffers::Vector<flatbuffers::ForwardsUOffset<NodeParts<'a>>>,
+                    >>(Mesh::VT_NODE_PARTS, None)
+                }
+                #[inline]
+                pub fn skeleton(&self) -> Option<Skeleton<'a>> {
                     self._tab
-                        .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
-                            Mesh::VT_BOUNDING_MIN,
-                            None,
-                        )
+                        .get::<flatbuffers::ForwardsUOffset<Skeleton<'a>>>(Mesh::VT_SKELETON, None)
                 }
                 #[inline]
-                pub fn bounding_max(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+                pub fn skinning_influences(&self) -> u32 {
                     self._tab
-                        .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
-                            Mesh::VT_BOUNDING_MAX,
+                        .get::<u32>(Mesh::VT_SKINNING_INFLUENCES, Some(0))
+                        .unwrap()
+                }
+                #[inline]
+                pub fn optimization(&self) -> Option<OptimizationStats<'a>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<OptimizationStats<'a>>>(
+                            Mesh::VT_OPTIMIZATION,
                             None,
                         )
                 }
+                #[inline]
+                pub fn lods(
+                    &self,
+                ) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<MeshLod<'a>>>>
+                {
+                    self._tab.get::<flatbuffers::ForwardsUOffset<
+                        flatbuffers::Vector<flatbuffers::ForwardsUOffset<MeshLod<'a>>>,
+                    >>(Mesh::VT_LODS, None)
+                }
+                #[inline]
+                pub fn bounds(&self) -> Option<Bounds<'a>> {
+                    self._tab
+                        .get::<flatbuffers::ForwardsUOffset<Bounds<'a>>>(Mesh::VT_BOUNDS, None)
+                }
             }
 
             pub struct MeshArgs<'a> {
@@ -922,8 +3303,22 @@ pub mod service {
                         flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Animation<'a>>>,
                     >,
                 >,
-                pub bounding_min: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
-                pub bounding_max: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+                pub bounding_min: Option<flatbuffers::WIPOffset<Vector3<'a>>>,
+                pub bounding_max: Option<flatbuffers::WIPOffset<Vector3<'a>>>,
+                pub node_parts: Option<
+                    flatbuffers::WIPOffset<
+                        flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NodeParts<'a>>>,
+                    >,
+                >,
+                pub skeleton: Option<flatbuffers::WIPOffset<Skeleton<'a>>>,
+                pub skinning_influences: u32,
+                pub optimization: Option<flatbuffers::WIPOffset<OptimizationStats<'a>>>,
+                pub lods: Option<
+                    flatbuffers::WIPOffset<
+                        flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MeshLod<'a>>>,
+                    >,
+                >,
+                pub bounds: Option<flatbuffers::WIPOffset<Bounds<'a>>>,
             }
             impl<'a> Default for MeshArgs<'a> {
                 #[inline]
@@ -938,6 +3333,12 @@ pub mod service {
                         animations: None,
                         bounding_min: None,
                         bounding_max: None,
+                        node_parts: None,
+                        skeleton: None,
+                        skinning_influences: 0,
+                        optimization: None,
+                        lods: None,
+                        bounds: None,
                     }
                 }
             }
@@ -1013,24 +3414,83 @@ pub mod service {
                 #[inline]
                 pub fn add_bounding_min(
                     &mut self,
-                    bounding_min: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>,
+                    bounding_min: flatbuffers::WIPOffset<Vector3<'b>>,
                 ) {
-                    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-                        Mesh::VT_BOUNDING_MIN,
-                        bounding_min,
-                    );
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<Vector3>>(
+                            Mesh::VT_BOUNDING_MIN,
+                            bounding_min,
+                        );
                 }
                 #[inline]
                 pub fn add_bounding_max(
                     &mut self,
-                    bounding_max: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>,
+                    bounding_max: flatbuffers::WIPOffset<Vector3<'b>>,
+                ) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<Vector3>>(
+                            Mesh::VT_BOUNDING_MAX,
+                            bounding_max,
+                        );
+                }
+                #[inline]
+                pub fn add_node_parts(
+                    &mut self,
+                    node_parts: flatbuffers::WIPOffset<
+                        flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<NodeParts<'b>>>,
+                    >,
                 ) {
                     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-                        Mesh::VT_BOUNDING_MAX,
-                        bounding_max,
+                        Mesh::VT_NODE_PARTS,
+                        node_parts,
+                    );
+                }
+                #[inline]
+                pub fn add_skeleton(&mut self, skeleton: flatbuffers::WIPOffset<Skeleton<'b>>) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<Skeleton>>(
+                            Mesh::VT_SKELETON,
+                            skeleton,
+                        );
+                }
+                #[inline]
+                pub fn add_skinning_influences(&mut self, skinning_influences: u32) {
+                    self.fbb_.push_slot::<u32>(
+                        Mesh::VT_SKINNING_INFLUENCES,
+                        skinning_influences,
+                        0,
                     );
                 }
                 #[inline]
+                pub fn add_optimization(
+                    &mut self,
+                    optimization: flatbuffers::WIPOffset<OptimizationStats<'b>>,
+                ) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<OptimizationStats>>(
+                            Mesh::VT_OPTIMIZATION,
+                            optimization,
+                        );
+                }
+                #[inline]
+                pub fn add_lods(
+                    &mut self,
+                    lods: flatbuffers::WIPOffset<
+                        flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<MeshLod<'b>>>,
+                    >,
+                ) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_LODS, lods);
+                }
+                #[inline]
+                pub fn add_bounds(&mut self, bounds: flatbuffers::WIPOffset<Bounds<'b>>) {
+                    self.fbb_
+                        .push_slot_always::<flatbuffers::WIPOffset<Bounds>>(
+                            Mesh::VT_BOUNDS,
+                            bounds,
+                        );
+                }
+                #[inline]
                 pub fn new(
                     _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
                 ) -> MeshBuilder<'a, 'b> {
@@ -0,0 +1,273 @@
+use crate::GltfOptions;
+use crate::StreamEncoding;
+use sha2::{Digest, Sha256};
+
+/// Revision of the processing pipeline, bump it whenever the output for the
+/// same source and options changes so cached meshes are not reused.
+const PIPELINE_REVISION: u64 = 8;
+
+/// Incremental SHA-256 over labelled, length prefixed values, so adjacent
+/// values can never be confused with each other.
+pub struct IdentityHasher(Sha256);
+
+impl IdentityHasher {
+    pub fn new() -> Self {
+        IdentityHasher(Sha256::new())
+    }
+
+    pub fn write(&mut self, label: &str, bytes: &[u8]) {
+        self.0.input(&(label.len() as u64).to_le_bytes());
+        self.0.input(label.as_bytes());
+        self.0.input(&(bytes.len() as u64).to_le_bytes());
+        self.0.input(bytes);
+    }
+
+    pub fn write_u64(&mut self, label: &str, value: u64) {
+        self.write(label, &value.to_le_bytes());
+    }
+
+    pub fn write_f32(&mut self, label: &str, value: f32) {
+        self.write(label, &value.to_bits().to_le_bytes());
+    }
+
+    pub fn write_bool(&mut self, label: &str, value: bool) {
+        self.write(label, &[value as u8]);
+    }
+
+    pub fn finish(self) -> Vec<u8> {
+        self.0.result().to_vec()
+    }
+}
+
+impl Default for IdentityHasher {
+    fn default() -> Self {
+        IdentityHasher::new()
+    }
+}
+
+/// Hash every option that affects the processed output.
+fn hash_options(hasher: &mut IdentityHasher, options: &GltfOptions) {
+    hasher.write_u64(
+        "scene_index",
+        options
+            .scene_index
+            .map_or(std::u64::MAX, |index| index as u64),
+    );
+    hasher.write_bool("load_animations", options.load_animations);
+    hasher.write_bool("regenerate_tangents", options.regenerate_tangents);
+    hasher.write_f32("generate_tex_coord_u", options.generate_tex_coords.0);
+    hasher.write_f32("generate_tex_coord_v", options.generate_tex_coords.1);
+    hasher.write_bool("flip_v_coord", options.flip_v_coord);
+    hasher.write_bool("split_scenes", options.split_scenes);
+    hasher.write_f32("weld_epsilon", options.weld_epsilon);
+    hasher.write_bool("optimize", options.optimize);
+    hasher.write_f32("overdraw_threshold", options.overdraw_threshold);
+    hasher.write_u64("lod_ratios", options.lod_ratios.len() as u64);
+    for &ratio in &options.lod_ratios {
+        hasher.write_f32("lod_ratio", ratio);
+    }
+    hasher.write_u64("lod_errors", options.lod_errors.len() as u64);
+    for &error in &options.lod_errors {
+        hasher.write_f32("lod_error", error);
+    }
+    hasher.write_bool("build_meshlets", options.build_meshlets);
+    hasher.write_u64("meshlet_max_vertices", options.meshlet_max_vertices.into());
+    hasher.write_u64(
+        "meshlet_max_triangles",
+        options.meshlet_max_triangles.into(),
+    );
+    let quantization = &options.quantization;
+    let encodings = [
+        ("quantize_positions", quantization.positions),
+        ("quantize_normals", quantization.normals),
+        ("quantize_tangents", quantization.tangents),
+        ("quantize_bitangents", quantization.bitangents),
+        ("quantize_tex_coords", quantization.tex_coords),
+        ("quantize_colors", quantization.colors),
+    ];
+    for &(name, encoding) in &encodings {
+        let encoding = encoding.unwrap_or(StreamEncoding::Float);
+        hasher.write_u64(name, encoding as u64);
+    }
+    hasher.write_bool("split_large_parts", options.split_large_parts);
+    hasher.write_bool("interleave", options.interleave);
+    hasher.write_u64(
+        "interleaved_attributes",
+        options.interleaved_attributes.len() as u64,
+    );
+    for &semantic in &options.interleaved_attributes {
+        hasher.write_u64("interleaved_attribute", semantic as u64);
+    }
+    hasher.write_bool("compress_streams", options.compress_streams);
+    hasher.write_bool("instancing", options.instancing);
+    hasher.write_f32("global_scale", options.global_scale);
+    hasher.write_u64("units", options.units as u64);
+    hasher.write_u64("up_axis", options.up_axis as u64);
+    hasher.write_u64("handedness", options.handedness as u64);
+}
+
+/// Identity of the mesh exported from `scene_index`: a hex encoded hash of the
+/// service version and pipeline revision, the source bytes (including external
+/// buffers and images) and the effective options. Identical inputs produce
+/// identical identities.
+pub fn mesh_identity(source_digest: &[u8], options: &GltfOptions, scene_index: usize) -> String {
+    let mut hasher = IdentityHasher::new();
+    hasher.write("version", env!("CARGO_PKG_VERSION").as_bytes());
+    hasher.write_u64("revision", PIPELINE_REVISION);
+    hasher.write("source", source_digest);
+    hash_options(&mut hasher, options);
+    hasher.write_u64("scene", scene_index as u64);
+
+    hasher
+        .finish()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external_triangle_gltf;
+    use crate::GltfSource;
+    use crate::Handedness;
+    use crate::LengthUnit;
+    use crate::UpAxis;
+    use crate::UriResolver;
+    use crate::VertexSemantic;
+    use std::collections::{HashMap, HashSet};
+
+    fn identity(document: &[u8], buffer: &[u8], options: &GltfOptions) -> String {
+        let mut blobs = HashMap::new();
+        blobs.insert("triangle.bin".to_string(), buffer.to_vec());
+        let source =
+            GltfSource::from_slice(document, &UriResolver::Memory(&blobs), options.clone())
+                .unwrap();
+        mesh_identity(&source.source_digest, &source.options, 0)
+    }
+
+    #[test]
+    fn identical_inputs_have_identical_identities() {
+        let (document, buffer) = external_triangle_gltf();
+        assert_eq!(
+            identity(&document, &buffer, &GltfOptions::default()),
+            identity(&document, &buffer, &GltfOptions::default())
+        );
+    }
+
+    #[test]
+    fn every_option_changes_the_identity() {
+        // Fails to compile when an option is added, so it gets a mutation below
+        let GltfOptions {
+            scene_index: _,
+            load_animations: _,
+            regenerate_tangents: _,
+            generate_tex_coords: _,
+            flip_v_coord: _,
+            split_scenes: _,
+            weld_epsilon: _,
+            optimize: _,
+            overdraw_threshold: _,
+            lod_ratios: _,
+            lod_errors: _,
+            build_meshlets: _,
+            meshlet_max_vertices: _,
+            meshlet_max_triangles: _,
+            quantization: _,
+            split_large_parts: _,
+            interleave: _,
+            interleaved_attributes: _,
+            compress_streams: _,
+            instancing: _,
+            global_scale: _,
+            units: _,
+            up_axis: _,
+            handedness: _,
+        } = GltfOptions::default();
+
+        let mutations: &[(&str, fn(&mut GltfOptions))] = &[
+            ("scene_index", |o| o.scene_index = Some(0)),
+            ("load_animations", |o| {
+                o.load_animations = !o.load_animations
+            }),
+            ("regenerate_tangents", |o| {
+                o.regenerate_tangents = !o.regenerate_tangents
+            }),
+            ("generate_tex_coords.0", |o| o.generate_tex_coords.0 += 1.0),
+            ("generate_tex_coords.1", |o| o.generate_tex_coords.1 += 1.0),
+            ("flip_v_coord", |o| o.flip_v_coord = !o.flip_v_coord),
+            ("split_scenes", |o| o.split_scenes = !o.split_scenes),
+            ("weld_epsilon", |o| o.weld_epsilon += 0.001),
+            ("optimize", |o| o.optimize = !o.optimize),
+            ("overdraw_threshold", |o| o.overdraw_threshold += 0.1),
+            ("lod_ratios", |o| o.lod_ratios.push(0.5)),
+            ("lod_errors", |o| o.lod_errors.push(0.01)),
+            ("build_meshlets", |o| o.build_meshlets = !o.build_meshlets),
+            ("meshlet_max_vertices", |o| o.meshlet_max_vertices += 1),
+            ("meshlet_max_triangles", |o| o.meshlet_max_triangles += 1),
+            ("quantization.positions", |o| {
+                o.quantization.positions = Some(StreamEncoding::Unorm16)
+            }),
+            ("quantization.normals", |o| {
+                o.quantization.normals = Some(StreamEncoding::Octahedral16)
+            }),
+            ("quantization.tangents", |o| {
+                o.quantization.tangents = Some(StreamEncoding::Octahedral8)
+            }),
+            ("quantization.bitangents", |o| {
+                o.quantization.bitangents = Some(StreamEncoding::Snorm8)
+            }),
+            ("quantization.tex_coords", |o| {
+                o.quantization.tex_coords = Some(StreamEncoding::Half)
+            }),
+            ("quantization.colors", |o| {
+                o.quantization.colors = Some(StreamEncoding::Unorm8)
+            }),
+            ("split_large_parts", |o| {
+                o.split_large_parts = !o.split_large_parts
+            }),
+            ("interleave", |o| o.interleave = !o.interleave),
+            ("interleaved_attributes", |o| {
+                o.interleaved_attributes.push(VertexSemantic::Positions)
+            }),
+            ("compress_streams", |o| {
+                o.compress_streams = !o.compress_streams
+            }),
+            ("instancing", |o| o.instancing = !o.instancing),
+            ("global_scale", |o| o.global_scale *= 2.0),
+            ("units", |o| o.units = LengthUnit::Inches),
+            ("up_axis", |o| o.up_axis = UpAxis::Z),
+            ("handedness", |o| o.handedness = Handedness::Left),
+        ];
+
+        let (document, buffer) = external_triangle_gltf();
+        let base = GltfOptions::default();
+        let mut identities = HashSet::new();
+        identities.insert(identity(&document, &buffer, &base));
+        for &(name, mutate) in mutations {
+            let mut options = base.clone();
+            mutate(&mut options);
+            assert!(
+                identities.insert(identity(&document, &buffer, &options)),
+                "changing {} does not change the identity",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn source_bytes_change_the_identity() {
+        let (document, buffer) = external_triangle_gltf();
+        let options = GltfOptions::default();
+        let base = identity(&document, &buffer, &options);
+
+        // Same document, reformatted
+        let reformatted = [&document[..], &b"\n"[..]].concat();
+        assert_ne!(identity(&reformatted, &buffer, &options), base);
+
+        // Same document, different external buffer
+        let mut changed = buffer;
+        changed[0] ^= 1;
+        assert_ne!(identity(&document, &changed, &options), base);
+    }
+}
@@ -0,0 +1,197 @@
+use crate::generated::service::mesh::schema;
+use crate::LodLevel;
+use crate::MeshAssetPart;
+use crate::StreamData;
+use crate::Topology;
+
+/// Largest index relative to the base vertex of a part stored in 16 bits, so a
+/// part spans at most 65535 vertices. 0xffff is left free as it is the
+/// primitive restart index of most graphics APIs.
+pub const MAX_SHORT_INDEX: u32 = 0xfffe;
+
+/// Split parts spanning more vertices than 16-bit indices can address into
+/// parts of consecutive primitives that fit, remapping `node_parts` to the new
+/// part indices.
+pub fn split_large_parts(
+    indices: &[u32],
+    parts: &mut Vec<MeshAssetPart>,
+    node_parts: &mut [(String, Vec<u32>)],
+) {
+    let mut split: Vec<MeshAssetPart> = Vec::with_capacity(parts.len());
+    let mut remap: Vec<Vec<u32>> = Vec::with_capacity(parts.len());
+    for part in parts.iter() {
+        let first = split.len() as u32;
+        for (index_start, index_count) in
+            split_range(indices, part.index_start, part.index_count, part.topology)
+        {
+            split.push(MeshAssetPart {
+                index_start,
+                index_count,
+                ..part.clone()
+            });
+        }
+        remap.push((first..split.len() as u32).collect());
+    }
+
+    *parts = split;
+
+    for (_, part_indices) in node_parts.iter_mut() {
+        *part_indices = part_indices
+            .iter()
+            .flat_map(|&part| remap[part as usize].iter().cloned())
+            .collect();
+    }
+}
+
+/// Split an index range at primitive boundaries into ranges whose vertex span
+/// fits 16-bit indices.
+fn split_range(indices: &[u32], start: u32, count: u32, topology: Topology) -> Vec<(u32, u32)> {
+    let end = start + count;
+    let mut ranges = Vec::new();
+    let mut range_start = start;
+    let mut min = std::u32::MAX;
+    let mut max = 0;
+    let size = topology.primitive_size();
+    for primitive_start in (start..end).step_by(size as usize) {
+        let primitive_end = (primitive_start + size).min(end);
+        let primitive = &indices[primitive_start as usize..primitive_end as usize];
+        let primitive_min = primitive.iter().cloned().min().unwrap_or(0);
+        let primitive_max = primitive.iter().cloned().max().unwrap_or(0);
+
+        if max.max(primitive_max) - min.min(primitive_min) > MAX_SHORT_INDEX
+            && primitive_start > range_start
+        {
+            ranges.push((range_start, primitive_start - range_start));
+            range_start = primitive_start;
+            min = primitive_min;
+            max = primitive_max;
+        } else {
+            min = min.min(primitive_min);
+            max = max.max(primitive_max);
+        }
+    }
+    ranges.push((range_start, end - range_start));
+    ranges
+}
+
+/// Build the index stream. When the vertex range of every part fits, indices
+/// are 16-bit and stored relative to the lowest vertex of their part, recorded
+/// as its `base_vertex`, and so are the indices of its LOD ranges. Otherwise
+/// they are 32-bit and absolute, and every `base_vertex` is 0.
+pub fn index_stream(indices: &[u32], parts: &mut [MeshAssetPart], lods: &[LodLevel]) -> StreamData {
+    let vertex_range = |part: &MeshAssetPart| {
+        let start = part.index_start as usize;
+        let range = &indices[start..start + part.index_count as usize];
+        let base = range.iter().cloned().min().unwrap_or(0);
+        let max = range.iter().cloned().max().unwrap_or(base);
+        (base, max)
+    };
+    let short = parts.iter().all(|part| {
+        let (base, max) = vertex_range(part);
+        max - base <= MAX_SHORT_INDEX
+    });
+    if !short {
+        for part in parts.iter_mut() {
+            part.base_vertex = 0;
+        }
+        return StreamData::new(
+            schema::StreamType::Indices,
+            schema::StreamFormat::Int,
+            indices.to_vec(),
+        );
+    }
+
+    let mut bases = vec![0u32; indices.len()];
+    for (part_index, part) in parts.iter_mut().enumerate() {
+        let (base, _) = vertex_range(part);
+        part.base_vertex = base;
+
+        // LOD levels only reference vertices of their source part
+        let lod_ranges = lods.iter().map(|level| {
+            let lod_part = &level.parts[part_index];
+            (lod_part.index_start, lod_part.index_count)
+        });
+        for (start, count) in
+            std::iter::once((part.index_start, part.index_count)).chain(lod_ranges)
+        {
+            for index_base in &mut bases[start as usize..(start + count) as usize] {
+                *index_base = base;
+            }
+        }
+    }
+
+    StreamData::new(
+        schema::StreamType::Indices,
+        schema::StreamFormat::Short,
+        indices
+            .iter()
+            .zip(&bases)
+            .map(|(index, base)| (index - base) as u16)
+            .collect::<Vec<u16>>(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::triangle_part;
+
+    /// Check that `ranges` split `start..start + count` in order, at primitive
+    /// boundaries, into ranges spanning at most 65535 vertices.
+    fn assert_split(indices: &[u32], start: u32, count: u32, topology: Topology) -> usize {
+        let ranges = split_range(indices, start, count, topology);
+        let mut next = start;
+        for &(range_start, range_count) in &ranges {
+            assert_eq!(range_start, next);
+            assert!(range_count > 0);
+            assert_eq!(range_count % topology.primitive_size(), 0);
+            next += range_count;
+
+            let range = &indices[range_start as usize..(range_start + range_count) as usize];
+            let min = range.iter().min().unwrap();
+            let max = range.iter().max().unwrap();
+            assert!(max - min < 65535);
+        }
+        assert_eq!(next, start + count);
+        ranges.len()
+    }
+
+    #[test]
+    fn split_ranges_cover_the_part_and_fit_short_indices() {
+        // Triangles spread over 120000 vertices
+        let indices: Vec<u32> = (0..3000)
+            .flat_map(|triangle| vec![triangle * 40, triangle * 40 + 20, triangle * 40 + 39])
+            .collect();
+        assert!(assert_split(&indices, 0, 9000, Topology::Triangles) >= 2);
+        assert_eq!(assert_split(&indices, 300, 600, Topology::Triangles), 1);
+        assert!(assert_split(&indices, 3, 8994, Topology::Lines) >= 2);
+
+        // Vertices touching both ends of the short range
+        let indices = [0, 1, 65534, 65535, 65536, 2];
+        assert_eq!(assert_split(&indices, 0, 6, Topology::Triangles), 2);
+    }
+
+    #[test]
+    fn short_indices_are_relative_to_their_part() {
+        let indices = [10, 11, 12, 100, 102, 101];
+        let mut parts = [triangle_part(0, 3), triangle_part(3, 3)];
+        let stream = index_stream(&indices, &mut parts, &[]);
+        assert_eq!(stream.format, schema::StreamFormat::Short);
+        assert_eq!(stream.data, [0, 0, 1, 0, 2, 0, 0, 0, 2, 0, 1, 0]);
+        assert_eq!((parts[0].base_vertex, parts[1].base_vertex), (10, 100));
+    }
+
+    #[test]
+    fn int_indices_are_absolute() {
+        let indices = [0, 1, 65535, 100, 102, 101];
+        let mut parts = [triangle_part(0, 3), triangle_part(3, 3)];
+        let stream = index_stream(&indices, &mut parts, &[]);
+        assert_eq!(stream.format, schema::StreamFormat::Int);
+        let expected: Vec<u8> = indices
+            .iter()
+            .flat_map(|index: &u32| index.to_le_bytes().to_vec())
+            .collect();
+        assert_eq!(stream.data, expected);
+        assert_eq!((parts[0].base_vertex, parts[1].base_vertex), (0, 0));
+    }
+}
@@ -0,0 +1,235 @@
+use crate::generated::service::mesh::schema;
+use crate::StreamData;
+use crate::Vector3;
+use serde::Deserialize;
+
+/// Vertex attribute that can be packed into an interleaved stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VertexSemantic {
+    Positions,
+    Normals,
+    Tangents,
+    Bitangents,
+    TexCoords,
+    Colors,
+}
+
+impl VertexSemantic {
+    fn stream_type(self) -> schema::StreamType {
+        match self {
+            VertexSemantic::Positions => schema::StreamType::Positions,
+            VertexSemantic::Normals => schema::StreamType::Normals,
+            VertexSemantic::Tangents => schema::StreamType::Tangents,
+            VertexSemantic::Bitangents => schema::StreamType::Bitangents,
+            VertexSemantic::TexCoords => schema::StreamType::TextureCoordinates,
+            VertexSemantic::Colors => schema::StreamType::Colors,
+        }
+    }
+}
+
+/// Attribute of an interleaved stream, written as a `schema::VertexAttribute`.
+#[derive(Debug, Clone)]
+pub struct StreamAttribute {
+    pub semantic: schema::StreamType,
+    pub format: schema::StreamFormat,
+    /// Byte offset of the attribute in every vertex.
+    pub offset: u32,
+    /// Byte size of a vertex.
+    pub stride: u32,
+    pub dequantize: Option<(Vector3, Vector3)>,
+}
+
+fn is_vertex_attribute(type_: schema::StreamType) -> bool {
+    match type_ {
+        schema::StreamType::Positions
+        | schema::StreamType::Normals
+        | schema::StreamType::Tangents
+        | schema::StreamType::Bitangents
+        | schema::StreamType::TextureCoordinates
+        | schema::StreamType::Colors => true,
+        _ => false,
+    }
+}
+
+/// Pack the vertex attribute streams selected by `semantics` (all of them
+/// when empty) into a single interleaved stream, in stream order. Attributes
+/// are aligned to 4 bytes. Other streams are kept after it.
+pub fn interleave_streams(
+    streams: Vec<StreamData>,
+    semantics: &[VertexSemantic],
+) -> Vec<StreamData> {
+    let selected = |type_: schema::StreamType| {
+        semantics.is_empty()
+            || semantics
+                .iter()
+                .any(|semantic| semantic.stream_type() == type_)
+    };
+    let (interleaved, separate): (Vec<_>, Vec<_>) = streams
+        .into_iter()
+        .partition(|stream| is_vertex_attribute(stream.type_) && selected(stream.type_));
+    let elements = match interleaved.first() {
+        Some(stream) => stream.elements,
+        None => return separate,
+    };
+
+    let sizes: Vec<usize> = interleaved
+        .iter()
+        .map(|stream| {
+            if stream.elements > 0 {
+                stream.data.len() / stream.elements as usize
+            } else {
+                0
+            }
+        })
+        .collect();
+    let mut attributes = Vec::with_capacity(interleaved.len());
+    let mut stride = 0;
+    for (stream, size) in interleaved.iter().zip(&sizes) {
+        attributes.push(StreamAttribute {
+            semantic: stream.type_,
+            format: stream.format,
+            offset: stride as u32,
+            stride: 0,
+            dequantize: stream.dequantize,
+        });
+        stride += (size + 3) & !3;
+    }
+    for attribute in &mut attributes {
+        attribute.stride = stride as u32;
+    }
+
+    let mut data = Vec::with_capacity(stride * elements as usize);
+    for vertex in 0..elements as usize {
+        for (stream, &size) in interleaved.iter().zip(&sizes) {
+            data.extend_from_slice(&stream.data[vertex * size..(vertex + 1) * size]);
+            data.resize((data.len() + 3) & !3, 0);
+        }
+    }
+    log::debug!(
+        "Interleaved {} attributes, {} bytes per vertex",
+        attributes.len(),
+        stride
+    );
+
+    let mut streams = Vec::with_capacity(separate.len() + 1);
+    streams.push(StreamData {
+        type_: schema::StreamType::Interleaved,
+        format: schema::StreamFormat::Interleaved,
+        elements,
+        decoded_size: data.len() as u64,
+        data,
+        dequantize: None,
+        attributes,
+        compression: schema::StreamCompression::None,
+    });
+    streams.extend(separate);
+    streams
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::service::mesh::schema::StreamFormat;
+    use crate::generated::service::mesh::schema::StreamType;
+
+    fn streams() -> Vec<StreamData> {
+        vec![
+            StreamData::new(
+                StreamType::Positions,
+                StreamFormat::Vector3,
+                vec![[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            ),
+            StreamData::new(
+                StreamType::Normals,
+                StreamFormat::Octahedral8,
+                vec![[1u8, 2], [3, 4]],
+            ),
+            StreamData::new(
+                StreamType::TextureCoordinates,
+                StreamFormat::Half2,
+                vec![[5u16, 6], [7, 8]],
+            ),
+            StreamData::new(
+                StreamType::Colors,
+                StreamFormat::Unorm8x4,
+                vec![[9u8, 10, 11, 12], [13, 14, 15, 16]],
+            ),
+            StreamData::new(StreamType::Indices, StreamFormat::Short, vec![0u16, 1, 0]),
+        ]
+    }
+
+    fn layout(stream: &StreamData) -> Vec<(StreamType, StreamFormat, u32, u32)> {
+        stream
+            .attributes
+            .iter()
+            .map(|attribute| {
+                (
+                    attribute.semantic,
+                    attribute.format,
+                    attribute.offset,
+                    attribute.stride,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_attribute_is_interleaved_by_default() {
+        let streams = interleave_streams(streams(), &[]);
+        let types: Vec<_> = streams.iter().map(|stream| stream.type_).collect();
+        assert_eq!(types, [StreamType::Interleaved, StreamType::Indices]);
+
+        let interleaved = &streams[0];
+        assert_eq!(
+            layout(interleaved),
+            [
+                (StreamType::Positions, StreamFormat::Vector3, 0, 24),
+                (StreamType::Normals, StreamFormat::Octahedral8, 12, 24),
+                (StreamType::TextureCoordinates, StreamFormat::Half2, 16, 24),
+                (StreamType::Colors, StreamFormat::Unorm8x4, 20, 24),
+            ]
+        );
+        assert_eq!(interleaved.elements, 2);
+        assert_eq!(interleaved.data.len(), 48);
+        // Second vertex, the normal is padded to 4 bytes
+        assert_eq!(
+            interleaved.data[36..48],
+            [3, 4, 0, 0, 7, 0, 8, 0, 13, 14, 15, 16]
+        );
+    }
+
+    #[test]
+    fn only_selected_attributes_are_interleaved() {
+        let semantics = [VertexSemantic::TexCoords, VertexSemantic::Positions];
+        let streams = interleave_streams(streams(), &semantics);
+        let types: Vec<_> = streams.iter().map(|stream| stream.type_).collect();
+        assert_eq!(
+            types,
+            [
+                StreamType::Interleaved,
+                StreamType::Normals,
+                StreamType::Colors,
+                StreamType::Indices
+            ]
+        );
+
+        // Attributes keep the stream order
+        let interleaved = &streams[0];
+        assert_eq!(
+            layout(interleaved),
+            [
+                (StreamType::Positions, StreamFormat::Vector3, 0, 16),
+                (StreamType::TextureCoordinates, StreamFormat::Half2, 12, 16),
+            ]
+        );
+        assert_eq!(interleaved.data.len(), 32);
+    }
+
+    #[test]
+    fn missing_attributes_leave_streams_separate() {
+        let streams = interleave_streams(streams(), &[VertexSemantic::Bitangents]);
+        assert_eq!(streams.len(), 5);
+        assert!(streams.iter().all(|stream| stream.attributes.is_empty()));
+    }
+}
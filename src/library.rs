@@ -0,0 +1,153 @@
+use crate::Error;
+use crate::ErrorKind;
+use crate::GltfOptionOverrides;
+use crate::GltfOptions;
+use crate::Result;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
+
+/// A mesh library, as described by a `Meshes.toml` file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MeshLibrary {
+    /// Options shared by every entry, from the `[defaults]` table.
+    #[serde(default)]
+    pub defaults: GltfOptionOverrides,
+    #[serde(default)]
+    pub entries: Vec<MeshEntry>,
+}
+
+/// A single `[[entries]]` item of a mesh library.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MeshEntry {
+    /// Name of the processed mesh in the output manifest.
+    pub name: String,
+    /// Source asset, relative to the library file.
+    pub file: String,
+    /// Options for this entry, overriding the library defaults.
+    #[serde(flatten)]
+    pub options: GltfOptionOverrides,
+    /// Keys left over by `options`, since `deny_unknown_fields` has no effect
+    /// on flattened fields. Rejected when the library is parsed.
+    #[serde(flatten)]
+    unknown: BTreeMap<String, toml::Value>,
+}
+
+impl MeshLibrary {
+    /// Effective options of `entry`: the built-in defaults, overridden by the
+    /// library `[defaults]` table and then by the entry itself.
+    pub fn entry_options(&self, entry: &MeshEntry) -> GltfOptions {
+        GltfOptions::default()
+            .with_overrides(&self.defaults)
+            .with_overrides(&entry.options)
+    }
+
+    pub fn from_path(path: &Path) -> Result<MeshLibrary> {
+        let text = std::fs::read_to_string(path)?;
+        text.parse()
+    }
+}
+
+impl FromStr for MeshLibrary {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<MeshLibrary> {
+        let library: MeshLibrary = toml::from_str(text)?;
+        for entry in &library.entries {
+            if let Some(key) = entry.unknown.keys().next() {
+                return Err(Error::config(format!(
+                    "unknown option '{}' in entry '{}'",
+                    key, entry.name
+                )));
+            }
+        }
+        Ok(library)
+    }
+}
+
+/// Resolve a relative asset path against `root`, rejecting absolute paths and
+/// anything that would escape it.
+pub fn resolve_relative(root: &Path, path: &str) -> Result<PathBuf> {
+    let relative = Path::new(path);
+    let is_contained = !path.is_empty()
+        && relative.components().all(|component| match component {
+            Component::Normal(_) | Component::CurDir => true,
+            _ => false,
+        });
+    if is_contained {
+        Ok(root.join(relative))
+    } else {
+        Err(Error::from(ErrorKind::path(relative)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StreamEncoding;
+    use crate::UpAxis;
+
+    #[test]
+    fn entry_overrides_defaults() {
+        let library: MeshLibrary = r#"
+            [defaults]
+            optimize = false
+            flip_v_coord = true
+
+            [[entries]]
+            name = "book"
+            file = "Book_03.glb"
+            optimize = true
+            lod_ratios = [0.5, 0.25]
+            up_axis = "z"
+
+            [entries.quantization]
+            normals = "octahedral16"
+        "#
+        .parse()
+        .unwrap();
+
+        assert_eq!(library.entries.len(), 1);
+        let entry = &library.entries[0];
+        assert_eq!(entry.name, "book");
+        assert_eq!(entry.file, "Book_03.glb");
+
+        let options = library.entry_options(entry);
+        assert!(options.optimize);
+        assert!(options.flip_v_coord);
+        assert_eq!(options.lod_ratios, vec![0.5, 0.25]);
+        assert_eq!(options.up_axis, UpAxis::Z);
+        assert_eq!(
+            options.quantization.normals,
+            Some(StreamEncoding::Octahedral16)
+        );
+        assert_eq!(options.quantization.positions, None);
+    }
+
+    #[test]
+    fn unknown_options_are_rejected() {
+        let entry = r#"
+            [[entries]]
+            name = "book"
+            file = "Book_03.glb"
+            optimise = false
+        "#;
+        assert!(entry.parse::<MeshLibrary>().is_err());
+
+        let defaults = r#"
+            [defaults]
+            optimise = false
+        "#;
+        assert!(defaults.parse::<MeshLibrary>().is_err());
+
+        let quantization = r#"
+            [[entries]]
+            name = "book"
+            file = "Book_03.glb"
+            quantization = { normal = "octahedral16" }
+        "#;
+        assert!(quantization.parse::<MeshLibrary>().is_err());
+    }
+}
@@ -0,0 +1,377 @@
+use crate::GltfOptions;
+use crate::GltfVertex;
+use crate::MeshAssetPart;
+use crate::Topology;
+use crate::Vector3;
+use std::collections::{HashMap, HashSet};
+
+/// Target error used for LOD levels without an explicit `lod_errors` entry.
+pub const DEFAULT_LOD_ERROR: f32 = 0.01;
+
+/// Simplified index range of a single part.
+#[derive(Debug, Clone, Copy)]
+pub struct LodPart {
+    pub index_start: u32,
+    pub index_count: u32,
+    /// Largest distance between the source vertices and the simplified surface.
+    pub error: f32,
+}
+
+/// One level of detail, with a simplified index range per mesh part.
+#[derive(Debug, Clone)]
+pub struct LodLevel {
+    pub ratio: f32,
+    /// Target error relative to the extents of each part.
+    pub max_error: f32,
+    /// Largest error of all parts, in the units of the vertex positions.
+    pub error: f32,
+    pub parts: Vec<LodPart>,
+}
+
+/// Simplify every part once per `lod_ratios` entry, appending the simplified
+/// triangles to `indices`. LOD levels reference the same vertices as the
+/// source parts. Parts sharing an index range share their simplified range.
+pub fn generate_lods(
+    vertices: &[GltfVertex],
+    indices: &mut Vec<u32>,
+    parts: &[MeshAssetPart],
+    options: &GltfOptions,
+) -> Vec<LodLevel> {
+    let mut levels = Vec::with_capacity(options.lod_ratios.len());
+    for (level, &ratio) in options.lod_ratios.iter().enumerate() {
+        let max_error = options
+            .lod_errors
+            .get(level)
+            .or_else(|| options.lod_errors.last())
+            .cloned()
+            .unwrap_or(DEFAULT_LOD_ERROR);
+
+        let mut lod_parts: Vec<LodPart> = Vec::with_capacity(parts.len());
+        // Simplified part of each source index range
+        let mut ranges: HashMap<(u32, u32), LodPart> = HashMap::new();
+        for part in parts {
+            let range = (part.index_start, part.index_count);
+            if let Some(&shared) = ranges.get(&range) {
+                lod_parts.push(shared);
+                continue;
+            }
+            // Lines and points are kept as they are
+            if part.topology != Topology::Triangles {
+                lod_parts.push(LodPart {
+                    index_start: part.index_start,
+                    index_count: part.index_count,
+                    error: 0.0,
+                });
+                continue;
+            }
+
+            let start = part.index_start as usize;
+            let source = &indices[start..start + part.index_count as usize];
+            let simplified = simplify_range(vertices, source, ratio, max_error, options.optimize);
+            let error = simplification_error(vertices, source, &simplified);
+
+            let lod_part = LodPart {
+                index_start: indices.len() as u32,
+                index_count: simplified.len() as u32,
+                error,
+            };
+            ranges.insert(range, lod_part);
+            lod_parts.push(lod_part);
+            indices.extend(simplified);
+        }
+
+        let error = lod_parts
+            .iter()
+            .fold(0.0, |error, part| part.error.max(error));
+        log::debug!(
+            "LOD {}: ratio {}, max error {}, error {}, {} indices",
+            level + 1,
+            ratio,
+            max_error,
+            error,
+            lod_parts.iter().map(|part| part.index_count).sum::<u32>()
+        );
+        levels.push(LodLevel {
+            ratio,
+            max_error,
+            error,
+            parts: lod_parts,
+        });
+    }
+    levels
+}
+
+/// Simplify a range of triangles to about `ratio` of its indices.
+fn simplify_range(
+    vertices: &[GltfVertex],
+    source: &[u32],
+    ratio: f32,
+    max_error: f32,
+    optimize: bool,
+) -> Vec<u32> {
+    let base = match source.iter().min() {
+        Some(&base) => base,
+        None => return Vec::new(),
+    };
+    let end = source.iter().max().map_or(base, |&max| max + 1);
+    let range_vertices = &vertices[base as usize..end as usize];
+    let local: Vec<u32> = source.iter().map(|index| index - base).collect();
+
+    let target_count = ((local.len() as f32 * ratio.max(0.0).min(1.0)) as usize) / 3 * 3;
+    let mut simplified = meshopt::simplify(&local, range_vertices, target_count, max_error);
+    if optimize {
+        simplified = meshopt::optimize_vertex_cache(&simplified, range_vertices.len());
+    }
+    simplified.iter().map(|index| index + base).collect()
+}
+
+/// Largest distance between a vertex of the `source` triangles and the
+/// closest `simplified` triangle.
+fn simplification_error(vertices: &[GltfVertex], source: &[u32], simplified: &[u32]) -> f32 {
+    let position = |index: u32| Vector3::from(vertices[index as usize].position);
+    let triangles: Vec<[Vector3; 3]> = simplified
+        .chunks(3)
+        .filter(|triangle| triangle.len() == 3)
+        .map(|triangle| {
+            [
+                position(triangle[0]),
+                position(triangle[1]),
+                position(triangle[2]),
+            ]
+        })
+        .collect();
+
+    let mut min = Vector3::repeat(std::f32::MAX);
+    let mut max = Vector3::repeat(std::f32::MIN);
+    for &index in source {
+        let point = position(index);
+        min = min.inf(&point);
+        max = max.sup(&point);
+    }
+    if source.is_empty() {
+        return 0.0;
+    }
+    if triangles.is_empty() {
+        // Everything collapsed, the error is the size of the part
+        return (max - min).norm();
+    }
+
+    let grid = TriangleGrid::new(&triangles, min, max);
+    let mut visited = HashSet::with_capacity(source.len());
+    let mut error: f32 = 0.0;
+    for &index in source {
+        if !visited.insert(index) {
+            continue;
+        }
+        error = error.max(grid.distance(&triangles, &position(index)));
+    }
+    error
+}
+
+/// Uniform grid of triangle indices, used to find the closest triangle.
+struct TriangleGrid {
+    min: Vector3,
+    cell_size: Vector3,
+    resolution: usize,
+    cells: Vec<Vec<u32>>,
+}
+
+impl TriangleGrid {
+    fn new(triangles: &[[Vector3; 3]], min: Vector3, max: Vector3) -> TriangleGrid {
+        let resolution = ((triangles.len() as f32 / 2.0).cbrt().ceil() as usize)
+            .max(1)
+            .min(64);
+        let cell_size = (max - min).map(|size| size.max(1e-6) / resolution as f32);
+        let mut grid = TriangleGrid {
+            min,
+            cell_size,
+            resolution,
+            cells: vec![Vec::new(); resolution * resolution * resolution],
+        };
+
+        for (triangle_index, triangle) in triangles.iter().enumerate() {
+            let low = grid.cell(&triangle[0].inf(&triangle[1]).inf(&triangle[2]));
+            let high = grid.cell(&triangle[0].sup(&triangle[1]).sup(&triangle[2]));
+            for z in low[2]..=high[2] {
+                for y in low[1]..=high[1] {
+                    for x in low[0]..=high[0] {
+                        let cell = grid.cell_index(x, y, z);
+                        grid.cells[cell].push(triangle_index as u32);
+                    }
+                }
+            }
+        }
+        grid
+    }
+
+    fn cell(&self, point: &Vector3) -> [usize; 3] {
+        let offset = (point - self.min).component_div(&self.cell_size);
+        let clamp = |offset: f32| (offset.floor().max(0.0) as usize).min(self.resolution - 1);
+        [clamp(offset.x), clamp(offset.y), clamp(offset.z)]
+    }
+
+    fn cell_index(&self, x: usize, y: usize, z: usize) -> usize {
+        (z * self.resolution + y) * self.resolution + x
+    }
+
+    /// Distance from `point` to the closest triangle, searching rings of cells
+    /// around the cell of the point until no closer triangle can exist.
+    fn distance(&self, triangles: &[[Vector3; 3]], point: &Vector3) -> f32 {
+        let center = self.cell(point);
+        let ring_size = self.cell_size.x.min(self.cell_size.y).min(self.cell_size.z);
+        let mut best = std::f32::MAX;
+        for ring in 0..self.resolution {
+            let low: Vec<usize> = center.iter().map(|&c| c.saturating_sub(ring)).collect();
+            let high: Vec<usize> = center
+                .iter()
+                .map(|&c| (c + ring).min(self.resolution - 1))
+                .collect();
+            for z in low[2]..=high[2] {
+                for y in low[1]..=high[1] {
+                    for x in low[0]..=high[0] {
+                        let on_ring = [x, y, z].iter().zip(&center).any(|(&c, &origin)| {
+                            (c as isize - origin as isize).abs() as usize == ring
+                        });
+                        if !on_ring {
+                            continue;
+                        }
+                        for &triangle in &self.cells[self.cell_index(x, y, z)] {
+                            let triangle = &triangles[triangle as usize];
+                            let closest = closest_point_on_triangle(point, triangle);
+                            best = best.min((closest - point).norm());
+                        }
+                    }
+                }
+            }
+            if best <= ring as f32 * ring_size {
+                break;
+            }
+        }
+        best
+    }
+}
+
+/// Closest point to `p` on triangle `abc`, from Real-Time Collision Detection.
+fn closest_point_on_triangle(p: &Vector3, [a, b, c]: &[Vector3; 3]) -> Vector3 {
+    let ab = b - a;
+    let ac = c - a;
+    let ap = p - a;
+    let d1 = ab.dot(&ap);
+    let d2 = ac.dot(&ap);
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return *a;
+    }
+
+    let bp = p - b;
+    let d3 = ab.dot(&bp);
+    let d4 = ac.dot(&bp);
+    if d3 >= 0.0 && d4 <= d3 {
+        return *b;
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        return a + ab * (d1 / (d1 - d3));
+    }
+
+    let cp = p - c;
+    let d5 = ab.dot(&cp);
+    let d6 = ac.dot(&cp);
+    if d6 >= 0.0 && d5 <= d6 {
+        return *c;
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        return a + ac * (d2 / (d2 - d6));
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+        return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+
+    let denom = 1.0 / (va + vb + vc);
+    a + ab * (vb * denom) + ac * (vc * denom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_mesh;
+    use crate::random_sequence;
+
+    fn assert_close(actual: Vector3, expected: [f32; 3]) {
+        let expected = Vector3::from(expected);
+        assert!(
+            (actual - expected).norm() < 1e-6,
+            "{:?} is not {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn closest_point_in_every_region() {
+        let triangle = [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+        ];
+        let closest = |p: [f32; 3]| closest_point_on_triangle(&Vector3::from(p), &triangle);
+
+        // Vertex regions
+        assert_close(closest([-1.0, -1.0, 0.5]), [0.0, 0.0, 0.0]);
+        assert_close(closest([2.0, -0.5, 0.0]), [1.0, 0.0, 0.0]);
+        assert_close(closest([-0.5, 2.0, -0.5]), [0.0, 1.0, 0.0]);
+        // Edge regions
+        assert_close(closest([0.5, -1.0, 0.0]), [0.5, 0.0, 0.0]);
+        assert_close(closest([-1.0, 0.25, 1.0]), [0.0, 0.25, 0.0]);
+        assert_close(closest([1.0, 1.0, 0.0]), [0.5, 0.5, 0.0]);
+        // Face region, above and on the triangle
+        assert_close(closest([0.25, 0.25, 1.0]), [0.25, 0.25, 0.0]);
+        assert_close(closest([0.2, 0.3, 0.0]), [0.2, 0.3, 0.0]);
+    }
+
+    #[test]
+    fn grid_distance_matches_brute_force() {
+        // A bumpy surface, so triangles span several cells in every direction
+        let (vertices, indices) = grid_mesh(8);
+        let position = |index: u32| {
+            let position = vertices[index as usize].position;
+            let height = ((position[0] as u32 * 7 + position[1] as u32 * 3) % 5) as f32 * 0.4;
+            Vector3::new(position[0], position[1], height)
+        };
+        let triangles: Vec<[Vector3; 3]> = indices
+            .chunks(3)
+            .map(|triangle| {
+                [
+                    position(triangle[0]),
+                    position(triangle[1]),
+                    position(triangle[2]),
+                ]
+            })
+            .collect();
+        let min = Vector3::new(0.0, 0.0, 0.0);
+        let max = Vector3::new(8.0, 8.0, 1.6);
+        let grid = TriangleGrid::new(&triangles, min, max);
+
+        let mut next = random_sequence(1);
+        let mut random = || (next() >> 8) as f32 / (1 << 24) as f32;
+        for _ in 0..500 {
+            let point = Vector3::new(random() * 8.0, random() * 8.0, random() * 1.6);
+            let expected = triangles
+                .iter()
+                .map(|triangle| (closest_point_on_triangle(&point, triangle) - point).norm())
+                .fold(std::f32::MAX, f32::min);
+            let distance = grid.distance(&triangles, &point);
+            assert!(
+                (distance - expected).abs() < 1e-5,
+                "distance from {:?} is {} instead of {}",
+                point,
+                distance,
+                expected
+            );
+        }
+    }
+}
@@ -1,565 +1,398 @@
 //use gltf::{buffer::Source as BufferSource, image::Source as ImageSource, Gltf};
-use std::collections::HashMap;
-use std::path::Path;
+use elapsed::measure_time;
+use serde::Deserialize;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
 //use std::rc::Rc;
 
 mod animation;
+mod bounds;
+mod cache;
+mod compression;
+mod convert;
 mod data;
 mod error;
+mod flatten;
 mod format;
 mod generated;
+mod identity;
+mod indices;
+mod layout;
+mod library;
+mod lod;
+mod manifest;
 mod material;
 mod math;
 mod mesh;
+mod meshlet;
 mod model;
 mod node;
+mod optimize;
 mod primitive;
+mod process;
+mod proto;
+mod quantize;
 mod scene;
+mod service;
 mod tangents;
+#[cfg(test)]
+mod test_assets;
 mod texture;
 
 use animation::*;
+use bounds::*;
+use cache::*;
+use compression::*;
+use convert::*;
 use data::*;
 use error::*;
+use flatten::*;
 use format::*;
-use generated::service::mesh::schema;
+use identity::*;
+use indices::*;
+use layout::*;
+use library::*;
+use lod::*;
+use manifest::*;
 use material::*;
 use math::*;
 use mesh::*;
+use meshlet::*;
 use model::*;
 use node::*;
+use optimize::*;
 use primitive::*;
+use process::*;
+use quantize::*;
 use scene::*;
+use service::*;
 use tangents::*;
+#[cfg(test)]
+use test_assets::*;
 use texture::*;
 
-use math::Vector4;
-
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct GltfOptions {
     pub scene_index: Option<usize>,
     pub load_animations: bool,
     pub regenerate_tangents: bool,
     pub generate_tex_coords: (f32, f32),
     pub flip_v_coord: bool,
+    pub split_scenes: bool,
+    /// Tolerance used to weld nearly identical vertices, 0 welds exact duplicates only.
+    pub weld_epsilon: f32,
+    /// Optimize the vertex and index order for the GPU vertex cache and fetch.
+    pub optimize: bool,
+    /// Largest tolerated vertex cache degradation when optimizing for
+    /// overdraw, 0 disables overdraw optimization.
+    pub overdraw_threshold: f32,
+    /// Fraction of the source indices targeted by each generated LOD level.
+    pub lod_ratios: Vec<f32>,
+    /// Largest simplification error of each LOD level, relative to the part
+    /// extents. Levels without an entry use the last one.
+    pub lod_errors: Vec<f32>,
+    /// Split every part into meshlets, exported as meshlet streams.
+    pub build_meshlets: bool,
+    pub meshlet_max_vertices: u32,
+    pub meshlet_max_triangles: u32,
+    /// Encoding of each vertex attribute stream.
+    pub quantization: QuantizationPolicy,
+    /// Split parts spanning more than 65536 vertices so every part can use
+    /// 16-bit indices.
+    pub split_large_parts: bool,
+    /// Pack vertex attributes into a single interleaved stream.
+    pub interleave: bool,
+    /// Attributes packed when interleaving, every attribute when empty.
+    pub interleaved_attributes: Vec<VertexSemantic>,
+    /// Compress stream data with the meshopt codecs or zlib.
+    pub compress_streams: bool,
+    /// Emit meshes shared by several nodes once with a list of instance
+    /// transforms, instead of baking a copy per node.
+    pub instancing: bool,
+    /// Uniform scale applied on top of the unit conversion.
+    pub global_scale: f32,
+    pub units: LengthUnit,
+    pub up_axis: UpAxis,
+    pub handedness: Handedness,
 }
 
-#[inline(always)]
-pub unsafe fn any_as_u8_slice<T: Sized>(p: &T) -> &[u8] {
-    ::std::slice::from_raw_parts((p as *const T) as *const u8, ::std::mem::size_of::<T>())
+impl Default for GltfOptions {
+    fn default() -> Self {
+        GltfOptions {
+            scene_index: None,
+            load_animations: true,
+            regenerate_tangents: true,
+            generate_tex_coords: (0.0, 0.0),
+            flip_v_coord: false,
+            split_scenes: false,
+            weld_epsilon: 0.0,
+            optimize: true,
+            overdraw_threshold: 1.05,
+            lod_ratios: Vec::new(),
+            lod_errors: Vec::new(),
+            build_meshlets: false,
+            meshlet_max_vertices: 64,
+            meshlet_max_triangles: 124,
+            quantization: QuantizationPolicy::default(),
+            split_large_parts: false,
+            interleave: false,
+            interleaved_attributes: Vec::new(),
+            compress_streams: false,
+            instancing: false,
+            global_scale: 1.0,
+            units: LengthUnit::Meters,
+            up_axis: UpAxis::Y,
+            handedness: Handedness::Right,
+        }
+    }
 }
 
-fn load_model<'a>(
-    mut builder: &mut flatbuffers::FlatBufferBuilder<'a>,
-    model_path: &Path,
-) -> Result<flatbuffers::WIPOffset<schema::Mesh<'a>>> {
-    let _base_path = model_path.parent().unwrap_or(Path::new("./"));
-
-    let (document, buffers, images) = gltf::import(model_path)?;
-
-    let options = GltfOptions {
-        regenerate_tangents: true,
-        ..Default::default()
-    };
-
-    let data = GltfData {
-        options,
-        document,
-        buffers,
-        images,
-    };
-
-    let mut model = GltfModel::from_gltf(&data, model_path)?;
+/// Optional overrides of `GltfOptions`, as read from a mesh library or a request.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GltfOptionOverrides {
+    pub scene_index: Option<usize>,
+    pub load_animations: Option<bool>,
+    pub regenerate_tangents: Option<bool>,
+    pub generate_tex_coords: Option<(f32, f32)>,
+    pub flip_v_coord: Option<bool>,
+    pub split_scenes: Option<bool>,
+    pub weld_epsilon: Option<f32>,
+    pub optimize: Option<bool>,
+    pub overdraw_threshold: Option<f32>,
+    pub lod_ratios: Option<Vec<f32>>,
+    pub lod_errors: Option<Vec<f32>>,
+    pub build_meshlets: Option<bool>,
+    pub meshlet_max_vertices: Option<u32>,
+    pub meshlet_max_triangles: Option<u32>,
+    #[serde(default)]
+    pub quantization: QuantizationPolicy,
+    pub split_large_parts: Option<bool>,
+    pub interleave: Option<bool>,
+    pub interleaved_attributes: Option<Vec<VertexSemantic>>,
+    pub compress_streams: Option<bool>,
+    pub instancing: Option<bool>,
+    pub global_scale: Option<f32>,
+    pub units: Option<LengthUnit>,
+    pub up_axis: Option<UpAxis>,
+    pub handedness: Option<Handedness>,
+}
 
-    let scene_count = data.document.scenes().len();
-    let scene_index = 0;
-    if scene_index >= scene_count {
-        //error!("Scene index too high - file has only {} scene(s)", scene_count);
-        //process::exit(3)
-        panic!("scene index is too high");
+impl GltfOptions {
+    /// Return a copy of these options with every set override applied.
+    pub fn with_overrides(&self, overrides: &GltfOptionOverrides) -> GltfOptions {
+        GltfOptions {
+            scene_index: overrides.scene_index.or(self.scene_index),
+            load_animations: overrides.load_animations.unwrap_or(self.load_animations),
+            regenerate_tangents: overrides
+                .regenerate_tangents
+                .unwrap_or(self.regenerate_tangents),
+            generate_tex_coords: overrides
+                .generate_tex_coords
+                .unwrap_or(self.generate_tex_coords),
+            flip_v_coord: overrides.flip_v_coord.unwrap_or(self.flip_v_coord),
+            split_scenes: overrides.split_scenes.unwrap_or(self.split_scenes),
+            weld_epsilon: overrides.weld_epsilon.unwrap_or(self.weld_epsilon),
+            optimize: overrides.optimize.unwrap_or(self.optimize),
+            overdraw_threshold: overrides
+                .overdraw_threshold
+                .unwrap_or(self.overdraw_threshold),
+            lod_ratios: overrides
+                .lod_ratios
+                .clone()
+                .unwrap_or_else(|| self.lod_ratios.clone()),
+            lod_errors: overrides
+                .lod_errors
+                .clone()
+                .unwrap_or_else(|| self.lod_errors.clone()),
+            build_meshlets: overrides.build_meshlets.unwrap_or(self.build_meshlets),
+            meshlet_max_vertices: overrides
+                .meshlet_max_vertices
+                .unwrap_or(self.meshlet_max_vertices),
+            meshlet_max_triangles: overrides
+                .meshlet_max_triangles
+                .unwrap_or(self.meshlet_max_triangles),
+            quantization: self.quantization.with_overrides(&overrides.quantization),
+            split_large_parts: overrides
+                .split_large_parts
+                .unwrap_or(self.split_large_parts),
+            interleave: overrides.interleave.unwrap_or(self.interleave),
+            interleaved_attributes: overrides
+                .interleaved_attributes
+                .clone()
+                .unwrap_or_else(|| self.interleaved_attributes.clone()),
+            compress_streams: overrides.compress_streams.unwrap_or(self.compress_streams),
+            instancing: overrides.instancing.unwrap_or(self.instancing),
+            global_scale: overrides.global_scale.unwrap_or(self.global_scale),
+            units: overrides.units.unwrap_or(self.units),
+            up_axis: overrides.up_axis.unwrap_or(self.up_axis),
+            handedness: overrides.handedness.unwrap_or(self.handedness),
+        }
     }
-    //println!("Scene count: {}", scene_count);
-
-    let gltf_scene = data.document.scenes().nth(scene_index).unwrap();
-    let scene = GltfScene::from_gltf(&gltf_scene, &mut model)?;
-    println!("Scene Dimensions: {:?}", scene.dimensions);
-
-    let has_animations = model.animations.len() > 0;
-    let flatten_transforms = !has_animations;
-
-    // Protect against shared triangle vertices being transformed multiple times
-    let mut transformed: Vec<bool> = if flatten_transforms {
-        vec![false; model.vertex_buffer.len()]
-    } else {
-        Vec::new()
-    };
-
-    // Map from name of node to indices of all parts owned by that node in the global parts array
-    let mut part_map: HashMap<String, Vec<u32>> = HashMap::new();
-
-    let global_scale = 1.0;
-    let scale = Matrix4::identity();
-    scale.prepend_nonuniform_scaling(&Vector3::new(global_scale, global_scale, global_scale));
-
-    let mut parts: Vec<MeshAssetPart> = Vec::with_capacity(model.linear_nodes.len());
-    for linear_node in &model.linear_nodes {
-        let linear_node = linear_node.borrow();
-        if let Some(ref mesh) = linear_node.mesh {
-            let matrix = scale * linear_node.get_matrix();
-
-            let mut part = MeshAssetPart {
-                index_start: 0,
-                index_count: 0,
-                material_index: None,
-                node_index: None,
-                base_transform: Matrix4::identity(),
-                animation_type: AnimationType::None,
-                name: linear_node.name.clone(),
-            };
-
-            let mut part_indices: Vec<u32> = Vec::new();
-            for primitive in &mesh.primitives {
-                part.index_start = primitive.index_start;
-                part.index_count = primitive.index_count;
-                part.material_index = if let Some(index) = primitive.material_index {
-                    Some(index as u32)
-                } else {
-                    None
-                };
-                part.animation_type = if has_animations {
-                    if linear_node.skin_index.is_some() {
-                        AnimationType::Skinned
-                    } else {
-                        AnimationType::Rigid
-                    }
-                } else {
-                    AnimationType::None
-                };
-
-                if flatten_transforms {
-                    for index in
-                        (part.index_start..(part.index_start + part.index_count)).step_by(3)
-                    {
-                        let i0 = model.index_buffer[index as usize + 0] as usize;
-                        let i1 = model.index_buffer[index as usize + 1] as usize;
-                        let i2 = model.index_buffer[index as usize + 2] as usize;
-
-                        if !transformed[i0] {
-                            let pos = Vector4::new(
-                                model.vertex_buffer[i0].position[0],
-                                model.vertex_buffer[i0].position[1],
-                                model.vertex_buffer[i0].position[2],
-                                1.0,
-                            );
-                            let pos = matrix * pos;
-                            model.vertex_buffer[i0].position = [pos.x, pos.y, pos.z];
-
-                            let norm = Vector4::new(
-                                model.vertex_buffer[i0].normal[0],
-                                model.vertex_buffer[i0].normal[1],
-                                model.vertex_buffer[i0].normal[2],
-                                1.0,
-                            );
-                            let norm = matrix * norm;
-                            let norm = norm.normalize();
-                            model.vertex_buffer[i0].normal = [norm.x, norm.y, norm.z];
-
-                            transformed[i0] = true;
-                        }
-
-                        if !transformed[i1] {
-                            let pos = Vector4::new(
-                                model.vertex_buffer[i1].position[0],
-                                model.vertex_buffer[i1].position[1],
-                                model.vertex_buffer[i1].position[2],
-                                1.0,
-                            );
-                            let pos = matrix * pos;
-                            model.vertex_buffer[i1].position = [pos.x, pos.y, pos.z];
-
-                            let norm = Vector4::new(
-                                model.vertex_buffer[i1].normal[0],
-                                model.vertex_buffer[i1].normal[1],
-                                model.vertex_buffer[i1].normal[2],
-                                1.0,
-                            );
-                            let norm = matrix * norm;
-                            let norm = norm.normalize();
-                            model.vertex_buffer[i1].normal = [norm.x, norm.y, norm.z];
-
-                            transformed[i1] = true;
-                        }
-
-                        if !transformed[i2] {
-                            let pos = Vector4::new(
-                                model.vertex_buffer[i2].position[0],
-                                model.vertex_buffer[i2].position[1],
-                                model.vertex_buffer[i2].position[2],
-                                1.0,
-                            );
-                            let pos = matrix * pos;
-                            model.vertex_buffer[i2].position = [pos.x, pos.y, pos.z];
-
-                            let norm = Vector4::new(
-                                model.vertex_buffer[i2].normal[0],
-                                model.vertex_buffer[i2].normal[1],
-                                model.vertex_buffer[i2].normal[2],
-                                1.0,
-                            );
-                            let norm = matrix * norm;
-                            let norm = norm.normalize();
-                            model.vertex_buffer[i2].normal = [norm.x, norm.y, norm.z];
+}
 
-                            transformed[i2] = true;
-                        }
-                    }
-                }
+#[inline(always)]
+pub unsafe fn any_as_u8_slice<T: Sized>(p: &T) -> &[u8] {
+    ::std::slice::from_raw_parts((p as *const T) as *const u8, ::std::mem::size_of::<T>())
+}
 
-                parts.push(part.clone());
-                part_indices.push((parts.len() - 1) as u32);
-            }
+const DEFAULT_ADDRESS: &str = "0.0.0.0:50051";
+const DEFAULT_ASSET_ROOT: &str = "data";
 
-            assert!(linear_node.name.is_some());
-            let name = if let Some(ref name_ref) = linear_node.name {
-                name_ref.clone()
-            } else {
-                "UNNAMED".to_string()
-            };
+/// Writes log records of the service to stderr.
+struct Logger;
 
-            part_map.insert(name, part_indices);
-        }
+impl log::Log for Logger {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        metadata.level() <= log::max_level()
     }
 
-    let mut mesh_data = MeshData::default();
-
-    mesh_data.indices = model.index_buffer.clone();
-
-    mesh_data.positions.reserve(model.vertex_buffer.len());
-    mesh_data.tex_coords.reserve(model.vertex_buffer.len());
-    mesh_data.normals.reserve(model.vertex_buffer.len());
-    mesh_data.tangents.reserve(model.vertex_buffer.len());
-    mesh_data.bitangents.reserve(model.vertex_buffer.len());
-    mesh_data.colors.reserve(model.vertex_buffer.len());
-    mesh_data.indices.reserve(model.index_buffer.len());
-    if has_animations {
-        mesh_data.skinning_data.reserve(model.vertex_buffer.len());
+    fn log(&self, record: &log::Record<'_>) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{}] {}", record.level(), record.args());
+        }
     }
 
-    for i in 0..model.vertex_buffer.len() {
-        let vertex = &model.vertex_buffer[i];
-        mesh_data.positions.push(vertex.position);
-        mesh_data.tex_coords.push(vertex.uv0);
-        mesh_data.normals.push(vertex.normal);
-        mesh_data.tangents.push(vertex.tangent);
-        mesh_data.bitangents.push(vertex.bitangent);
-        mesh_data.colors.push(vertex.color0);
-
-        if has_animations {
-            // TODO
-            let mut skinning_data = SkinningData {
-                bone_count: vertex.influence_count,
-                weights: [0.0; MAX_BONE_INFLUENCES],
-                bone_ids: [0; MAX_BONE_INFLUENCES],
-            };
-
-            let mut sum = 0.0;
-
-            // Largest index with a non zero weight
-            let mut max_non_zero = std::usize::MAX;
-
-            for bone in 0..skinning_data.bone_count as usize {
-                if bone < 4 {
-                    skinning_data.weights[bone] = vertex.weight0[bone];
-                    skinning_data.bone_ids[bone] = vertex.joint0[bone] as u32;
-                } else if bone < 8 {
-                    skinning_data.weights[bone] = vertex.weight1[bone - 4];
-                    skinning_data.bone_ids[bone] = vertex.joint1[bone - 4] as u32;
-                } else if bone < 12 {
-                    skinning_data.weights[bone] = vertex.weight2[bone - 8];
-                    skinning_data.bone_ids[bone] = vertex.joint2[bone - 8] as u32;
-                } else {
-                    skinning_data.weights[bone] = vertex.weight3[bone - 12];
-                    skinning_data.bone_ids[bone] =
-                        vertex.joint3[bone - 12] as u32;
-                }
-
-                if skinning_data.weights[bone] > 0.0 {
-                    max_non_zero = bone;
-                }
-
-                sum += skinning_data.weights[bone];
-            }
+    fn flush(&self) {}
+}
 
-            skinning_data.bone_count = (max_non_zero + 1) as u32;
+static LOGGER: Logger = Logger;
 
-            // Re-balance the weights
-            for bone in 0..skinning_data.bone_count as usize {
-                skinning_data.weights[bone] /= sum;
-            }
+fn usage() -> ! {
+    eprintln!("usage: svc-mesh [--address <ip:port>] [--root <asset directory>] [cache options]");
+    eprintln!("       svc-mesh batch <Meshes.toml> <output manifest> [cache options]");
+    eprintln!("cache options: [--cache <directory>] [--cache-size <MiB>]");
+    std::process::exit(2)
+}
 
-            mesh_data.skinning_data.push(skinning_data);
+/// Open the mesh cache selected on the command line, if any.
+fn open_cache(directory: Option<PathBuf>, max_size: u64) -> Option<MeshCache> {
+    let directory = directory?;
+    match MeshCache::open(&directory, max_size) {
+        Ok(cache) => {
+            let stats = cache.stats();
+            println!(
+                "Using mesh cache '{}' - {} meshes, {} of {} bytes",
+                cache.directory().to_string_lossy(),
+                stats.entries,
+                stats.size,
+                max_size
+            );
+            Some(cache)
+        }
+        Err(err) => {
+            eprintln!(
+                "Failed to open mesh cache '{}': {}",
+                directory.to_string_lossy(),
+                err
+            );
+            std::process::exit(1)
         }
     }
+}
 
-    if has_animations {
-        // export the animations
-        // TODO
-    }
-
-    if has_animations {
-        // export bone offsets and per vertex skinning data
-        // TODO
-    }
-
-    if mesh_data.positions.is_empty() || mesh_data.indices.is_empty() {
-        dbg!(mesh_data.indices);
-        return Err(Error::memory("no vertices found"));
+/// Process every entry of a mesh library and write the resulting manifest.
+fn batch(library_path: &Path, output_path: &Path, cache: Option<&MeshCache>) -> Result<usize> {
+    let library = MeshLibrary::from_path(library_path)?;
+    let root = library_path.parent().unwrap_or(Path::new("./"));
+
+    let (elapsed, report) =
+        measure_time(|| process_library(&library, root, &GltfOptionOverrides::default(), cache));
+    for entry in &report.entries {
+        match entry.error {
+            Some(ref err) => println!("  {} - failed: {}", entry.name, err),
+            None => println!("  {} - ok", entry.name),
+        }
     }
 
-    // Setup streams
-    let mut streams: Vec<_> = Vec::new();
-
-    // Positions
-    let position_data = mesh_data.positions.as_ptr() as *const u8;
-    let position_data = unsafe {
-        std::slice::from_raw_parts(
-            position_data,
-            mesh_data.positions.len() * std::mem::size_of::<[f32; 3]>(),
-        )
-    };
-    let position_data = Some(builder.create_vector_direct(&position_data));
-    let position_stream = schema::MeshStream::create(
-        &mut builder,
-        &schema::MeshStreamArgs {
-            format: schema::StreamFormat::Vector3,
-            type_: schema::StreamType::Positions,
-            elements: mesh_data.positions.len() as u64,
-            data: position_data,
-        },
-    );
-    streams.push(position_stream);
-
-    // Normals
-    let normal_data = mesh_data.normals.as_ptr() as *const u8;
-    let normal_data = unsafe {
-        std::slice::from_raw_parts(
-            normal_data,
-            mesh_data.normals.len() * std::mem::size_of::<[f32; 3]>(),
-        )
-    };
-    let normal_data = Some(builder.create_vector_direct(&normal_data));
-    let normal_stream = schema::MeshStream::create(
-        &mut builder,
-        &schema::MeshStreamArgs {
-            format: schema::StreamFormat::Vector3,
-            type_: schema::StreamType::Normals,
-            elements: mesh_data.normals.len() as u64,
-            data: normal_data,
-        },
-    );
-    streams.push(normal_stream);
-
-    // Tangents
-    let tangent_data = mesh_data.tangents.as_ptr() as *const u8;
-    let tangent_data = unsafe {
-        std::slice::from_raw_parts(
-            tangent_data,
-            mesh_data.tangents.len() * std::mem::size_of::<[f32; 3]>(),
-        )
-    };
-    let tangent_data = Some(builder.create_vector_direct(&tangent_data));
-    let tangent_stream = schema::MeshStream::create(
-        &mut builder,
-        &schema::MeshStreamArgs {
-            format: schema::StreamFormat::Vector3,
-            type_: schema::StreamType::Tangents,
-            elements: mesh_data.tangents.len() as u64,
-            data: tangent_data,
-        },
-    );
-    streams.push(tangent_stream);
-
-    // Bitangents
-    let bitangent_data = mesh_data.bitangents.as_ptr() as *const u8;
-    let bitangent_data = unsafe {
-        std::slice::from_raw_parts(
-            bitangent_data,
-            mesh_data.bitangents.len() * std::mem::size_of::<[f32; 3]>(),
-        )
-    };
-    let bitangent_data = Some(builder.create_vector_direct(&bitangent_data));
-    let bitangent_stream = schema::MeshStream::create(
-        &mut builder,
-        &schema::MeshStreamArgs {
-            format: schema::StreamFormat::Vector3,
-            type_: schema::StreamType::Bitangents,
-            elements: mesh_data.bitangents.len() as u64,
-            data: bitangent_data,
-        },
-    );
-    streams.push(bitangent_stream);
-
-    // Texture Coords
-    let tex_coord_data = mesh_data.tex_coords.as_ptr() as *const u8;
-    let tex_coord_data = unsafe {
-        std::slice::from_raw_parts(
-            tex_coord_data,
-            mesh_data.tex_coords.len() * std::mem::size_of::<[f32; 2]>(),
-        )
-    };
-    let tex_coord_data = Some(builder.create_vector_direct(&tex_coord_data));
-    let tex_coord_stream = schema::MeshStream::create(
-        &mut builder,
-        &schema::MeshStreamArgs {
-            format: schema::StreamFormat::Vector2,
-            type_: schema::StreamType::TextureCoordinates,
-            elements: mesh_data.tex_coords.len() as u64,
-            data: tex_coord_data,
-        },
-    );
-    streams.push(tex_coord_stream);
-
-    // Colors
-    let color_data = mesh_data.colors.as_ptr() as *const u8;
-    let color_data = unsafe {
-        std::slice::from_raw_parts(
-            color_data,
-            mesh_data.colors.len() * std::mem::size_of::<[f32; 4]>(),
-        )
-    };
-    let color_data = Some(builder.create_vector_direct(&color_data));
-    let color_stream = schema::MeshStream::create(
-        &mut builder,
-        &schema::MeshStreamArgs {
-            format: schema::StreamFormat::Vector4,
-            type_: schema::StreamType::Colors,
-            elements: mesh_data.colors.len() as u64,
-            data: color_data,
-        },
-    );
-    streams.push(color_stream);
-
-    // Indices
-    let index_data = mesh_data.indices.as_ptr() as *const u8;
-    let index_data = unsafe {
-        std::slice::from_raw_parts(
-            index_data,
-            mesh_data.indices.len() * std::mem::size_of::<u32>(),
-        )
-    };
-    let index_data = Some(builder.create_vector_direct(&index_data));
-    let index_stream = schema::MeshStream::create(
-        &mut builder,
-        &schema::MeshStreamArgs {
-            format: schema::StreamFormat::Int,
-            type_: schema::StreamType::Indices,
-            elements: mesh_data.indices.len() as u64,
-            data: index_data,
-        },
+    std::fs::write(output_path, &report.manifest)?;
+    println!(
+        "Wrote '{}' - {} of {} entries failed, {} bytes in {}",
+        output_path.to_string_lossy(),
+        report.failed(),
+        report.entries.len(),
+        report.manifest.len(),
+        elapsed
     );
-    streams.push(index_stream);
-
-    let streams = Some(builder.create_vector(&streams));
-
-    // Setup materials
-    let mut materials: Vec<_> = Vec::new();
-    for material in &model.materials {
-        let name = if let Some(ref name) = material.name {
-            name.to_owned()
-        } else {
-            "".to_string()
-        };
-        let name = Some(builder.create_string(&name));
-        let uri = Some(builder.create_string(&material.material_uri));
-        let albedo_tint = Some(builder.create_vector_direct(&[
-            material.base_color_factor[0],
-            material.base_color_factor[1],
-            material.base_color_factor[2],
-        ]));
-        materials.push(schema::MeshMaterial::create(
-            &mut builder,
-            &schema::MeshMaterialArgs {
-                name,
-                material: uri,
-                albedo_tint,
-                roughness: material.roughness_factor,
-            },
-        ));
+    if let Some(cache) = cache {
+        let stats = cache.stats();
+        println!(
+            "Mesh cache - {} hits, {} misses, {} meshes, {} bytes",
+            stats.hits, stats.misses, stats.entries, stats.size
+        );
     }
-    let materials = Some(builder.create_vector(&materials));
 
-    // Setup parts
-    //let parts: Vec<_> = Vec::new();
-    //let parts = Some(builder.create_vector(&parts));
-
-    // Calculate bounding box
-    let bounding_min = Some(builder.create_vector_direct(&[
-        model.dimensions.min[0],
-        model.dimensions.min[1],
-        model.dimensions.min[2],
-    ]));
-    let bounding_max = Some(builder.create_vector_direct(&[
-        model.dimensions.max[0],
-        model.dimensions.max[1],
-        model.dimensions.max[2],
-    ]));
-    let name = Some(builder.create_string(&model_path.to_string_lossy()));
-    let identity = "123456-ident";
-    let identity = Some(builder.create_string(&identity));
-    let mesh = schema::Mesh::create(
-        &mut builder,
-        &schema::MeshArgs {
-            name,
-            identity,
-            streams,
-            materials,
-            animations: None,
-            //parts,
-            parts: None,
-            skinning_data: None,
-            bounding_min,
-            bounding_max,
-        },
-    );
-
-    Ok(mesh)
+    Ok(report.failed())
 }
 
 fn main() {
-    let meshes = ["data/Combat_Helmet.glb", "data/Floor_Junk_Cluster_01.glb"];
+    if log::set_logger(&LOGGER).is_ok() {
+        let verbose = std::env::var_os("SVC_MESH_DEBUG").is_some();
+        log::set_max_level(if verbose {
+            log::LevelFilter::Debug
+        } else {
+            log::LevelFilter::Info
+        });
+    }
 
-    let mut manifest_builder = flatbuffers::FlatBufferBuilder::new();
+    let mut args = std::env::args().skip(1).peekable();
+    let mut batch_paths = None;
+    if args.peek().map(String::as_str) == Some("batch") {
+        let library_path = args.nth(1).unwrap_or_else(|| usage());
+        let output_path = args.next().unwrap_or_else(|| usage());
+        batch_paths = Some((library_path, output_path));
+    }
 
-    //list(&"data/Book_03.glb").expect("runtime error");
-    //list(&"data/BoxAnimated.glb").expect("runtime error");
-    //list(&"data/Combat_Helmet.glb").expect("runtime error");
-    //list(&"data/EpicCitadel.glb").expect("runtime error");
-    //list(&"data/Floor_Junk_Cluster_01.glb").expect("runtime error");
-    //list(&"data/Machine_01.glb").expect("runtime error");
-    //list(&"data/RiggedFigure.glb").expect("runtime error");
-    //list(&"data/Warrok.glb").expect("runtime error");
-    //load_model(&Path::new("data/SciFiHelmet.gltf")).expect("runtime error");
-    //load_model(&Path::new("data/EpicCitadel.glb")).expect("runtime error");
-    //load_model(&Path::new("data/BoxAnimated.glb")).expect("runtime error");
-    //load_model(&Path::new("data/RiggedFigure.glb")).expect("runtime error");
-    //display(&"data/RiggedFigure.glb").expect("runtime error");
+    let mut address = DEFAULT_ADDRESS.to_string();
+    let mut asset_root = PathBuf::from(DEFAULT_ASSET_ROOT);
+    let mut cache_directory = None;
+    let mut cache_size = DEFAULT_CACHE_SIZE;
 
-    let mut manifest_meshes: Vec<_> = Vec::with_capacity(meshes.len());
-    for mesh_name in &meshes {
-        let mesh = load_model(&mut manifest_builder, &Path::new(mesh_name)).expect("runtime error");
-        manifest_meshes.push(mesh);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--address" if batch_paths.is_none() => {
+                address = args.next().unwrap_or_else(|| usage())
+            }
+            "--root" if batch_paths.is_none() => {
+                asset_root = PathBuf::from(args.next().unwrap_or_else(|| usage()))
+            }
+            "--cache" => {
+                cache_directory = Some(PathBuf::from(args.next().unwrap_or_else(|| usage())))
+            }
+            "--cache-size" => {
+                cache_size = args
+                    .next()
+                    .and_then(|size| size.parse::<u64>().ok())
+                    .and_then(|megabytes| megabytes.checked_mul(1024 * 1024))
+                    .unwrap_or_else(|| usage());
+            }
+            _ => usage(),
+        }
     }
 
-    let manifest_meshes = Some(manifest_builder.create_vector(&manifest_meshes));
-    let manifest = schema::Manifest::create(
-        &mut manifest_builder,
-        &schema::ManifestArgs {
-            meshes: manifest_meshes,
-        },
-    );
+    let cache = open_cache(cache_directory, cache_size);
+
+    if let Some((library_path, output_path)) = batch_paths {
+        match batch(
+            Path::new(&library_path),
+            Path::new(&output_path),
+            cache.as_ref(),
+        ) {
+            Ok(0) => return,
+            Ok(_) => std::process::exit(1),
+            Err(err) => {
+                eprintln!("Failed to process '{}': {}", library_path, err);
+                std::process::exit(1)
+            }
+        }
+    }
+
+    let address: SocketAddr = address.parse().unwrap_or_else(|err| {
+        eprintln!("invalid address '{}': {}", address, err);
+        std::process::exit(2)
+    });
 
-    manifest_builder.finish(manifest, None);
-    let manifest_data = manifest_builder.finished_data();
+    log::info!("Serving '{}' on {}", asset_root.to_string_lossy(), address);
 
-    println!("Done - {} bytes", manifest_data.len());
+    serve(address, MeshService::new(asset_root, cache));
 }
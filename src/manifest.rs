@@ -0,0 +1,174 @@
+use crate::generated::service::mesh::schema;
+
+/// A processed mesh serialized as finished `schema::Mesh` flatbuffers, once
+/// without stream data for headers and once with everything.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SerializedMesh {
+    pub header: Vec<u8>,
+    pub mesh: Vec<u8>,
+}
+
+impl SerializedMesh {
+    /// The same mesh written as `name`, or `None` when it has no name to replace.
+    pub fn renamed(self, name: &str) -> Option<SerializedMesh> {
+        Some(SerializedMesh {
+            header: rename_mesh(self.header, name)?,
+            mesh: rename_mesh(self.mesh, name)?,
+        })
+    }
+}
+
+/// Root table of a finished `schema::Mesh` flatbuffer.
+pub fn serialized_mesh(buffer: &[u8]) -> schema::Mesh<'_> {
+    flatbuffers::get_root::<schema::Mesh<'_>>(buffer)
+}
+
+/// Point the name of a finished `schema::Mesh` flatbuffer at `name`, appended
+/// to the end of the buffer. The rest of the buffer is left untouched and the
+/// previous name is no longer referenced.
+fn rename_mesh(mut buffer: Vec<u8>, name: &str) -> Option<Vec<u8>> {
+    let field = {
+        let mesh = serialized_mesh(&buffer);
+        if mesh.name() == Some(name) {
+            return Some(buffer);
+        }
+        match mesh._tab.vtable().get(schema::Mesh::VT_NAME) {
+            0 => return None,
+            offset => mesh._tab.loc + offset as usize,
+        }
+    };
+
+    // A string is an aligned length followed by its bytes and a terminating zero
+    let padding = (flatbuffers::SIZE_UOFFSET - buffer.len() % flatbuffers::SIZE_UOFFSET)
+        % flatbuffers::SIZE_UOFFSET;
+    buffer.resize(buffer.len() + padding, 0);
+    let string = buffer.len();
+    buffer.extend_from_slice(&(name.len() as u32).to_le_bytes());
+    buffer.extend_from_slice(name.as_bytes());
+    buffer.push(0);
+
+    buffer[field..field + flatbuffers::SIZE_UOFFSET]
+        .copy_from_slice(&((string - field) as u32).to_le_bytes());
+    Some(buffer)
+}
+
+/// Copy a finished `schema::Mesh` flatbuffer into `builder` as a unit, and
+/// return the offset of its root table. The buffer is stored as a vector of
+/// 8-byte words, so its contents keep their alignment.
+fn embed_mesh<'a>(
+    builder: &mut flatbuffers::FlatBufferBuilder<'a>,
+    buffer: &[u8],
+) -> flatbuffers::WIPOffset<schema::Mesh<'a>> {
+    let words: Vec<u64> = buffer
+        .chunks(8)
+        .map(|chunk| {
+            let mut word = [0u8; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(word)
+        })
+        .collect();
+    let vector = builder.create_vector_direct(&words);
+
+    // Builder offsets count from the end, the words follow the vector length
+    let start = vector.value() - flatbuffers::SIZE_UOFFSET as flatbuffers::UOffsetT;
+    let root = flatbuffers::read_scalar::<flatbuffers::UOffsetT>(buffer);
+    flatbuffers::WIPOffset::new(start - root)
+}
+
+/// Assemble a finished `schema::Manifest` holding the finished `schema::Mesh`
+/// flatbuffers `meshes`, in order.
+pub fn finish_manifest<'b, I>(meshes: I) -> Vec<u8>
+where
+    I: IntoIterator<Item = &'b [u8]>,
+{
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let offsets: Vec<_> = meshes
+        .into_iter()
+        .map(|mesh| embed_mesh(&mut builder, mesh))
+        .collect();
+    let meshes = Some(builder.create_vector(&offsets));
+    let manifest = schema::Manifest::create(&mut builder, &schema::ManifestArgs { meshes });
+
+    builder.finish(manifest, None);
+    builder.finished_data().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mesh_buffer(name: &str, data: &[u8]) -> Vec<u8> {
+        let mut builder = flatbuffers::FlatBufferBuilder::new();
+        let name = Some(builder.create_string(name));
+        let identity = Some(builder.create_string("identity"));
+        let stream_data = Some(builder.create_vector_direct(data));
+        let stream = schema::MeshStream::create(
+            &mut builder,
+            &schema::MeshStreamArgs {
+                type_: schema::StreamType::Indices,
+                elements: data.len() as u64,
+                data: stream_data,
+                decoded_size: data.len() as u64,
+                ..Default::default()
+            },
+        );
+        let streams = Some(builder.create_vector(&[stream]));
+        let mesh = schema::Mesh::create(
+            &mut builder,
+            &schema::MeshArgs {
+                name,
+                identity,
+                streams,
+                skinning_influences: 4,
+                ..Default::default()
+            },
+        );
+        builder.finish(mesh, None);
+        builder.finished_data().to_vec()
+    }
+
+    fn assert_same_contents(a: &schema::Mesh<'_>, b: &schema::Mesh<'_>) {
+        assert_eq!(a.identity(), b.identity());
+        assert_eq!(a.skinning_influences(), b.skinning_influences());
+        let (a, b) = (a.streams().unwrap(), b.streams().unwrap());
+        assert_eq!(a.len(), b.len());
+        for index in 0..a.len() {
+            let (a, b) = (a.get(index), b.get(index));
+            assert_eq!(a.type_(), b.type_());
+            assert_eq!(a.elements(), b.elements());
+            assert_eq!(a.decoded_size(), b.decoded_size());
+            assert_eq!(a.data(), b.data());
+        }
+    }
+
+    #[test]
+    fn rename_only_changes_the_name() {
+        let buffer = mesh_buffer("mesh", &[1, 2, 3, 4, 5]);
+        let renamed = rename_mesh(buffer.clone(), "a longer mesh name").unwrap();
+
+        let (mesh, renamed) = (serialized_mesh(&buffer), serialized_mesh(&renamed));
+        assert_eq!(renamed.name(), Some("a longer mesh name"));
+        assert_same_contents(&mesh, &renamed);
+
+        // Renaming to the current name keeps the buffer as is
+        let unchanged = rename_mesh(buffer.clone(), "mesh").unwrap();
+        assert_eq!(unchanged, buffer);
+    }
+
+    #[test]
+    fn manifest_holds_meshes_unchanged() {
+        let buffers = [
+            mesh_buffer("first", &[1, 2, 3]),
+            rename_mesh(mesh_buffer("second", &[4, 5, 6, 7, 8, 9]), "renamed").unwrap(),
+        ];
+        let manifest = finish_manifest(buffers.iter().map(Vec::as_slice));
+
+        let meshes = schema::get_root_as_manifest(&manifest).meshes().unwrap();
+        assert_eq!(meshes.len(), 2);
+        assert_eq!(meshes.get(0).name(), Some("first"));
+        assert_eq!(meshes.get(1).name(), Some("renamed"));
+        for (index, buffer) in buffers.iter().enumerate() {
+            assert_same_contents(&meshes.get(index), &serialized_mesh(buffer));
+        }
+    }
+}
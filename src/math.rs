@@ -8,11 +8,13 @@ pub type Vector4 = nalgebra::Vector4<f32>;
 
 //pub type Point3 = nalgebra::Point3<f32>;
 
+pub type Matrix3 = nalgebra::Matrix3<f32>;
 pub type Matrix4 = nalgebra::Matrix4<f32>;
 pub type Quaternion = nalgebra::Quaternion<f32>;
 pub type UnitQuaternion = nalgebra::UnitQuaternion<f32>;
 
-#[derive(Debug)]
+/// Axis aligned bounds, with an enclosing sphere.
+#[derive(Debug, Clone, Copy)]
 pub struct Dimensions {
     pub min: Vector3,
     pub max: Vector3,
@@ -44,6 +46,58 @@ impl Dimensions {
             radius: distance / 2.0,
         }
     }
+
+    /// Bounds of `points`, with a tight sphere rather than the sphere around
+    /// the box.
+    pub fn from_points(points: &[Vector3]) -> Dimensions {
+        if points.is_empty() {
+            return Dimensions::default();
+        }
+        let mut min = points[0];
+        let mut max = points[0];
+        for point in points {
+            min = min.inf(point);
+            max = max.sup(point);
+        }
+        let mut dimensions = Dimensions::new(min, max);
+        let (center, radius) = bounding_sphere(points);
+        if radius < dimensions.radius {
+            dimensions.center = center;
+            dimensions.radius = radius;
+        }
+        dimensions
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x
+    }
+
+    /// Bounds of the eight transformed corners of the box.
+    pub fn transform(&self, matrix: &Matrix4) -> Dimensions {
+        if self.is_empty() {
+            return Dimensions::default();
+        }
+        let corners: Vec<Vector3> = (0..8)
+            .map(|corner| {
+                let pick = |axis: usize| {
+                    if corner & (1 << axis) != 0 {
+                        self.max[axis]
+                    } else {
+                        self.min[axis]
+                    }
+                };
+                let corner = matrix * Vector4::new(pick(0), pick(1), pick(2), 1.0);
+                Vector3::new(corner.x, corner.y, corner.z)
+            })
+            .collect();
+        let mut min = corners[0];
+        let mut max = corners[0];
+        for corner in &corners {
+            min = min.inf(corner);
+            max = max.sup(corner);
+        }
+        Dimensions::new(min, max)
+    }
 }
 
 pub fn flip_check(uv: [f32; 2], flip_v: bool) -> [f32; 2] {
@@ -53,3 +107,102 @@ pub fn flip_check(uv: [f32; 2], flip_v: bool) -> [f32; 2] {
         uv
     }
 }
+
+/// Approximate minimal sphere enclosing `points` (Ritter's algorithm),
+/// returned as center and radius.
+pub fn bounding_sphere(points: &[Vector3]) -> (Vector3, f32) {
+    let first = match points.first() {
+        Some(first) => *first,
+        None => return (Vector3::zeros(), 0.0),
+    };
+
+    // Start from the two points farthest apart along the widest axis
+    let mut min = [first; 3];
+    let mut max = [first; 3];
+    for point in points {
+        for (axis, (low, high)) in min.iter_mut().zip(max.iter_mut()).enumerate() {
+            if point[axis] < low[axis] {
+                *low = *point;
+            }
+            if point[axis] > high[axis] {
+                *high = *point;
+            }
+        }
+    }
+    let axis = (0..3)
+        .max_by(|&a, &b| {
+            let span_a = (max[a] - min[a]).norm_squared();
+            let span_b = (max[b] - min[b]).norm_squared();
+            span_a
+                .partial_cmp(&span_b)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .unwrap_or(0);
+
+    let mut center = (min[axis] + max[axis]) / 2.0;
+    let mut radius = (max[axis] - min[axis]).norm() / 2.0;
+
+    // Grow the sphere to include every point
+    for point in points {
+        let distance = (point - center).norm();
+        if distance > radius {
+            let grown = (radius + distance) / 2.0;
+            center += (point - center) * ((grown - radius) / distance);
+            radius = grown;
+        }
+    }
+
+    (center, radius)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random_sequence;
+
+    /// Point clouds of various shapes, from a fixed pseudo random sequence.
+    fn point_clouds() -> Vec<Vec<Vector3>> {
+        let mut next = random_sequence(7);
+        let mut random = move || (next() >> 16) as f32 / 65535.0 * 2.0 - 1.0;
+
+        let cube: Vec<Vector3> = (0..200)
+            .map(|_| Vector3::new(random(), random(), random()))
+            .collect();
+        let slab: Vec<Vector3> = (0..200)
+            .map(|_| Vector3::new(random() * 50.0 + 10.0, random() * 0.1, random() * 3.0))
+            .collect();
+        let sphere: Vec<Vector3> = (0..200)
+            .map(|_| Vector3::new(random(), random(), random()).normalize() * 4.0)
+            .collect();
+        let line: Vec<Vector3> = (0..10)
+            .map(|index| Vector3::new(1.0, 2.0, 3.0) * index as f32)
+            .collect();
+        vec![cube, slab, sphere, line, vec![Vector3::new(5.0, -2.0, 1.0)]]
+    }
+
+    #[test]
+    fn sphere_and_box_contain_every_point() {
+        for points in point_clouds() {
+            let dimensions = Dimensions::from_points(&points);
+            assert!(!dimensions.is_empty());
+            assert!(dimensions.radius <= Dimensions::new(dimensions.min, dimensions.max).radius);
+
+            let tolerance = 1e-5 * (1.0 + dimensions.radius);
+            for point in &points {
+                assert!((point - dimensions.center).norm() <= dimensions.radius + tolerance);
+                for axis in 0..3 {
+                    assert!(dimensions.min[axis] <= point[axis]);
+                    assert!(point[axis] <= dimensions.max[axis]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn no_points_have_empty_dimensions() {
+        let dimensions = Dimensions::from_points(&[]);
+        assert!(dimensions.is_empty());
+        assert!(dimensions.radius <= 0.0);
+        assert!(dimensions.center.norm() <= 0.0);
+    }
+}
@@ -0,0 +1,291 @@
+use crate::bounding_sphere;
+use crate::Error;
+use crate::GltfVertex;
+use crate::MeshAssetPart;
+use crate::Result;
+use crate::Topology;
+use crate::Vector3;
+use std::collections::HashMap;
+
+/// Meshlet triangles store their corners as 8-bit local vertex indices.
+pub const MAX_MESHLET_VERTICES: u32 = 256;
+pub const MAX_MESHLET_TRIANGLES: u32 = 256;
+
+/// A meshlet as stored in a `StreamType::Meshlets` stream, 64 bytes each.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Meshlet {
+    /// First entry of the meshlet in the meshlet vertex stream.
+    pub vertex_offset: u32,
+    pub vertex_count: u32,
+    /// First entry of the meshlet in the meshlet triangle stream.
+    pub triangle_offset: u32,
+    pub triangle_count: u32,
+    pub center: [f32; 3],
+    pub radius: f32,
+    /// The meshlet faces away from a camera at `position` when
+    /// `dot(normalize(cone_apex - position), cone_axis) >= cone_cutoff`.
+    pub cone_apex: [f32; 3],
+    pub cone_cutoff: f32,
+    pub cone_axis: [f32; 3],
+    pub padding: u32,
+}
+
+/// Meshlets of every part of a mesh.
+#[derive(Debug, Default)]
+pub struct MeshletData {
+    pub meshlets: Vec<Meshlet>,
+    /// Mesh vertex index of every meshlet vertex.
+    pub vertices: Vec<u32>,
+    /// Meshlet triangles, three 8-bit meshlet vertex indices packed per u32.
+    pub triangles: Vec<u32>,
+    /// First meshlet and meshlet count of each part.
+    pub part_ranges: Vec<(u32, u32)>,
+}
+
+/// Meshlet being filled with triangles.
+#[derive(Default)]
+struct MeshletBuilder {
+    local: HashMap<u32, u32>,
+    vertices: Vec<u32>,
+    triangles: Vec<u32>,
+}
+
+impl MeshletBuilder {
+    fn local_index(&mut self, index: u32) -> u32 {
+        let vertices = &mut self.vertices;
+        *self.local.entry(index).or_insert_with(|| {
+            vertices.push(index);
+            vertices.len() as u32 - 1
+        })
+    }
+
+    fn flush(&mut self, vertex_buffer: &[GltfVertex], data: &mut MeshletData) {
+        if self.triangles.is_empty() {
+            return;
+        }
+
+        let position = |local: u32| {
+            Vector3::from(vertex_buffer[self.vertices[local as usize] as usize].position)
+        };
+        let corners: Vec<[Vector3; 3]> = self
+            .triangles
+            .iter()
+            .map(|&triangle| {
+                [
+                    position(triangle & 0xff),
+                    position((triangle >> 8) & 0xff),
+                    position(triangle >> 16),
+                ]
+            })
+            .collect();
+
+        let mut meshlet = meshlet_bounds(&corners);
+        meshlet.vertex_offset = data.vertices.len() as u32;
+        meshlet.vertex_count = self.vertices.len() as u32;
+        meshlet.triangle_offset = data.triangles.len() as u32;
+        meshlet.triangle_count = self.triangles.len() as u32;
+        data.meshlets.push(meshlet);
+
+        data.vertices.append(&mut self.vertices);
+        data.triangles.append(&mut self.triangles);
+        self.local.clear();
+    }
+}
+
+/// Split the triangles of every part into meshlets of at most `max_vertices`
+/// vertices and `max_triangles` triangles, in index order. Parts sharing an
+/// index range share their meshlets.
+///
+/// meshopt 0.1 `build_meshlets` is not used: it is limited to 64 vertices and
+/// 126 triangles per meshlet, while the stream format allows up to 256 of
+/// each, and its meshlets would have to be repacked into our layout anyway.
+pub fn build_meshlets(
+    vertices: &[GltfVertex],
+    indices: &[u32],
+    parts: &[MeshAssetPart],
+    max_vertices: u32,
+    max_triangles: u32,
+) -> Result<MeshletData> {
+    if !(3..=MAX_MESHLET_VERTICES).contains(&max_vertices) {
+        return Err(Error::config(format!(
+            "meshlet_max_vertices must be between 3 and {}",
+            MAX_MESHLET_VERTICES
+        )));
+    }
+    if !(1..=MAX_MESHLET_TRIANGLES).contains(&max_triangles) {
+        return Err(Error::config(format!(
+            "meshlet_max_triangles must be between 1 and {}",
+            MAX_MESHLET_TRIANGLES
+        )));
+    }
+
+    let mut data = MeshletData::default();
+    // Meshlet range of each source index range
+    let mut ranges: HashMap<(u32, u32), (u32, u32)> = HashMap::new();
+    for part in parts {
+        let index_range = (part.index_start, part.index_count);
+        if let Some(&shared) = ranges.get(&index_range) {
+            data.part_ranges.push(shared);
+            continue;
+        }
+        // Lines and points have no meshlets
+        if part.topology != Topology::Triangles {
+            data.part_ranges.push((data.meshlets.len() as u32, 0));
+            continue;
+        }
+
+        let meshlet_start = data.meshlets.len() as u32;
+        let start = part.index_start as usize;
+        let mut builder = MeshletBuilder::default();
+        for triangle in indices[start..start + part.index_count as usize].chunks(3) {
+            if triangle.len() < 3 {
+                continue;
+            }
+
+            let mut new_vertices = 0;
+            for (corner, index) in triangle.iter().enumerate() {
+                if !builder.local.contains_key(index) && !triangle[..corner].contains(index) {
+                    new_vertices += 1;
+                }
+            }
+            if builder.vertices.len() as u32 + new_vertices > max_vertices
+                || builder.triangles.len() as u32 >= max_triangles
+            {
+                builder.flush(vertices, &mut data);
+            }
+
+            let a = builder.local_index(triangle[0]);
+            let b = builder.local_index(triangle[1]);
+            let c = builder.local_index(triangle[2]);
+            builder.triangles.push(a | (b << 8) | (c << 16));
+        }
+        builder.flush(vertices, &mut data);
+
+        let meshlet_count = data.meshlets.len() as u32 - meshlet_start;
+        ranges.insert(index_range, (meshlet_start, meshlet_count));
+        data.part_ranges.push((meshlet_start, meshlet_count));
+    }
+    Ok(data)
+}
+
+/// Bounding sphere and normal cone of a set of triangles.
+fn meshlet_bounds(corners: &[[Vector3; 3]]) -> Meshlet {
+    let points: Vec<Vector3> = corners
+        .iter()
+        .flat_map(|corners| corners.to_vec())
+        .collect();
+    let (center, radius) = bounding_sphere(&points);
+
+    let mut meshlet = Meshlet {
+        center: center.into(),
+        radius,
+        cone_apex: center.into(),
+        // A cutoff of 1 never culls the meshlet
+        cone_cutoff: 1.0,
+        ..Meshlet::default()
+    };
+
+    let normals: Vec<(Vector3, Vector3)> = corners
+        .iter()
+        .filter_map(|[a, b, c]| {
+            let normal = (b - a).cross(&(c - a));
+            let area = normal.norm();
+            if area > 0.0 {
+                Some((*a, normal / area))
+            } else {
+                None
+            }
+        })
+        .collect();
+    let axis = normals
+        .iter()
+        .fold(Vector3::zeros(), |axis, (_, normal)| axis + normal);
+    let axis_length = axis.norm();
+    if normals.is_empty() || axis_length <= 0.0 {
+        return meshlet;
+    }
+    let axis = axis / axis_length;
+    meshlet.cone_axis = axis.into();
+
+    let min_dot = normals
+        .iter()
+        .fold(1.0f32, |min_dot, (_, normal)| min_dot.min(axis.dot(normal)));
+    if min_dot <= 0.1 {
+        // The normals spread over more than a hemisphere, culling is pointless
+        return meshlet;
+    }
+
+    // Move the apex back along the axis until it lies behind every triangle
+    let max_t = normals.iter().fold(0.0f32, |max_t, (corner, normal)| {
+        let t = (center - corner).dot(normal) / axis.dot(normal);
+        max_t.max(t)
+    });
+    meshlet.cone_apex = (center - axis * max_t).into();
+    meshlet.cone_cutoff = (1.0 - min_dot * min_dot).sqrt();
+    meshlet
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_mesh;
+    use crate::triangle_part;
+
+    #[test]
+    fn meshlets_respect_limits_and_hold_every_triangle_once() {
+        let (vertices, indices) = grid_mesh(16);
+        let half = indices.len() as u32 / 2;
+        let parts = [
+            triangle_part(0, half),
+            triangle_part(half, half),
+            triangle_part(0, half),
+        ];
+
+        for &(max_vertices, max_triangles) in &[(64, 124), (3, 1), (10, 256), (256, 7), (256, 256)]
+        {
+            let data =
+                build_meshlets(&vertices, &indices, &parts, max_vertices, max_triangles).unwrap();
+            assert_eq!(data.part_ranges.len(), parts.len());
+            assert_eq!(data.part_ranges[2], data.part_ranges[0]);
+
+            for (part, &(meshlet_start, meshlet_count)) in parts.iter().zip(&data.part_ranges) {
+                let mut triangles = Vec::new();
+                let meshlets = meshlet_start as usize..(meshlet_start + meshlet_count) as usize;
+                for meshlet in &data.meshlets[meshlets] {
+                    assert!(meshlet.vertex_count <= max_vertices);
+                    assert!(meshlet.triangle_count <= max_triangles);
+
+                    let start = meshlet.vertex_offset as usize;
+                    let local = &data.vertices[start..start + meshlet.vertex_count as usize];
+                    let start = meshlet.triangle_offset as usize;
+                    for &triangle in &data.triangles[start..start + meshlet.triangle_count as usize]
+                    {
+                        for &corner in &[triangle & 0xff, (triangle >> 8) & 0xff, triangle >> 16] {
+                            assert!(corner < meshlet.vertex_count);
+                            triangles.push(local[corner as usize]);
+                        }
+                    }
+                }
+
+                // Every triangle of the part, in order
+                let start = part.index_start as usize;
+                assert_eq!(
+                    triangles,
+                    &indices[start..start + part.index_count as usize]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_limits_are_rejected() {
+        let (vertices, indices) = grid_mesh(1);
+        let parts = [triangle_part(0, indices.len() as u32)];
+        for &(max_vertices, max_triangles) in &[(2, 1), (257, 1), (3, 0), (3, 257)] {
+            assert!(
+                build_meshlets(&vertices, &indices, &parts, max_vertices, max_triangles).is_err()
+            );
+        }
+    }
+}
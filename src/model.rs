@@ -4,12 +4,16 @@ use super::GltfIndex;
 use crate::Dimensions;
 use crate::GltfAnimation;
 use crate::GltfData;
+use crate::GltfJointNode;
 use crate::GltfMaterial;
 use crate::GltfMesh;
 use crate::GltfNode;
 use crate::GltfNodeRef;
+use crate::GltfSkeleton;
+use crate::GltfSkeletonJoint;
 use crate::GltfSkin;
 use crate::GltfTexture;
+use crate::Matrix4;
 use crate::Result;
 use crate::Vector3;
 use std::collections::HashMap;
@@ -17,7 +21,7 @@ use std::path::Path;
 use std::rc::Rc;
 
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct GltfVertex {
     pub position: [f32; 3],
     pub normal: [f32; 3],
@@ -49,6 +53,7 @@ pub struct GltfModel {
     pub materials: Vec<Rc<GltfMaterial>>,
     pub animations: Vec<Rc<GltfAnimation>>,
     pub skins: Vec<Rc<GltfSkin>>,
+    pub skeleton: GltfSkeleton,
 
     pub dimensions: Dimensions,
 
@@ -96,7 +101,9 @@ impl GltfModel {
         }
     }
 
-    pub fn from_gltf(data: &GltfData, path: &Path) -> Result<Self> {
+    /// Load the nodes of `scene_ref`, along with every texture, material,
+    /// animation and skin of the document.
+    pub fn from_gltf(data: &GltfData, path: &Path, scene_ref: &gltf::Scene<'_>) -> Result<Self> {
         let mut model = GltfModel::default();
 
         // Load textures
@@ -114,13 +121,10 @@ impl GltfModel {
             .collect::<Result<_>>()?;
 
         // Load nodes
-        for scene in data.document.scenes() {
-            let mut nodev: Vec<GltfNodeRef> = scene
-                .nodes()
-                .map(|node_ref| GltfNode::from_gltf(None, &node_ref, &mut model, data, path))
-                .collect::<Result<_>>()?;
-            model.root_nodes.append(&mut nodev);
-        }
+        model.root_nodes = scene_ref
+            .nodes()
+            .map(|node_ref| GltfNode::from_gltf(None, &node_ref, &mut model, data, path))
+            .collect::<Result<_>>()?;
 
         let mut res_nodes: Vec<GltfNodeRef> = Vec::new();
         for node in &model.root_nodes {
@@ -170,15 +174,18 @@ impl GltfModel {
         .collect();*/
 
         // Load animations
-        model.animations = data
-            .document
-            .animations()
-            .map(|animation_ref| GltfAnimation::from_gltf(&animation_ref, data, path, &model))
-            .filter(|animation| {
-                // Only keep animations with valid channels
-                animation.channels.len() > 0
-            })
-            .collect();
+        if data.options.load_animations {
+            let animations = data
+                .document
+                .animations()
+                .map(|animation_ref| GltfAnimation::from_gltf(&animation_ref, data, path, &model))
+                .collect::<Result<Vec<_>>>()?;
+            // Only keep animations with valid channels
+            model.animations = animations
+                .into_iter()
+                .filter(|animation| animation.channels.len() > 0)
+                .collect();
+        }
 
         // Load skins
         model.skins = data
@@ -196,23 +203,81 @@ impl GltfModel {
     fn merge_skins(&mut self) {
         // A gltf model can contain multiple meshes with multiple primitives (or "parts").
         // We already merge these into a single asset with multiple parts, so we also need to merge the skins here.
-
-        /*
-        for (auto node : linearNodes)
-        {
-            // Assign skins
-            if (node->skinIndex > -1)
-            {
-                node->skin = skins[node->skinIndex];
+        // Joints shared between skins are merged, keeping the inverse bind matrix of the first skin.
+        let mut skeleton = GltfSkeleton::default();
+        let mut joint_map: HashMap<GltfIndex, usize> = HashMap::new();
+        let mut skins = Vec::with_capacity(self.skins.len());
+        for skin in &self.skins {
+            let mut joints = Vec::with_capacity(skin.joints.len());
+            for (joint_index, joint) in skin.joints.iter().enumerate() {
+                let global_index = match joint_map.get(&joint.node_index) {
+                    Some(global_index) => *global_index,
+                    None => {
+                        let inverse_bind_matrix = skin
+                            .inv_bind_matrices
+                            .get(joint_index)
+                            .cloned()
+                            .unwrap_or_else(Matrix4::identity);
+                        skeleton.joints.push(GltfSkeletonJoint {
+                            node_index: joint.node_index,
+                            name: format!("N_{}", joint.node_index),
+                            parent: None,
+                            inverse_bind_matrix,
+                        });
+                        joint_map.insert(joint.node_index, skeleton.joints.len() - 1);
+                        skeleton.joints.len() - 1
+                    }
+                };
+                joints.push(GltfJointNode {
+                    node_index: joint.node_index,
+                    global_index: Some(global_index),
+                });
             }
+            // Rebuilt rather than mutated, as the skin may be shared
+            skins.push(Rc::new(GltfSkin {
+                skin_index: skin.skin_index,
+                name: skin.name.clone(),
+                skeleton_root: skin.skeleton_root,
+                inv_bind_matrices: skin.inv_bind_matrices.clone(),
+                joints,
+            }));
+        }
+        self.skins = skins;
+
+        // Resolve names and parents, the parent of a joint is its closest ancestor that is also a joint
+        for (global_index, joint) in skeleton.joints.iter_mut().enumerate() {
+            if let Some(node) = self.node_from_index(joint.node_index) {
+                node.borrow_mut().joint_index = Some(global_index);
+                if let Some(ref name) = node.borrow().name {
+                    joint.name = name.clone();
+                }
 
-            // Initial pose
-            if (node->mesh)
-            {
-                //node->update();
+                let mut parent = node.borrow().parent.clone();
+                while let Some(parent_node) = parent {
+                    let parent_node = parent_node.borrow();
+                    if let Some(parent_index) = joint_map.get(&parent_node.node_index) {
+                        joint.parent = Some(*parent_index);
+                        break;
+                    }
+                    parent = parent_node.parent.clone();
+                }
             }
         }
-        */
+
+        skeleton.root = self
+            .skins
+            .iter()
+            .filter_map(|skin| skin.skeleton_root)
+            .filter_map(|node_index| joint_map.get(&node_index).cloned())
+            .next()
+            .or_else(|| {
+                skeleton
+                    .joints
+                    .iter()
+                    .position(|joint| joint.parent.is_none())
+            });
+
+        self.skeleton = skeleton;
     }
 
     fn compute_dimensions(&mut self) {
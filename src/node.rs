@@ -142,23 +142,11 @@ impl GltfNode {
         if let Some(ref mesh) = self.mesh {
             let node_matrix = self.get_matrix();
             for primitive in &mesh.primitives {
-                //println!("Min: {:?}, Max: {:?}", &primitive.dimensions.min, &primitive.dimensions.max);
-
-                //println!("Node Matrix: {:?}", node_matrix);
-                let loc_min = node_matrix.transform_vector(&primitive.dimensions.min);
-                let loc_max = node_matrix.transform_vector(&primitive.dimensions.max);
-
-                //println!("LocMin: {:?}, LocMax: {:?}", &loc_min, &loc_max);
-
-                min.x = loc_min.x.min(min.x);
-                min.y = loc_min.y.min(min.y);
-                min.z = loc_min.z.min(min.z);
-
-                max.x = loc_max.x.max(max.x);
-                max.y = loc_max.y.max(max.y);
-                max.z = loc_max.z.max(max.z);
-
-                //println!("min[{}, {}, {}], max[{}, {}, {}]", min.x, min.y, min.z, max.x, max.y, max.z);
+                let bounds = primitive.dimensions.transform(&node_matrix);
+                if !bounds.is_empty() {
+                    *min = min.inf(&bounds.min);
+                    *max = max.sup(&bounds.max);
+                }
             }
         }
 
@@ -0,0 +1,233 @@
+use crate::GltfVertex;
+
+/// Snap a value to a multiple of `epsilon`, mapping -0.0 to 0.0.
+fn quantize(value: f32, epsilon: f32) -> f32 {
+    (value / epsilon).round() + 0.0
+}
+
+fn quantize_array<A: AsMut<[f32]>>(mut values: A, epsilon: f32) -> A {
+    for value in values.as_mut() {
+        *value = quantize(*value, epsilon);
+    }
+    values
+}
+
+/// Copy of `vertex` used to compare vertices when welding with an epsilon.
+fn weld_key(vertex: &GltfVertex, epsilon: f32) -> GltfVertex {
+    GltfVertex {
+        position: quantize_array(vertex.position, epsilon),
+        normal: quantize_array(vertex.normal, epsilon),
+        uv0: quantize_array(vertex.uv0, epsilon),
+        tangent: quantize_array(vertex.tangent, epsilon),
+        bitangent: quantize_array(vertex.bitangent, epsilon),
+        ..*vertex
+    }
+}
+
+/// Weld identical vertices, returning the unique vertices and an index buffer
+/// referencing them, in the order of `vertices`. With a non zero `epsilon`,
+/// positions, normals, tangent frames and texture coordinates that round to
+/// the same multiple of `epsilon` are merged as well.
+pub fn weld_vertices(vertices: &[GltfVertex], epsilon: f32) -> (Vec<GltfVertex>, Vec<u32>) {
+    let (unique_count, remap) = if epsilon > 0.0 {
+        let keys: Vec<GltfVertex> = vertices
+            .iter()
+            .map(|vertex| weld_key(vertex, epsilon))
+            .collect();
+        meshopt::generate_vertex_remap(&keys, None)
+    } else {
+        meshopt::generate_vertex_remap(vertices, None)
+    };
+
+    // Unique vertices are numbered in order of their first occurrence
+    let mut unique = Vec::with_capacity(unique_count);
+    for (vertex, &index) in vertices.iter().zip(&remap) {
+        if index as usize == unique.len() {
+            unique.push(*vertex);
+        }
+    }
+
+    (unique, remap)
+}
+
+impl meshopt::DecodePosition for GltfVertex {
+    fn decode_position(&self) -> [f32; 3] {
+        self.position
+    }
+}
+
+/// Size of the simulated post transform cache used to analyze index orders.
+const ANALYZE_CACHE_SIZE: u32 = 16;
+
+/// Post transform cache statistics, accumulated over several index ranges.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct VertexCacheStats {
+    pub triangles: u64,
+    pub vertices: u64,
+    pub transformed: u64,
+}
+
+impl VertexCacheStats {
+    fn analyze(indices: &[u32], vertex_count: usize) -> VertexCacheStats {
+        let stats = meshopt::analyze_vertex_cache(indices, vertex_count, ANALYZE_CACHE_SIZE, 0, 0);
+        VertexCacheStats {
+            triangles: indices.len() as u64 / 3,
+            vertices: vertex_count as u64,
+            transformed: u64::from(stats.vertices_transformed),
+        }
+    }
+
+    fn add(&mut self, other: VertexCacheStats) {
+        self.triangles += other.triangles;
+        self.vertices += other.vertices;
+        self.transformed += other.transformed;
+    }
+
+    /// Average cache miss ratio, transformed vertices per triangle.
+    pub fn acmr(&self) -> f32 {
+        if self.triangles == 0 {
+            0.0
+        } else {
+            self.transformed as f32 / self.triangles as f32
+        }
+    }
+
+    /// Average transformed vertex ratio, transformed vertices per vertex.
+    pub fn atvr(&self) -> f32 {
+        if self.vertices == 0 {
+            0.0
+        } else {
+            self.transformed as f32 / self.vertices as f32
+        }
+    }
+}
+
+/// Vertex cache efficiency of a mesh before and after optimization.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OptimizationReport {
+    pub before: VertexCacheStats,
+    pub after: VertexCacheStats,
+}
+
+/// Reorder the triangles of every `(index_start, index_count)` range for the
+/// vertex cache, and for overdraw with a non zero `overdraw_threshold` (the
+/// largest tolerated ACMR increase, e.g. 1.05). Vertices are then reordered
+/// by first use across all ranges, dropping unreferenced vertices.
+pub fn optimize_mesh(
+    vertices: &mut Vec<GltfVertex>,
+    indices: &mut [u32],
+    ranges: &[(u32, u32)],
+    overdraw_threshold: f32,
+) -> OptimizationReport {
+    let mut report = OptimizationReport::default();
+
+    // Parts of instanced meshes share their index range
+    let mut ranges = ranges.to_vec();
+    ranges.sort();
+    ranges.dedup();
+
+    for (index_start, index_count) in ranges {
+        let start = index_start as usize;
+        let range = &mut indices[start..start + index_count as usize];
+        let base = match range.iter().min() {
+            Some(&base) => base,
+            None => continue,
+        };
+        let end = range.iter().max().map_or(base, |&max| max + 1);
+        let range_vertices = &vertices[base as usize..end as usize];
+
+        let local: Vec<u32> = range.iter().map(|index| index - base).collect();
+        report
+            .before
+            .add(VertexCacheStats::analyze(&local, range_vertices.len()));
+
+        let mut local = meshopt::optimize_vertex_cache(&local, range_vertices.len());
+        if overdraw_threshold > 0.0 {
+            meshopt::optimize_overdraw_in_place(&mut local, range_vertices, overdraw_threshold);
+        }
+        report
+            .after
+            .add(VertexCacheStats::analyze(&local, range_vertices.len()));
+
+        for (index, local) in range.iter_mut().zip(local) {
+            *index = local + base;
+        }
+    }
+
+    optimize_vertex_fetch(vertices, indices);
+    report
+}
+
+/// Reorder vertices in the order the index buffer first references them.
+fn optimize_vertex_fetch(vertices: &mut Vec<GltfVertex>, indices: &mut [u32]) {
+    let mut remap = vec![std::u32::MAX; vertices.len()];
+    let mut order: Vec<u32> = Vec::with_capacity(vertices.len());
+    for index in indices.iter_mut() {
+        let slot = &mut remap[*index as usize];
+        if *slot == std::u32::MAX {
+            *slot = order.len() as u32;
+            order.push(*index);
+        }
+        *index = *slot;
+    }
+    *vertices = order
+        .iter()
+        .map(|&index| vertices[index as usize])
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_mesh;
+
+    /// Corner positions of every triangle, rotated to start at their smallest
+    /// corner so the winding is kept, in sorted order.
+    fn triangle_set(vertices: &[GltfVertex], indices: &[u32]) -> Vec<[[i32; 2]; 3]> {
+        let mut triangles: Vec<_> = indices
+            .chunks(3)
+            .map(|triangle| {
+                let corners: Vec<[i32; 2]> = triangle
+                    .iter()
+                    .map(|&index| {
+                        let position = vertices[index as usize].position;
+                        [position[0] as i32, position[1] as i32]
+                    })
+                    .collect();
+                let first = (0..3).min_by_key(|&corner| corners[corner]).unwrap();
+                [
+                    corners[first],
+                    corners[(first + 1) % 3],
+                    corners[(first + 2) % 3],
+                ]
+            })
+            .collect();
+        triangles.sort();
+        triangles
+    }
+
+    #[test]
+    fn optimization_keeps_triangles_and_does_not_worsen_acmr() {
+        let (mut vertices, mut indices) = grid_mesh(16);
+        let expected = triangle_set(&vertices, &indices);
+        let half = indices.len() as u32 / 2;
+        let report = optimize_mesh(
+            &mut vertices,
+            &mut indices,
+            &[(0, half), (half, half)],
+            1.05,
+        );
+
+        assert_eq!(triangle_set(&vertices, &indices), expected);
+        assert_eq!(vertices.len(), 17 * 17);
+        assert_eq!(report.before.triangles, report.after.triangles);
+        assert!(report.after.acmr() <= report.before.acmr());
+
+        // Without overdraw optimization as well
+        let (mut vertices, mut indices) = grid_mesh(16);
+        let count = indices.len() as u32;
+        let report = optimize_mesh(&mut vertices, &mut indices, &[(0, count)], 0.0);
+        assert_eq!(triangle_set(&vertices, &indices), expected);
+        assert!(report.after.acmr() <= report.before.acmr());
+    }
+}
@@ -1,14 +1,18 @@
 use crate::calculate_tangents;
 use crate::math::*;
+use crate::weld_vertices;
+use crate::Error;
 use crate::GltfData;
 use crate::GltfIndex;
 use crate::GltfModel;
 use crate::GltfVertex;
 use crate::Result;
+use crate::Topology;
 
 #[derive(Debug)]
 pub struct GltfPrimitive {
     pub mode: gltf::mesh::Mode,
+    pub topology: Topology,
     pub dimensions: Dimensions,
     pub mesh_index: GltfIndex,
     pub primitive_index: GltfIndex,
@@ -68,26 +72,24 @@ impl GltfPrimitive {
 
         // Indices / Faces
 
-        let faces = reader
+        let mode = primitive_ref.mode();
+        let position_count = primitive_ref
+            .get(&gltf::Semantic::Positions)
+            .map_or(0, |accessor| accessor.count());
+        let indices = reader
             .read_indices()
-            .map(|indices| indices.into_u32())
-            .map(|mut indices| {
-                let mut faces = vec![];
-                while let (Some(a), Some(b), Some(c)) =
-                    (indices.next(), indices.next(), indices.next())
-                {
-                    faces.push(a as usize);
-                    faces.push(b as usize);
-                    faces.push(c as usize);
-
-                    model.index_buffer.push(a + vertex_start);
-                    model.index_buffer.push(b + vertex_start);
-                    model.index_buffer.push(c + vertex_start);
-                }
-                faces
-            });
-
-        let index_count = model.index_buffer.len() as u32 - index_start;
+            .map(|indices| indices.into_u32().collect::<Vec<_>>());
+        let (topology, faces) = primitive_elements(mode, indices, position_count);
+        if let Some(index) = faces
+            .iter()
+            .flatten()
+            .find(|&&index| index >= position_count)
+        {
+            return Err(Error::invalid_input(format!(
+                "index {} of mesh {} primitive {} is out of range",
+                index, mesh_index, primitive_index
+            )));
+        }
 
         // Positions
 
@@ -125,19 +127,19 @@ impl GltfPrimitive {
             })
             .unwrap_or_else(|| {
                 use std::iter::once;
-                let f = faces
-                    .as_ref()
-                    .map(|f| f.clone())
-                    .unwrap_or_else(|| (0..positions.len()).collect::<Vec<_>>());
-                f.chunks(3)
+                // Lines and points have no faces to derive normals from
+                if topology != Topology::Triangles {
+                    return vec![[0.0, 0.0, 0.0]; positions.len()];
+                }
+                // Positions are already expanded per face corner
+                positions
+                    .chunks(3)
                     .flat_map(|chunk| {
-                        let a = Vector3::from(positions[chunk[0]]);
-                        let ab = Vector3::from(positions[chunk[1]]) - a;
-                        let ac = Vector3::from(positions[chunk[2]]) - a;
+                        let a = Vector3::from(chunk[0]);
+                        let ab = Vector3::from(chunk[1]) - a;
+                        let ac = Vector3::from(chunk[2]) - a;
                         let normal: [f32; 3] = ab.cross(&ac).into();
-                        once(normal.clone())
-                            .chain(once(normal.clone()))
-                            .chain(once(normal))
+                        once(normal).chain(once(normal)).chain(once(normal))
                     })
                     .collect::<Vec<_>>()
             });
@@ -169,10 +171,17 @@ impl GltfPrimitive {
 
         // TODO: uv1, uv2, uv3, ...
 
-        // Tangents
+        // Tangents, only generated for triangles
 
+        let generate_tangents = |positions: &[[f32; 3]], normals: &[[f32; 3]], uv0: &[[f32; 2]]| {
+            if topology == Topology::Triangles {
+                calculate_tangents(positions, normals, uv0)
+            } else {
+                vec![[0.0, 0.0, 0.0, 1.0]; positions.len()]
+            }
+        };
         let tangents: Vec<[f32; 4]> = if data.options.regenerate_tangents {
-            calculate_tangents(&positions, &normals, &uv0)
+            generate_tangents(&positions, &normals, &uv0)
         } else {
             reader
                 .read_tangents()
@@ -193,7 +202,7 @@ impl GltfPrimitive {
                     }
                     None => tangents.map(|t| [t[0], t[1], t[2], t[3]]).collect(),
                 })
-                .unwrap_or_else(|| calculate_tangents(&positions, &normals, &uv0))
+                .unwrap_or_else(|| generate_tangents(&positions, &normals, &uv0))
         };
 
         // Vertex Colors
@@ -310,32 +319,12 @@ impl GltfPrimitive {
         let bounds = primitive_ref.bounding_box();
         let dimensions = Dimensions::new(bounds.min.into(), bounds.max.into());
 
-        let mode = primitive_ref.mode();
-        match mode {
-            gltf::mesh::Mode::Points => {
-                unimplemented!();
-            }
-            gltf::mesh::Mode::Lines => {
-                unimplemented!();
-            }
-            gltf::mesh::Mode::LineLoop => {
-                unimplemented!();
-            }
-            gltf::mesh::Mode::LineStrip => {
-                unimplemented!();
-            }
-            gltf::mesh::Mode::Triangles => {}
-            gltf::mesh::Mode::TriangleStrip => {
-                unimplemented!();
-            }
-            gltf::mesh::Mode::TriangleFan => {
-                unimplemented!();
-            }
-        }
-
         let material_index = primitive_ref.material().index();
 
+        // Attributes are expanded per face corner, so tangents can be generated
+        // per face. Identical corners are welded back together afterwards.
         let vertex_count = positions.len();
+        let mut vertices = Vec::with_capacity(vertex_count);
         for i in 0..vertex_count {
             let color0: [f32; 4] = if let Some(ref color0) = color0 {
                 let color0: &Vec<[f32; 4]> = &color0;
@@ -421,7 +410,7 @@ impl GltfPrimitive {
             //dbg!(sign_bit);
             //dbg!(bitangent);
 
-            model.vertex_buffer.push(GltfVertex {
+            vertices.push(GltfVertex {
                 position: positions[i],
                 normal,
                 uv0: uv0[i],
@@ -435,14 +424,22 @@ impl GltfPrimitive {
                 weight2,
                 weight3,
                 influence_count,
-                skin_index: -1,
+                skin_index: skin_ref.as_ref().map_or(-1, |skin| skin.index() as i32),
                 bitangent,
                 tangent,
             });
         }
 
+        let (vertices, indices) = weld_vertices(&vertices, data.options.weld_epsilon);
+        model.vertex_buffer.extend(vertices);
+        model
+            .index_buffer
+            .extend(indices.iter().map(|index| index + vertex_start));
+        let index_count = model.index_buffer.len() as u32 - index_start;
+
         Ok(GltfPrimitive {
             mode,
+            topology,
             primitive_index,
             mesh_index,
             material_index,
@@ -466,3 +463,152 @@ impl GltfPrimitive {
         })
     }
 }
+
+/// Vertex index of every triangle, line or point corner of a primitive, with
+/// strips, loops and fans converted to lists. None for non-indexed triangle
+/// lists of whole triangles, whose vertices are used in order.
+fn primitive_elements(
+    mode: gltf::mesh::Mode,
+    indices: Option<Vec<u32>>,
+    vertex_count: usize,
+) -> (Topology, Option<Vec<usize>>) {
+    use gltf::mesh::Mode;
+
+    let topology = match mode {
+        Mode::Triangles | Mode::TriangleStrip | Mode::TriangleFan => Topology::Triangles,
+        Mode::Lines | Mode::LineStrip | Mode::LineLoop => Topology::Lines,
+        Mode::Points => Topology::Points,
+    };
+    let sequence: Vec<usize> = match indices {
+        Some(indices) => indices.into_iter().map(|index| index as usize).collect(),
+        None if mode == Mode::Triangles && vertex_count % 3 == 0 => return (topology, None),
+        None => (0..vertex_count).collect(),
+    };
+
+    let count = sequence.len();
+    let mut elements = Vec::with_capacity(count * topology.primitive_size() as usize);
+    match mode {
+        Mode::Triangles => {
+            elements.extend_from_slice(&sequence[..count - count % 3]);
+        }
+        Mode::TriangleStrip => {
+            // Every other triangle is swapped to keep a consistent winding
+            for (i, triangle) in sequence.windows(3).enumerate() {
+                let (b, c) = if i % 2 == 0 { (1, 2) } else { (2, 1) };
+                push_triangle(&mut elements, triangle[0], triangle[b], triangle[c]);
+            }
+        }
+        Mode::TriangleFan => {
+            if let Some((&first, rest)) = sequence.split_first() {
+                for pair in rest.windows(2) {
+                    push_triangle(&mut elements, pair[0], pair[1], first);
+                }
+            }
+        }
+        Mode::Lines => {
+            elements.extend_from_slice(&sequence[..count - count % 2]);
+        }
+        Mode::LineStrip | Mode::LineLoop => {
+            for pair in sequence.windows(2) {
+                elements.extend_from_slice(pair);
+            }
+            if mode == Mode::LineLoop && count > 2 {
+                elements.push(sequence[count - 1]);
+                elements.push(sequence[0]);
+            }
+        }
+        Mode::Points => elements = sequence,
+    }
+    (topology, Some(elements))
+}
+
+/// Append a triangle unless it is degenerate, as strips use those to restart.
+fn push_triangle(elements: &mut Vec<usize>, a: usize, b: usize, c: usize) {
+    if a != b && b != c && c != a {
+        elements.extend_from_slice(&[a, b, c]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gltf::mesh::Mode;
+
+    fn elements(mode: Mode, indices: &[u32]) -> (Topology, Option<Vec<usize>>) {
+        primitive_elements(mode, Some(indices.to_vec()), 0)
+    }
+
+    #[test]
+    fn lists_drop_incomplete_elements() {
+        assert_eq!(
+            elements(Mode::Triangles, &[0, 1, 2, 2, 1, 3, 4]),
+            (Topology::Triangles, Some(vec![0, 1, 2, 2, 1, 3]))
+        );
+        assert_eq!(
+            elements(Mode::Lines, &[0, 1, 1, 2, 3]),
+            (Topology::Lines, Some(vec![0, 1, 1, 2]))
+        );
+        assert_eq!(
+            elements(Mode::Points, &[3, 1, 2]),
+            (Topology::Points, Some(vec![3, 1, 2]))
+        );
+    }
+
+    #[test]
+    fn strips_and_fans_become_triangle_lists() {
+        // Every other strip triangle is swapped and degenerate ones are skipped
+        assert_eq!(
+            elements(Mode::TriangleStrip, &[0, 1, 2, 3, 3, 4, 5]),
+            (Topology::Triangles, Some(vec![0, 1, 2, 1, 3, 2, 3, 4, 5]))
+        );
+        assert_eq!(
+            elements(Mode::TriangleFan, &[0, 1, 2, 3, 4]),
+            (Topology::Triangles, Some(vec![1, 2, 0, 2, 3, 0, 3, 4, 0]))
+        );
+        assert_eq!(
+            elements(Mode::TriangleFan, &[0, 1]),
+            (Topology::Triangles, Some(vec![]))
+        );
+    }
+
+    #[test]
+    fn line_strips_and_loops_become_line_lists() {
+        assert_eq!(
+            elements(Mode::LineStrip, &[0, 1, 2, 3]),
+            (Topology::Lines, Some(vec![0, 1, 1, 2, 2, 3]))
+        );
+        assert_eq!(
+            elements(Mode::LineLoop, &[0, 1, 2, 3]),
+            (Topology::Lines, Some(vec![0, 1, 1, 2, 2, 3, 3, 0]))
+        );
+        // A loop of two vertices is a single line
+        assert_eq!(
+            elements(Mode::LineLoop, &[4, 5]),
+            (Topology::Lines, Some(vec![4, 5]))
+        );
+    }
+
+    #[test]
+    fn missing_indices_use_vertices_in_order() {
+        assert_eq!(
+            primitive_elements(Mode::Triangles, None, 6),
+            (Topology::Triangles, None)
+        );
+        assert_eq!(
+            primitive_elements(Mode::Triangles, None, 7),
+            (Topology::Triangles, Some(vec![0, 1, 2, 3, 4, 5]))
+        );
+        assert_eq!(
+            primitive_elements(Mode::TriangleStrip, None, 4),
+            (Topology::Triangles, Some(vec![0, 1, 2, 1, 3, 2]))
+        );
+        assert_eq!(
+            primitive_elements(Mode::LineLoop, None, 3),
+            (Topology::Lines, Some(vec![0, 1, 1, 2, 2, 0]))
+        );
+        assert_eq!(
+            primitive_elements(Mode::Points, None, 3),
+            (Topology::Points, Some(vec![0, 1, 2]))
+        );
+    }
+}
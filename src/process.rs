@@ -0,0 +1,1544 @@
+use crate::bake_range;
+use crate::build_meshlets;
+use crate::compress_stream;
+use crate::compute_node_bounds;
+use crate::compute_part_bounds;
+use crate::convert_model;
+use crate::duplicate_range;
+use crate::encode_stream;
+use crate::finish_manifest;
+use crate::generate_lods;
+use crate::generated::service::mesh::schema;
+use crate::index_stream;
+use crate::interleave_streams;
+use crate::mesh_identity;
+use crate::optimize_mesh;
+use crate::resolve_relative;
+use crate::serialized_mesh;
+use crate::split_large_parts;
+use crate::AnimationType;
+use crate::CoordinateConversion;
+use crate::Dimensions;
+use crate::Error;
+use crate::GltfAnimation;
+use crate::GltfAnimationSampler;
+use crate::GltfData;
+use crate::GltfIndex;
+use crate::GltfInterpolationType;
+use crate::GltfMaterial;
+use crate::GltfModel;
+use crate::GltfOptionOverrides;
+use crate::GltfOptions;
+use crate::GltfScene;
+use crate::GltfSkeleton;
+use crate::GltfSkin;
+use crate::GltfSource;
+use crate::GltfTexture;
+use crate::LodLevel;
+use crate::Matrix4;
+use crate::MeshAssetPart;
+use crate::MeshCache;
+use crate::MeshData;
+use crate::MeshLibrary;
+use crate::OptimizationReport;
+use crate::Result;
+use crate::SerializedMesh;
+use crate::SkinningData;
+use crate::StreamAttribute;
+use crate::StreamEncoding;
+use crate::Topology;
+use crate::UriResolver;
+use crate::Vector3;
+use crate::VertexTransform;
+use crate::MAX_BONE_INFLUENCES;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::Path;
+use std::rc::Rc;
+
+/// Load a source asset and pass it to `emit` as a header followed by every
+/// stream of every mesh, split into chunks of at most `chunk_size` bytes.
+/// Processed meshes are never serialized in full: the header only describes
+/// them, and each mesh is released once its streams have been sent. Cached
+/// meshes are streamed from the cache, but streamed meshes are not added to it.
+pub fn stream_mesh<F>(
+    model_path: &Path,
+    options: &GltfOptions,
+    cache: Option<&MeshCache>,
+    chunk_size: usize,
+    mut emit: F,
+) -> Result<()>
+where
+    F: FnMut(MeshChunk) -> Result<()>,
+{
+    let chunk_size = chunk_size.max(1);
+    let source = GltfSource::from_path(model_path, options.clone())?;
+    let name = model_path.to_string_lossy();
+    let scenes = exported_scenes(source.document(), &source.options)?;
+
+    if let Some(cached) = cache.and_then(|cache| cached_meshes(&source, &scenes, &name, cache)) {
+        let header = finish_manifest(cached.iter().map(|mesh| mesh.header.as_slice()));
+        emit(MeshChunk::Header(header))?;
+        for (mesh_index, mesh) in cached.iter().enumerate() {
+            if let Some(streams) = serialized_mesh(&mesh.mesh).streams() {
+                for index in 0..streams.len() {
+                    let data = streams.get(index).data().unwrap_or_default();
+                    emit_stream(&mut emit, mesh_index, index, Cow::from(data), chunk_size)?;
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    let meshes = prepare_meshes(source, &scenes, model_path, &name)?;
+    let headers: Vec<Vec<u8>> = meshes.iter().map(|mesh| mesh_buffer(mesh, false)).collect();
+    emit(MeshChunk::Header(finish_manifest(
+        headers.iter().map(Vec::as_slice),
+    )))?;
+    for (mesh_index, mesh) in meshes.into_iter().enumerate() {
+        for (index, stream) in mesh.streams.into_iter().enumerate() {
+            emit_stream(
+                &mut emit,
+                mesh_index,
+                index,
+                Cow::from(stream.data),
+                chunk_size,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Pass the data of stream `stream` of mesh `mesh` to `emit` in chunks of at
+/// most `chunk_size` bytes. Owned data fitting in a single chunk is handed
+/// over as is.
+fn emit_stream<F>(
+    emit: &mut F,
+    mesh: usize,
+    stream: usize,
+    data: Cow<'_, [u8]>,
+    chunk_size: usize,
+) -> Result<()>
+where
+    F: FnMut(MeshChunk) -> Result<()>,
+{
+    if data.len() <= chunk_size {
+        return emit(MeshChunk::Stream {
+            mesh: mesh as u32,
+            stream: stream as u32,
+            offset: 0,
+            data: data.into_owned(),
+        });
+    }
+    for (chunk_index, chunk) in data.chunks(chunk_size).enumerate() {
+        emit(MeshChunk::Stream {
+            mesh: mesh as u32,
+            stream: stream as u32,
+            offset: (chunk_index * chunk_size) as u64,
+            data: chunk.to_vec(),
+        })?;
+    }
+    Ok(())
+}
+
+/// Raw contents of a single `schema::MeshStream`.
+pub struct StreamData {
+    pub type_: schema::StreamType,
+    pub format: schema::StreamFormat,
+    pub elements: u64,
+    pub data: Vec<u8>,
+    /// Offset and scale decoding quantized values.
+    pub dequantize: Option<(Vector3, Vector3)>,
+    /// Vertex declaration of an interleaved stream.
+    pub attributes: Vec<StreamAttribute>,
+    pub compression: schema::StreamCompression,
+    /// Size of `data` once decompressed.
+    pub decoded_size: u64,
+}
+
+impl StreamData {
+    pub fn new<T: Copy>(
+        type_: schema::StreamType,
+        format: schema::StreamFormat,
+        elements: Vec<T>,
+    ) -> StreamData {
+        let data = unsafe {
+            std::slice::from_raw_parts(
+                elements.as_ptr() as *const u8,
+                elements.len() * std::mem::size_of::<T>(),
+            )
+        };
+        StreamData {
+            type_,
+            format,
+            elements: elements.len() as u64,
+            data: data.to_vec(),
+            dequantize: None,
+            attributes: Vec::new(),
+            compression: schema::StreamCompression::None,
+            decoded_size: data.len() as u64,
+        }
+    }
+}
+
+/// A processed mesh that has not been written to a flatbuffer yet.
+pub struct PreparedMesh {
+    pub name: String,
+    pub identity: String,
+    pub materials: Vec<Rc<GltfMaterial>>,
+    pub textures: Vec<Rc<GltfTexture>>,
+    pub dimensions: Dimensions,
+    pub parts: Vec<MeshAssetPart>,
+    /// Names of the nodes that own parts, with the indices of their parts.
+    pub node_parts: Vec<(String, Vec<u32>)>,
+    /// Bounds of each node of `node_parts`.
+    pub node_bounds: Vec<Dimensions>,
+    pub skeleton: GltfSkeleton,
+    pub animations: Vec<Rc<GltfAnimation>>,
+    /// Joint influences stored per vertex in `skinning_data`.
+    pub skinning_influences: u32,
+    pub skinning_data: Vec<u8>,
+    pub streams: Vec<StreamData>,
+    pub optimization: Option<OptimizationReport>,
+    pub lods: Vec<LodLevel>,
+}
+
+/// Message emitted while streaming a mesh to a client.
+pub enum MeshChunk {
+    /// Finished `schema::Manifest` holding the meshes without stream data.
+    Header(Vec<u8>),
+    /// A slice of stream `stream` of header mesh `mesh`, starting at `offset` bytes.
+    Stream {
+        mesh: u32,
+        stream: u32,
+        offset: u64,
+        data: Vec<u8>,
+    },
+}
+
+/// Scenes exported from a glTF document, with the suffix appended to the mesh
+/// name of each: every scene when `split_scenes` is set, otherwise only
+/// `scene_index` or the default scene.
+fn exported_scenes(
+    document: &gltf::Document,
+    options: &GltfOptions,
+) -> Result<Vec<(usize, Option<String>)>> {
+    let scene_count = document.scenes().len();
+    if options.split_scenes {
+        if scene_count == 0 {
+            return Err(Error::scene_index(0, scene_count));
+        }
+        Ok(document
+            .scenes()
+            .map(|scene_ref| {
+                let scene_name = scene_ref
+                    .name()
+                    .map(|scene_name| scene_name.to_owned())
+                    .unwrap_or_else(|| scene_ref.index().to_string());
+                (scene_ref.index(), Some(scene_name))
+            })
+            .collect())
+    } else {
+        let scene_index = options
+            .scene_index
+            .or_else(|| document.default_scene().map(|scene| scene.index()))
+            .unwrap_or(0);
+        Ok(vec![(scene_index, None)])
+    }
+}
+
+fn scene_mesh_name(name: &str, scene_name: &Option<String>) -> String {
+    match scene_name {
+        Some(scene_name) => format!("{}:{}", name, scene_name),
+        None => name.to_string(),
+    }
+}
+
+/// Process a glTF document into a mesh named `name`, or into one mesh per
+/// scene named `name:<scene>` when `split_scenes` is set. The meshes are served
+/// from `cache` when every one of them is cached, otherwise they are all
+/// processed and added to it.
+pub fn output_meshes(
+    source: GltfSource,
+    model_path: &Path,
+    name: &str,
+    cache: Option<&MeshCache>,
+) -> Result<Vec<SerializedMesh>> {
+    let scenes = exported_scenes(source.document(), &source.options)?;
+    if let Some(cached) = cache.and_then(|cache| cached_meshes(&source, &scenes, name, cache)) {
+        return Ok(cached);
+    }
+
+    let meshes = prepare_meshes(source, &scenes, model_path, name)?;
+    Ok(meshes
+        .iter()
+        .map(|mesh| {
+            let serialized = SerializedMesh {
+                header: mesh_buffer(mesh, false),
+                mesh: mesh_buffer(mesh, true),
+            };
+            if let Some(cache) = cache {
+                if let Err(err) = cache.insert(&mesh.identity, &serialized) {
+                    log::warn!("Failed to cache '{}': {}", mesh.name, err);
+                }
+            }
+            serialized
+        })
+        .collect())
+}
+
+/// The meshes of `scenes` served from `cache` under their output names, None
+/// unless every one of them is cached. Counts them as hits or misses.
+fn cached_meshes(
+    source: &GltfSource,
+    scenes: &[(usize, Option<String>)],
+    name: &str,
+    cache: &MeshCache,
+) -> Option<Vec<SerializedMesh>> {
+    let cached: Option<Vec<SerializedMesh>> = scenes
+        .iter()
+        .map(|(scene_index, scene_name)| {
+            let identity = mesh_identity(&source.source_digest, &source.options, *scene_index);
+            cache
+                .get(&identity)?
+                .renamed(&scene_mesh_name(name, scene_name))
+        })
+        .collect();
+    match cached {
+        Some(ref cached) => cache.record_hits(cached.len()),
+        None => cache.record_misses(scenes.len()),
+    }
+    cached
+}
+
+/// Import a glTF document and process each of `scenes` into a mesh.
+fn prepare_meshes(
+    source: GltfSource,
+    scenes: &[(usize, Option<String>)],
+    model_path: &Path,
+    name: &str,
+) -> Result<Vec<PreparedMesh>> {
+    let data = source.import()?;
+    scenes
+        .iter()
+        .map(|(scene_index, scene_name)| {
+            let name = scene_mesh_name(name, scene_name);
+            prepare_scene(&data, model_path, name, *scene_index)
+        })
+        .collect()
+}
+
+/// A prepared mesh as a finished `schema::Mesh` flatbuffer, with or without
+/// its stream data.
+fn mesh_buffer(mesh: &PreparedMesh, with_data: bool) -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let offset = write_mesh(&mut builder, mesh, with_data);
+    builder.finish(offset, None);
+    builder.finished_data().to_vec()
+}
+
+/// Process the nodes of a single scene into streams, materials and bounds.
+fn prepare_scene(
+    data: &GltfData,
+    model_path: &Path,
+    name: String,
+    scene_index: usize,
+) -> Result<PreparedMesh> {
+    let scene_count = data.document.scenes().len();
+    let gltf_scene = data
+        .document
+        .scenes()
+        .nth(scene_index)
+        .ok_or_else(|| Error::scene_index(scene_index, scene_count))?;
+    let mut model = GltfModel::from_gltf(data, model_path, &gltf_scene)?;
+    let mut scene = GltfScene::from_gltf(&gltf_scene, &mut model)?;
+
+    let conversion = CoordinateConversion::new(&data.options);
+    convert_model(&mut model, &conversion)?;
+    scene.dimensions = conversion.dimensions(&scene.dimensions);
+    log::debug!("Scene Dimensions: {:?}", scene.dimensions);
+
+    let has_animations = !model.animations.is_empty();
+    // Skinned meshes keep their skeleton and bind pose, with or without clips
+    let has_skinning = !model.skeleton.joints.is_empty()
+        && model.linear_nodes.iter().any(|node| {
+            let node = node.borrow();
+            node.mesh.is_some() && node.skin_index.is_some()
+        });
+    let animated = has_animations || has_skinning;
+    let instanced = !animated && data.options.instancing;
+    let flatten_transforms = !animated && !instanced;
+
+    // Index ranges baked by flattening, with the transform of their node.
+    // Nodes sharing a mesh bake copies of its triangles.
+    let mut baked_ranges: Vec<(u32, u32, Topology, Matrix4)> = Vec::new();
+    let mut claimed_ranges: HashSet<u32> = HashSet::new();
+
+    // Part emitted for each instanced index range, by index start
+    let mut instanced_parts: HashMap<u32, usize> = HashMap::new();
+
+    // Map from name of node to indices of all parts owned by that node in the global parts array
+    let mut part_map: Vec<(String, Vec<u32>)> = Vec::new();
+    // Transform from the vertices of each node of the part map to the mesh
+    let mut node_transforms: Vec<Matrix4> = Vec::new();
+
+    let mut parts: Vec<MeshAssetPart> = Vec::with_capacity(model.linear_nodes.len());
+    for linear_node in &model.linear_nodes {
+        let linear_node = linear_node.borrow();
+        if let Some(ref mesh) = linear_node.mesh {
+            let matrix = linear_node.get_matrix();
+
+            let mut part = MeshAssetPart {
+                index_start: 0,
+                index_count: 0,
+                material_index: None,
+                node_index: Some(linear_node.node_index as u32),
+                base_transform: if flatten_transforms || instanced {
+                    Matrix4::identity()
+                } else {
+                    matrix
+                },
+                animation_type: AnimationType::None,
+                name: linear_node.name.clone(),
+                meshlet_start: 0,
+                meshlet_count: 0,
+                base_vertex: 0,
+                instance_transforms: Vec::new(),
+                bounds: Dimensions::default(),
+                topology: Topology::Triangles,
+            };
+
+            let mut part_indices: Vec<u32> = Vec::new();
+            for primitive in &mesh.primitives {
+                part.index_start = primitive.index_start;
+                part.index_count = primitive.index_count;
+                part.topology = primitive.topology;
+                part.material_index = if let Some(index) = primitive.material_index {
+                    Some(index as u32)
+                } else {
+                    None
+                };
+                part.animation_type = if has_skinning && linear_node.skin_index.is_some() {
+                    AnimationType::Skinned
+                } else if has_animations {
+                    AnimationType::Rigid
+                } else {
+                    AnimationType::None
+                };
+
+                if instanced {
+                    if let Some(&shared) = instanced_parts.get(&part.index_start) {
+                        parts[shared].instance_transforms.push(matrix);
+                        part_indices.push(shared as u32);
+                        continue;
+                    }
+                    instanced_parts.insert(part.index_start, parts.len());
+                    part.instance_transforms = vec![matrix];
+                }
+
+                if flatten_transforms {
+                    if !claimed_ranges.insert(part.index_start) {
+                        part.index_start = duplicate_range(
+                            &mut model.vertex_buffer,
+                            &mut model.index_buffer,
+                            part.index_start,
+                            part.index_count,
+                        );
+                    }
+                    baked_ranges.push((part.index_start, part.index_count, part.topology, matrix));
+                }
+
+                parts.push(part.clone());
+                part_indices.push((parts.len() - 1) as u32);
+            }
+
+            // Every node is named when the model is loaded
+            let name = linear_node.name.clone().ok_or_else(|| {
+                Error::bug(format!("node {} has no name", linear_node.node_index))
+            })?;
+
+            part_map.push((name, part_indices));
+            node_transforms.push(if flatten_transforms {
+                Matrix4::identity()
+            } else {
+                matrix
+            });
+        }
+    }
+
+    // Bake once every shared range has been copied from its untransformed source
+    for (index_start, index_count, topology, matrix) in baked_ranges {
+        let start = index_start as usize;
+        bake_range(
+            &mut model.vertex_buffer,
+            &mut model.index_buffer[start..start + index_count as usize],
+            topology,
+            &VertexTransform::new(&matrix),
+        );
+    }
+    if instanced {
+        log::debug!(
+            "Instancing: {} parts, {} instances",
+            parts.len(),
+            parts
+                .iter()
+                .map(|part| part.instance_transforms.len())
+                .sum::<usize>()
+        );
+    }
+
+    let optimization = if data.options.optimize {
+        // Lines and points keep their order
+        let ranges: Vec<(u32, u32)> = parts
+            .iter()
+            .filter(|part| part.topology == Topology::Triangles)
+            .map(|part| (part.index_start, part.index_count))
+            .collect();
+        let report = optimize_mesh(
+            &mut model.vertex_buffer,
+            &mut model.index_buffer,
+            &ranges,
+            data.options.overdraw_threshold,
+        );
+        log::debug!(
+            "Vertex Cache: ACMR {:.3} -> {:.3}, ATVR {:.3} -> {:.3}",
+            report.before.acmr(),
+            report.after.acmr(),
+            report.before.atvr(),
+            report.after.atvr()
+        );
+        Some(report)
+    } else {
+        None
+    };
+
+    if data.options.split_large_parts {
+        split_large_parts(&model.index_buffer, &mut parts, &mut part_map);
+    }
+
+    let lods = generate_lods(
+        &model.vertex_buffer,
+        &mut model.index_buffer,
+        &parts,
+        &data.options,
+    );
+
+    let meshlets = if data.options.build_meshlets {
+        let meshlets = build_meshlets(
+            &model.vertex_buffer,
+            &model.index_buffer,
+            &parts,
+            data.options.meshlet_max_vertices,
+            data.options.meshlet_max_triangles,
+        )?;
+        for (part, &(meshlet_start, meshlet_count)) in parts.iter_mut().zip(&meshlets.part_ranges) {
+            part.meshlet_start = meshlet_start;
+            part.meshlet_count = meshlet_count;
+        }
+        Some(meshlets)
+    } else {
+        None
+    };
+
+    compute_part_bounds(&model.vertex_buffer, &model.index_buffer, &mut parts);
+    let (node_bounds, dimensions) = compute_node_bounds(
+        &model.vertex_buffer,
+        &model.index_buffer,
+        &parts,
+        &part_map,
+        &node_transforms,
+    );
+    log::debug!("Mesh Dimensions: {:?}", dimensions);
+
+    let mut mesh_data = MeshData::default();
+
+    mesh_data.indices = model.index_buffer.clone();
+
+    mesh_data.positions.reserve(model.vertex_buffer.len());
+    mesh_data.tex_coords.reserve(model.vertex_buffer.len());
+    mesh_data.normals.reserve(model.vertex_buffer.len());
+    mesh_data.tangents.reserve(model.vertex_buffer.len());
+    mesh_data.bitangents.reserve(model.vertex_buffer.len());
+    mesh_data.colors.reserve(model.vertex_buffer.len());
+    mesh_data.indices.reserve(model.index_buffer.len());
+    if has_skinning {
+        mesh_data.skinning_data.reserve(model.vertex_buffer.len());
+    }
+
+    for i in 0..model.vertex_buffer.len() {
+        let vertex = &model.vertex_buffer[i];
+        mesh_data.positions.push(vertex.position);
+        mesh_data.tex_coords.push(vertex.uv0);
+        mesh_data.normals.push(vertex.normal);
+        mesh_data.tangents.push(vertex.tangent);
+        mesh_data.bitangents.push(vertex.bitangent);
+        mesh_data.colors.push(vertex.color0);
+
+        if has_skinning {
+            let mut skinning_data = SkinningData {
+                bone_count: vertex.influence_count,
+                weights: [0.0; MAX_BONE_INFLUENCES],
+                bone_ids: [0; MAX_BONE_INFLUENCES],
+            };
+
+            let mut sum = 0.0;
+
+            // Largest index with a non zero weight
+            let mut max_non_zero = None;
+
+            for bone in 0..skinning_data.bone_count as usize {
+                if bone < 4 {
+                    skinning_data.weights[bone] = vertex.weight0[bone];
+                    skinning_data.bone_ids[bone] = vertex.joint0[bone] as u32;
+                } else if bone < 8 {
+                    skinning_data.weights[bone] = vertex.weight1[bone - 4];
+                    skinning_data.bone_ids[bone] = vertex.joint1[bone - 4] as u32;
+                } else if bone < 12 {
+                    skinning_data.weights[bone] = vertex.weight2[bone - 8];
+                    skinning_data.bone_ids[bone] = vertex.joint2[bone - 8] as u32;
+                } else {
+                    skinning_data.weights[bone] = vertex.weight3[bone - 12];
+                    skinning_data.bone_ids[bone] = vertex.joint3[bone - 12] as u32;
+                }
+
+                // Joint indices are local to the skin of the vertex
+                skinning_data.bone_ids[bone] = skeleton_joint(
+                    &model.skins,
+                    vertex.skin_index,
+                    skinning_data.bone_ids[bone] as usize,
+                );
+
+                if skinning_data.weights[bone] > 0.0 {
+                    max_non_zero = Some(bone);
+                }
+
+                sum += skinning_data.weights[bone];
+            }
+
+            skinning_data.bone_count = max_non_zero.map_or(0, |bone| bone as u32 + 1);
+
+            // Re-balance the weights
+            if sum > 0.0 {
+                for bone in 0..skinning_data.bone_count as usize {
+                    skinning_data.weights[bone] /= sum;
+                }
+            }
+
+            mesh_data.skinning_data.push(skinning_data);
+        }
+    }
+
+    // Export bone offsets and per vertex skinning data
+    let (skinning_influences, skinning_data) = if has_skinning {
+        pack_skinning(&mesh_data.skinning_data)
+    } else {
+        (0, Vec::new())
+    };
+
+    if mesh_data.positions.is_empty() || mesh_data.indices.is_empty() {
+        return Err(Error::memory("no vertices found"));
+    }
+
+    // Setup streams
+    let quantization = &data.options.quantization;
+    let mut streams = vec![
+        encode_stream(
+            schema::StreamType::Positions,
+            &mesh_data.positions,
+            quantization.positions.unwrap_or(StreamEncoding::Float),
+        )?,
+        encode_stream(
+            schema::StreamType::Normals,
+            &mesh_data.normals,
+            quantization.normals.unwrap_or(StreamEncoding::Float),
+        )?,
+        encode_stream(
+            schema::StreamType::Tangents,
+            &mesh_data.tangents,
+            quantization.tangents.unwrap_or(StreamEncoding::Float),
+        )?,
+        encode_stream(
+            schema::StreamType::Bitangents,
+            &mesh_data.bitangents,
+            quantization.bitangents.unwrap_or(StreamEncoding::Float),
+        )?,
+        encode_stream(
+            schema::StreamType::TextureCoordinates,
+            &mesh_data.tex_coords,
+            quantization.tex_coords.unwrap_or(StreamEncoding::Float),
+        )?,
+        encode_stream(
+            schema::StreamType::Colors,
+            &mesh_data.colors,
+            quantization.colors.unwrap_or(StreamEncoding::Float),
+        )?,
+        index_stream(&mesh_data.indices, &mut parts, &lods),
+    ];
+    if let Some(meshlets) = meshlets {
+        streams.push(StreamData::new(
+            schema::StreamType::Meshlets,
+            schema::StreamFormat::Meshlet,
+            meshlets.meshlets,
+        ));
+        streams.push(StreamData::new(
+            schema::StreamType::MeshletVertices,
+            schema::StreamFormat::Int,
+            meshlets.vertices,
+        ));
+        streams.push(StreamData::new(
+            schema::StreamType::MeshletTriangles,
+            schema::StreamFormat::Int,
+            meshlets.triangles,
+        ));
+    }
+    if data.options.interleave {
+        streams = interleave_streams(streams, &data.options.interleaved_attributes);
+    }
+    if data.options.compress_streams {
+        let decoded_size: u64 = streams.iter().map(|stream| stream.decoded_size).sum();
+        let triangle_list = parts
+            .iter()
+            .all(|part| part.topology == Topology::Triangles);
+        for stream in &mut streams {
+            compress_stream(stream, triangle_list)?;
+        }
+        let size: usize = streams.iter().map(|stream| stream.data.len()).sum();
+        log::debug!("Compressed streams: {} -> {} bytes", decoded_size, size);
+    }
+
+    Ok(PreparedMesh {
+        name,
+        identity: mesh_identity(&data.source_digest, &data.options, scene_index),
+        materials: model.materials,
+        textures: model.textures,
+        dimensions,
+        parts,
+        node_parts: part_map,
+        node_bounds,
+        skeleton: if has_skinning {
+            model.skeleton
+        } else {
+            GltfSkeleton::default()
+        },
+        animations: model.animations,
+        skinning_influences,
+        skinning_data,
+        streams,
+        optimization,
+        lods,
+    })
+}
+
+/// Write the keyframes of an animation channel sampler.
+fn write_sampler<'a>(
+    builder: &mut flatbuffers::FlatBufferBuilder<'a>,
+    sampler: &Option<GltfAnimationSampler>,
+) -> Option<flatbuffers::WIPOffset<schema::AnimationSampler<'a>>> {
+    let sampler = sampler.as_ref()?;
+    let times = Some(builder.create_vector(&sampler.inputs));
+    let values: Vec<f32> = sampler
+        .outputs
+        .iter()
+        .flat_map(|value| value.as_slice().to_vec())
+        .collect();
+    let values = Some(builder.create_vector(&values));
+    Some(schema::AnimationSampler::create(
+        builder,
+        &schema::AnimationSamplerArgs {
+            interpolation: match sampler.interpolation_type {
+                GltfInterpolationType::Linear => schema::Interpolation::Linear,
+                GltfInterpolationType::Step => schema::Interpolation::Step,
+                GltfInterpolationType::CatmullRomSpline => schema::Interpolation::CatmullRomSpline,
+                GltfInterpolationType::CubicSpline => schema::Interpolation::CubicSpline,
+            },
+            times,
+            values,
+        },
+    ))
+}
+
+/// Map a joint index local to the skin of a vertex to its index in the merged skeleton.
+fn skeleton_joint(skins: &[Rc<GltfSkin>], skin_index: i32, joint: usize) -> u32 {
+    skins
+        .iter()
+        .find(|skin| skin.skin_index as i32 == skin_index)
+        .and_then(|skin| skin.joints.get(joint))
+        .and_then(|joint| joint.global_index)
+        .unwrap_or(0) as u32
+}
+
+/// Pack per vertex skinning as `influences` little endian u16 joint indices
+/// followed by `influences` f32 weights, with `influences` the largest bone
+/// count rounded up to a multiple of 4.
+fn pack_skinning(skinning_data: &[SkinningData]) -> (u32, Vec<u8>) {
+    let max_bone_count = skinning_data
+        .iter()
+        .map(|skinning| skinning.bone_count as usize)
+        .max()
+        .unwrap_or(0);
+    let influences = ((max_bone_count.max(1) + 3) / 4 * 4).min(MAX_BONE_INFLUENCES);
+
+    let mut data = Vec::with_capacity(skinning_data.len() * influences * 6);
+    for skinning in skinning_data {
+        for bone in 0..influences {
+            data.extend_from_slice(&(skinning.bone_ids[bone] as u16).to_le_bytes());
+        }
+        for bone in 0..influences {
+            let weight = if bone < skinning.bone_count as usize {
+                skinning.weights[bone]
+            } else {
+                0.0
+            };
+            data.extend_from_slice(&weight.to_bits().to_le_bytes());
+        }
+    }
+
+    (influences as u32, data)
+}
+
+/// Write the offset and scale decoding a quantized stream or attribute.
+fn write_dequantize<'a>(
+    builder: &mut flatbuffers::FlatBufferBuilder<'a>,
+    dequantize: Option<(Vector3, Vector3)>,
+) -> (
+    Option<flatbuffers::WIPOffset<schema::Vector3<'a>>>,
+    Option<flatbuffers::WIPOffset<schema::Vector3<'a>>>,
+) {
+    let mut write_vector = |vector: Vector3| {
+        schema::Vector3::create(
+            builder,
+            &schema::Vector3Args {
+                x: vector.x,
+                y: vector.y,
+                z: vector.z,
+            },
+        )
+    };
+    match dequantize {
+        Some((offset, scale)) => (Some(write_vector(offset)), Some(write_vector(scale))),
+        None => (None, None),
+    }
+}
+
+/// Write a box and sphere, None when the bounds are empty.
+fn write_bounds<'a>(
+    builder: &mut flatbuffers::FlatBufferBuilder<'a>,
+    bounds: &Dimensions,
+) -> Option<flatbuffers::WIPOffset<schema::Bounds<'a>>> {
+    if bounds.is_empty() {
+        return None;
+    }
+    let mut write_vector = |vector: Vector3| {
+        Some(schema::Vector3::create(
+            builder,
+            &schema::Vector3Args {
+                x: vector.x,
+                y: vector.y,
+                z: vector.z,
+            },
+        ))
+    };
+    let min = write_vector(bounds.min);
+    let max = write_vector(bounds.max);
+    let sphere_center = write_vector(bounds.center);
+    Some(schema::Bounds::create(
+        builder,
+        &schema::BoundsArgs {
+            min,
+            max,
+            sphere_center,
+            sphere_radius: bounds.radius,
+        },
+    ))
+}
+
+/// Write a prepared mesh into `builder`. When `with_data` is false the streams
+/// only describe their type, format and element count.
+fn write_mesh<'a>(
+    mut builder: &mut flatbuffers::FlatBufferBuilder<'a>,
+    mesh: &PreparedMesh,
+    with_data: bool,
+) -> flatbuffers::WIPOffset<schema::Mesh<'a>> {
+    // Setup streams
+    let mut streams: Vec<_> = Vec::with_capacity(mesh.streams.len());
+    for stream in &mesh.streams {
+        let data = if with_data {
+            Some(builder.create_vector_direct(&stream.data))
+        } else {
+            None
+        };
+        let (offset, scale) = write_dequantize(builder, stream.dequantize);
+        let attributes = if stream.attributes.is_empty() {
+            None
+        } else {
+            let mut attributes: Vec<_> = Vec::with_capacity(stream.attributes.len());
+            for attribute in &stream.attributes {
+                let (decode_offset, decode_scale) = write_dequantize(builder, attribute.dequantize);
+                attributes.push(schema::VertexAttribute::create(
+                    &mut builder,
+                    &schema::VertexAttributeArgs {
+                        semantic: attribute.semantic,
+                        format: attribute.format,
+                        offset: attribute.offset,
+                        stride: attribute.stride,
+                        decode_offset,
+                        decode_scale,
+                    },
+                ));
+            }
+            Some(builder.create_vector(&attributes))
+        };
+        streams.push(schema::MeshStream::create(
+            &mut builder,
+            &schema::MeshStreamArgs {
+                format: stream.format,
+                type_: stream.type_,
+                elements: stream.elements,
+                data,
+                offset,
+                scale,
+                attributes,
+                compression: stream.compression,
+                decoded_size: stream.decoded_size,
+            },
+        ));
+    }
+    let streams = Some(builder.create_vector(&streams));
+
+    // Setup materials
+    let mut materials: Vec<_> = Vec::new();
+    for material in &mesh.materials {
+        let name = if let Some(ref name) = material.name {
+            name.to_owned()
+        } else {
+            "".to_string()
+        };
+        let name = Some(builder.create_string(&name));
+        let uri = Some(builder.create_string(&material.material_uri));
+        let albedo_tint = Some(schema::Vector3::create(
+            &mut builder,
+            &schema::Vector3Args {
+                x: material.base_color_factor[0],
+                y: material.base_color_factor[1],
+                z: material.base_color_factor[2],
+            },
+        ));
+        let emissive = Some(schema::Vector3::create(
+            &mut builder,
+            &schema::Vector3Args {
+                x: material.emissive_factor[0],
+                y: material.emissive_factor[1],
+                z: material.emissive_factor[2],
+            },
+        ));
+        let base_color_texture = write_texture(builder, mesh, material.base_color_texture);
+        let metallic_roughness_texture =
+            write_texture(builder, mesh, material.metallic_roughness_texture);
+        let normal_texture = write_texture(builder, mesh, material.normal_texture);
+        let occlusion_texture = write_texture(builder, mesh, material.occlusion_texture);
+        let emissive_texture = write_texture(builder, mesh, material.emissive_texture);
+        materials.push(schema::MeshMaterial::create(
+            &mut builder,
+            &schema::MeshMaterialArgs {
+                name,
+                material: uri,
+                albedo_tint,
+                roughness: material.roughness_factor,
+                alpha: material.base_color_factor[3],
+                metallic: material.metallic_factor,
+                emissive,
+                normal_scale: material.normal_scale.unwrap_or(1.0),
+                occlusion_strength: material.occlusion_strength.unwrap_or(1.0),
+                alpha_mode: match material.alpha_mode {
+                    gltf::material::AlphaMode::Opaque => schema::AlphaMode::Opaque,
+                    gltf::material::AlphaMode::Mask => schema::AlphaMode::Mask,
+                    gltf::material::AlphaMode::Blend => schema::AlphaMode::Blend,
+                },
+                alpha_cutoff: material.alpha_cutoff,
+                double_sided: material.double_sided,
+                base_color_texture,
+                metallic_roughness_texture,
+                normal_texture,
+                occlusion_texture,
+                emissive_texture,
+            },
+        ));
+    }
+    let materials = Some(builder.create_vector(&materials));
+
+    // Setup parts
+    let mut parts: Vec<_> = Vec::with_capacity(mesh.parts.len());
+    for part in &mesh.parts {
+        let base_transform = Some(builder.create_vector(part.base_transform.as_slice()));
+        let instance_transforms = if part.instance_transforms.is_empty() {
+            None
+        } else {
+            let floats: Vec<f32> = part
+                .instance_transforms
+                .iter()
+                .flat_map(|transform| transform.as_slice().to_vec())
+                .collect();
+            Some(builder.create_vector(&floats))
+        };
+        let name = part.name.as_ref().map(|name| builder.create_string(name));
+        let bounds = write_bounds(builder, &part.bounds);
+        parts.push(schema::MeshPart::create(
+            &mut builder,
+            &schema::MeshPartArgs {
+                index_start: part.index_start,
+                index_count: part.index_count,
+                material_index: part.material_index.map_or(-1, |index| index as i32),
+                node_index: part.node_index.map_or(-1, |index| index as i32),
+                base_transform,
+                name,
+                animation_type: match part.animation_type {
+                    AnimationType::Rigid => schema::AnimationType::Rigid,
+                    AnimationType::Skinned => schema::AnimationType::Skinned,
+                    AnimationType::None | AnimationType::Mesh => schema::AnimationType::None,
+                },
+                meshlet_start: part.meshlet_start,
+                meshlet_count: part.meshlet_count,
+                base_vertex: part.base_vertex,
+                instance_transforms,
+                bounds,
+                topology: match part.topology {
+                    Topology::Triangles => schema::Topology::Triangles,
+                    Topology::Lines => schema::Topology::Lines,
+                    Topology::Points => schema::Topology::Points,
+                },
+            },
+        ));
+    }
+    let parts = Some(builder.create_vector(&parts));
+
+    let mut node_parts: Vec<_> = Vec::with_capacity(mesh.node_parts.len());
+    for ((node_name, part_indices), bounds) in mesh.node_parts.iter().zip(&mesh.node_bounds) {
+        let name = Some(builder.create_string(node_name));
+        let parts = Some(builder.create_vector(part_indices));
+        let bounds = write_bounds(builder, bounds);
+        node_parts.push(schema::NodeParts::create(
+            &mut builder,
+            &schema::NodePartsArgs {
+                name,
+                parts,
+                bounds,
+            },
+        ));
+    }
+    let node_parts = Some(builder.create_vector(&node_parts));
+
+    // Setup skeleton
+    let skeleton = if mesh.skeleton.joints.is_empty() {
+        None
+    } else {
+        let joint_names: Vec<_> = mesh
+            .skeleton
+            .joints
+            .iter()
+            .map(|joint| builder.create_string(&joint.name))
+            .collect();
+        let joint_names = Some(builder.create_vector(&joint_names));
+        let joint_parents: Vec<i32> = mesh
+            .skeleton
+            .joints
+            .iter()
+            .map(|joint| joint.parent.map_or(-1, |parent| parent as i32))
+            .collect();
+        let joint_parents = Some(builder.create_vector(&joint_parents));
+        let inverse_bind_matrices: Vec<f32> = mesh
+            .skeleton
+            .joints
+            .iter()
+            .flat_map(|joint| joint.inverse_bind_matrix.as_slice().to_vec())
+            .collect();
+        let inverse_bind_matrices = Some(builder.create_vector(&inverse_bind_matrices));
+        Some(schema::Skeleton::create(
+            &mut builder,
+            &schema::SkeletonArgs {
+                joint_names,
+                joint_parents,
+                inverse_bind_matrices,
+                root: mesh.skeleton.root.map_or(-1, |root| root as i32),
+            },
+        ))
+    };
+
+    // Setup animations
+    let mut animations: Vec<_> = Vec::with_capacity(mesh.animations.len());
+    for animation in &mesh.animations {
+        let mut channels: Vec<_> = Vec::with_capacity(animation.channels.len());
+        for channel in &animation.channels {
+            let node = channel.node_ref.borrow();
+            let node_name = node
+                .name
+                .clone()
+                .unwrap_or_else(|| format!("N_{}", node.node_index));
+            let node_name = Some(builder.create_string(&node_name));
+            let translation = write_sampler(builder, &channel.translation_sampler);
+            let rotation = write_sampler(builder, &channel.rotation_sampler);
+            let scale = write_sampler(builder, &channel.scale_sampler);
+            channels.push(schema::AnimationChannel::create(
+                &mut builder,
+                &schema::AnimationChannelArgs {
+                    node: node_name,
+                    joint: node.joint_index.map_or(-1, |joint| joint as i32),
+                    translation,
+                    rotation,
+                    scale,
+                },
+            ));
+        }
+        let channels = Some(builder.create_vector(&channels));
+        let name = animation
+            .name
+            .clone()
+            .unwrap_or_else(|| format!("animation_{}", animation.index));
+        let name = Some(builder.create_string(&name));
+        let (start, duration) = if animation.end >= animation.start {
+            (animation.start, animation.end - animation.start)
+        } else {
+            (0.0, 0.0)
+        };
+        animations.push(schema::Animation::create(
+            &mut builder,
+            &schema::AnimationArgs {
+                name,
+                start,
+                duration,
+                channels,
+            },
+        ));
+    }
+    let animations = Some(builder.create_vector(&animations));
+
+    let skinning_data = if mesh.skinning_data.is_empty() {
+        None
+    } else {
+        Some(builder.create_vector_direct(&mesh.skinning_data))
+    };
+
+    // Calculate bounding box
+    let bounding_min = Some(schema::Vector3::create(
+        &mut builder,
+        &schema::Vector3Args {
+            x: mesh.dimensions.min[0],
+            y: mesh.dimensions.min[1],
+            z: mesh.dimensions.min[2],
+        },
+    ));
+    let bounding_max = Some(schema::Vector3::create(
+        &mut builder,
+        &schema::Vector3Args {
+            x: mesh.dimensions.max[0],
+            y: mesh.dimensions.max[1],
+            z: mesh.dimensions.max[2],
+        },
+    ));
+    let bounds = write_bounds(builder, &mesh.dimensions);
+    let optimization = mesh.optimization.map(|report| {
+        schema::OptimizationStats::create(
+            builder,
+            &schema::OptimizationStatsArgs {
+                acmr_before: report.before.acmr(),
+                atvr_before: report.before.atvr(),
+                acmr_after: report.after.acmr(),
+                atvr_after: report.after.atvr(),
+            },
+        )
+    });
+    // Setup levels of detail
+    let mut lods: Vec<_> = Vec::with_capacity(mesh.lods.len());
+    for lod in &mesh.lods {
+        let mut lod_parts: Vec<_> = Vec::with_capacity(lod.parts.len());
+        for part in &lod.parts {
+            lod_parts.push(schema::MeshLodPart::create(
+                &mut builder,
+                &schema::MeshLodPartArgs {
+                    index_start: part.index_start,
+                    index_count: part.index_count,
+                    error: part.error,
+                },
+            ));
+        }
+        let parts = Some(builder.create_vector(&lod_parts));
+        lods.push(schema::MeshLod::create(
+            &mut builder,
+            &schema::MeshLodArgs {
+                ratio: lod.ratio,
+                max_error: lod.max_error,
+                error: lod.error,
+                parts,
+            },
+        ));
+    }
+    let lods = Some(builder.create_vector(&lods));
+
+    let name = Some(builder.create_string(&mesh.name));
+    let identity = Some(builder.create_string(&mesh.identity));
+    schema::Mesh::create(
+        &mut builder,
+        &schema::MeshArgs {
+            name,
+            identity,
+            streams,
+            materials,
+            animations,
+            parts,
+            skinning_data,
+            bounding_min,
+            bounding_max,
+            node_parts,
+            skeleton,
+            skinning_influences: mesh.skinning_influences,
+            optimization,
+            lods,
+            bounds,
+        },
+    )
+}
+
+/// Write a material texture slot, referencing the texture by its image name.
+fn write_texture<'a>(
+    builder: &mut flatbuffers::FlatBufferBuilder<'a>,
+    mesh: &PreparedMesh,
+    texture: Option<(GltfIndex, u32)>,
+) -> Option<flatbuffers::WIPOffset<schema::MaterialTexture<'a>>> {
+    let (texture_index, tex_coord) = texture?;
+    let texture = mesh
+        .textures
+        .iter()
+        .find(|texture| texture.index == texture_index)?;
+    let image = Some(builder.create_string(&texture.image_name));
+    Some(schema::MaterialTexture::create(
+        builder,
+        &schema::MaterialTextureArgs { image, tex_coord },
+    ))
+}
+
+/// Process a single asset into a finished `schema::Manifest` flatbuffer.
+pub fn process_mesh(
+    model_path: &Path,
+    options: &GltfOptions,
+    cache: Option<&MeshCache>,
+) -> Result<Vec<u8>> {
+    let source = GltfSource::from_path(model_path, options.clone())?;
+    let meshes = output_meshes(source, model_path, &model_path.to_string_lossy(), cache)?;
+    Ok(finish_manifest(
+        meshes.iter().map(|mesh| mesh.mesh.as_slice()),
+    ))
+}
+
+/// Process an asset uploaded in memory, with its external buffers and images keyed by URI.
+pub fn process_upload(
+    name: &str,
+    asset: &[u8],
+    resources: &HashMap<String, Vec<u8>>,
+    options: &GltfOptions,
+    cache: Option<&MeshCache>,
+) -> Result<Vec<u8>> {
+    let source = GltfSource::from_slice(asset, &UriResolver::Memory(resources), options.clone())?;
+    let meshes = output_meshes(source, Path::new(name), name, cache)?;
+    Ok(finish_manifest(
+        meshes.iter().map(|mesh| mesh.mesh.as_slice()),
+    ))
+}
+
+/// Run a processing task, turning panics into errors. Processing reports
+/// failures as errors, this is only a last resort against panics in
+/// dependencies such as the glTF reader.
+pub fn run_task<T, F>(task: F) -> Result<T>
+where
+    F: FnOnce() -> Result<T>,
+{
+    match catch_unwind(AssertUnwindSafe(task)) {
+        Ok(result) => result,
+        Err(panic) => {
+            let message = if let Some(message) = panic.downcast_ref::<&str>() {
+                message.to_string()
+            } else if let Some(message) = panic.downcast_ref::<String>() {
+                message.clone()
+            } else {
+                "unknown panic".to_string()
+            };
+            Err(Error::bug(message))
+        }
+    }
+}
+
+/// Outcome of processing a single library entry.
+#[derive(Debug)]
+pub struct EntryStatus {
+    pub name: String,
+    pub error: Option<Error>,
+}
+
+/// A manifest holding every library entry that processed successfully,
+/// along with the status of each entry.
+#[derive(Debug)]
+pub struct LibraryReport {
+    pub manifest: Vec<u8>,
+    pub entries: Vec<EntryStatus>,
+}
+
+impl LibraryReport {
+    pub fn failed(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.error.is_some())
+            .count()
+    }
+}
+
+/// Process every entry of a mesh library into one `schema::Manifest`, with
+/// each mesh named after its entry. Entry files are resolved against `root`,
+/// `overrides` take precedence over the options in the library, and a failing
+/// entry is reported without aborting the rest of the batch.
+pub fn process_library(
+    library: &MeshLibrary,
+    root: &Path,
+    overrides: &GltfOptionOverrides,
+    cache: Option<&MeshCache>,
+) -> LibraryReport {
+    let mut meshes = Vec::with_capacity(library.entries.len());
+    let mut entries = Vec::with_capacity(library.entries.len());
+    let mut processed: HashSet<&str> = HashSet::new();
+
+    for entry in &library.entries {
+        let result = if processed.contains(entry.name.as_str()) {
+            Err(Error::config(format!(
+                "duplicate entry name '{}'",
+                entry.name
+            )))
+        } else {
+            run_task(|| {
+                let model_path = resolve_relative(root, &entry.file)?;
+                let options = library.entry_options(entry).with_overrides(overrides);
+                let source = GltfSource::from_path(&model_path, options)?;
+                output_meshes(source, &model_path, &entry.name, cache)
+            })
+        };
+
+        let error = match result {
+            Ok(output) => {
+                meshes.extend(output);
+                processed.insert(entry.name.as_str());
+                None
+            }
+            Err(err) => Some(err),
+        };
+        entries.push(EntryStatus {
+            name: entry.name.clone(),
+            error,
+        });
+    }
+
+    LibraryReport {
+        manifest: finish_manifest(meshes.iter().map(|mesh| mesh.mesh.as_slice())),
+        entries,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skinned_triangle_gltf;
+    use crate::triangle_gltf;
+    use crate::TempDir;
+    use crate::DEFAULT_CACHE_SIZE;
+
+    #[test]
+    fn skins_without_animations_are_kept() {
+        let load_animations = GltfOptions {
+            load_animations: false,
+            ..GltfOptions::default()
+        };
+        for options in &[GltfOptions::default(), load_animations] {
+            let manifest = process_upload(
+                "skinned",
+                &skinned_triangle_gltf(),
+                &HashMap::new(),
+                options,
+                None,
+            )
+            .unwrap();
+            let mesh = schema::get_root_as_manifest(&manifest)
+                .meshes()
+                .unwrap()
+                .get(0);
+
+            let skeleton = mesh.skeleton().expect("skeleton");
+            assert_eq!(skeleton.joint_names().unwrap().len(), 2);
+            let parents = skeleton.joint_parents().unwrap();
+            assert_eq!((parents.get(0), parents.get(1)), (-1, 0));
+
+            // Joint ids and weights of every vertex, the mesh is not flattened
+            let positions = mesh
+                .streams()
+                .unwrap()
+                .iter()
+                .find(|stream| stream.type_() == schema::StreamType::Positions)
+                .expect("positions");
+            assert_eq!(mesh.skinning_influences(), 4);
+            assert_eq!(
+                mesh.skinning_data().unwrap().len() as u64,
+                positions.elements() * 4 * 6
+            );
+            assert_eq!(
+                mesh.parts().unwrap().get(0).animation_type(),
+                schema::AnimationType::Skinned
+            );
+            assert_eq!(
+                mesh.animations().map_or(0, |animations| animations.len()),
+                0
+            );
+        }
+    }
+
+    /// Stream `path` in chunks of `chunk_size` bytes, returning the header and
+    /// the data of every stream of every mesh reassembled from the chunks.
+    fn streamed(
+        path: &Path,
+        cache: Option<&MeshCache>,
+        chunk_size: usize,
+    ) -> (Vec<u8>, Vec<Vec<Vec<u8>>>) {
+        let mut header = None;
+        let mut meshes: Vec<Vec<Vec<u8>>> = Vec::new();
+        stream_mesh(path, &GltfOptions::default(), cache, chunk_size, |chunk| {
+            match chunk {
+                MeshChunk::Header(data) => {
+                    assert!(header.is_none());
+                    let manifest = schema::get_root_as_manifest(&data);
+                    for mesh in manifest.meshes().unwrap().iter() {
+                        let streams = mesh.streams().unwrap();
+                        assert!(streams.iter().all(|stream| stream.data().is_none()));
+                        meshes.push(vec![Vec::new(); streams.len()]);
+                    }
+                    header = Some(data);
+                }
+                MeshChunk::Stream {
+                    mesh,
+                    stream,
+                    offset,
+                    data,
+                } => {
+                    assert!(header.is_some() && data.len() <= chunk_size);
+                    let stream = &mut meshes[mesh as usize][stream as usize];
+                    assert_eq!(stream.len() as u64, offset);
+                    stream.extend_from_slice(&data);
+                }
+            }
+            Ok(())
+        })
+        .unwrap();
+        (header.expect("header"), meshes)
+    }
+
+    #[test]
+    fn streamed_chunks_match_processed_streams() {
+        let directory = TempDir::new("process-stream");
+        let path = directory.path().join("triangle.gltf");
+        std::fs::write(&path, triangle_gltf()).unwrap();
+        let manifest = process_mesh(&path, &GltfOptions::default(), None).unwrap();
+        let processed: Vec<Vec<Vec<u8>>> = schema::get_root_as_manifest(&manifest)
+            .meshes()
+            .unwrap()
+            .iter()
+            .map(|mesh| {
+                mesh.streams()
+                    .unwrap()
+                    .iter()
+                    .map(|stream| stream.data().unwrap_or_default().to_vec())
+                    .collect()
+            })
+            .collect();
+
+        for &chunk_size in &[1, 7, 1024 * 1024] {
+            let (_, meshes) = streamed(&path, None, chunk_size);
+            assert_eq!(meshes, processed);
+        }
+
+        // Cached meshes stream the same data, streaming does not fill the cache
+        let cache_directory = TempDir::new("process-stream-cache");
+        let cache = MeshCache::open(cache_directory.path(), DEFAULT_CACHE_SIZE).unwrap();
+        let (header, meshes) = streamed(&path, Some(&cache), 7);
+        assert_eq!(meshes, processed);
+        assert_eq!(cache.stats().entries, 0);
+        process_mesh(&path, &GltfOptions::default(), Some(&cache)).unwrap();
+        let (cached_header, cached_meshes) = streamed(&path, Some(&cache), 7);
+        assert_eq!(cached_header, header);
+        assert_eq!(cached_meshes, processed);
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses), (1, 2));
+    }
+
+    #[test]
+    fn cached_meshes_match_processed_meshes() {
+        let directory = TempDir::new("process-cache");
+        let cache = MeshCache::open(directory.path(), DEFAULT_CACHE_SIZE).unwrap();
+        let options = GltfOptions::default();
+        let process = |name: &str, cache: Option<&MeshCache>| {
+            process_upload(name, &triangle_gltf(), &HashMap::new(), &options, cache).unwrap()
+        };
+
+        let fresh = process("triangle", None);
+        let miss = process("triangle", Some(&cache));
+        let hit = process("triangle", Some(&cache));
+        assert_eq!(miss, fresh);
+        assert_eq!(hit, fresh);
+
+        // Served from the cache under another name, only the name changes
+        let renamed = process("renamed", Some(&cache));
+        let mesh = schema::get_root_as_manifest(&fresh)
+            .meshes()
+            .unwrap()
+            .get(0);
+        let renamed = schema::get_root_as_manifest(&renamed)
+            .meshes()
+            .unwrap()
+            .get(0);
+        assert_eq!(renamed.name(), Some("renamed"));
+        assert_eq!(renamed.identity(), mesh.identity());
+        let (streams, renamed_streams) = (mesh.streams().unwrap(), renamed.streams().unwrap());
+        assert_eq!(renamed_streams.len(), streams.len());
+        for index in 0..streams.len() {
+            assert_eq!(renamed_streams.get(index).data(), streams.get(index).data());
+        }
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses), (2, 1));
+    }
+
+    #[test]
+    fn partially_cached_sources_count_as_misses() {
+        let mut document: serde_json::Value = serde_json::from_slice(&triangle_gltf()).unwrap();
+        document["scenes"] = serde_json::json!([{ "nodes": [0] }, { "nodes": [0] }]);
+        let asset = serde_json::to_vec(&document).unwrap();
+        let options = GltfOptions {
+            split_scenes: true,
+            ..GltfOptions::default()
+        };
+
+        let directory = TempDir::new("process-cache-stats");
+        let cache = MeshCache::open(directory.path(), DEFAULT_CACHE_SIZE).unwrap();
+        let process = || {
+            let manifest =
+                process_upload("scenes", &asset, &HashMap::new(), &options, Some(&cache)).unwrap();
+            let meshes = schema::get_root_as_manifest(&manifest).meshes().unwrap();
+            assert_eq!(meshes.len(), 2);
+            let stats = cache.stats();
+            (stats.hits, stats.misses)
+        };
+        assert_eq!(process(), (0, 2));
+
+        let cached = std::fs::read_dir(directory.path())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        std::fs::remove_file(cached.path()).unwrap();
+        assert_eq!(process(), (0, 4));
+        assert_eq!(process(), (2, 4));
+    }
+}
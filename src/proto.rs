@@ -0,0 +1,4 @@
+#![allow(clippy::all)]
+
+// Protobuf messages and gRPC stubs generated from proto/svc_mesh.proto
+include!(concat!(env!("OUT_DIR"), "/service.mesh.rs"));
@@ -0,0 +1,423 @@
+use crate::generated::service::mesh::schema;
+use crate::Error;
+use crate::Result;
+use crate::StreamData;
+use crate::Vector3;
+use serde::Deserialize;
+
+/// Encoding of the components of a vertex attribute stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StreamEncoding {
+    Float,
+    Half,
+    Snorm8,
+    Unorm8,
+    Snorm16,
+    Unorm16,
+    /// Unit vectors folded onto an octahedron, two snorm components.
+    Octahedral8,
+    Octahedral16,
+    /// Three 10-bit and one 2-bit component in a u32.
+    #[serde(rename = "packed_1010102")]
+    Packed1010102,
+}
+
+/// Encoding of each vertex attribute stream, unset streams stay 32-bit floats.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct QuantizationPolicy {
+    pub positions: Option<StreamEncoding>,
+    pub normals: Option<StreamEncoding>,
+    pub tangents: Option<StreamEncoding>,
+    pub bitangents: Option<StreamEncoding>,
+    pub tex_coords: Option<StreamEncoding>,
+    pub colors: Option<StreamEncoding>,
+}
+
+impl QuantizationPolicy {
+    /// Return a copy of this policy with every set override applied.
+    pub fn with_overrides(&self, overrides: &QuantizationPolicy) -> QuantizationPolicy {
+        QuantizationPolicy {
+            positions: overrides.positions.or(self.positions),
+            normals: overrides.normals.or(self.normals),
+            tangents: overrides.tangents.or(self.tangents),
+            bitangents: overrides.bitangents.or(self.bitangents),
+            tex_coords: overrides.tex_coords.or(self.tex_coords),
+            colors: overrides.colors.or(self.colors),
+        }
+    }
+}
+
+/// How the values of a stream are mapped before quantization.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum StreamKind {
+    /// Arbitrary values, normalized to the bounds of the stream.
+    Bounded,
+    /// Unit vectors.
+    Direction,
+    /// Colors in the [0, 1] range, or above for half floats.
+    Color,
+}
+
+fn stream_kind(type_: schema::StreamType) -> StreamKind {
+    match type_ {
+        schema::StreamType::Normals
+        | schema::StreamType::Tangents
+        | schema::StreamType::Bitangents => StreamKind::Direction,
+        schema::StreamType::Colors => StreamKind::Color,
+        _ => StreamKind::Bounded,
+    }
+}
+
+/// Encode a vertex attribute stream of 2, 3 or 4 float components per element.
+/// Quantized positions and texture coordinates are normalized to the bounds of
+/// the stream and decode as `value * scale + offset`.
+pub fn encode_stream<A: AsRef<[f32]>>(
+    type_: schema::StreamType,
+    values: &[A],
+    encoding: StreamEncoding,
+) -> Result<StreamData> {
+    let components = values.first().map_or(3, |value| value.as_ref().len());
+    let kind = stream_kind(type_);
+
+    let format = stream_format(kind, components, encoding).ok_or_else(|| {
+        Error::config(format!("{:?} can not be encoded as {:?}", type_, encoding))
+    })?;
+
+    let signed = match encoding {
+        StreamEncoding::Unorm8 | StreamEncoding::Unorm16 => false,
+        StreamEncoding::Packed1010102 => kind == StreamKind::Direction,
+        _ => true,
+    };
+    let dequantize = if kind == StreamKind::Bounded && encoding != StreamEncoding::Float {
+        Some(stream_bounds(values, signed))
+    } else {
+        None
+    };
+
+    let mut data = Vec::with_capacity(values.len() * 16);
+    for value in values {
+        let mut element = [0.0f32; 4];
+        element[..components].copy_from_slice(value.as_ref());
+        if let Some((offset, scale)) = dequantize {
+            for (axis, component) in element.iter_mut().take(components.min(3)).enumerate() {
+                *component = (*component - offset[axis]) / scale[axis];
+            }
+        }
+        encode_element(&mut data, &element, components, encoding, signed);
+    }
+
+    Ok(StreamData {
+        type_,
+        format,
+        elements: values.len() as u64,
+        decoded_size: data.len() as u64,
+        data,
+        dequantize,
+        attributes: Vec::new(),
+        compression: schema::StreamCompression::None,
+    })
+}
+
+/// Stream format of `components` values encoded as `encoding`, or None when
+/// the encoding does not suit the stream.
+fn stream_format(
+    kind: StreamKind,
+    components: usize,
+    encoding: StreamEncoding,
+) -> Option<schema::StreamFormat> {
+    let format = match (encoding, components) {
+        (StreamEncoding::Float, 2) => schema::StreamFormat::Vector2,
+        (StreamEncoding::Float, 3) => schema::StreamFormat::Vector3,
+        (StreamEncoding::Float, 4) => schema::StreamFormat::Vector4,
+        (StreamEncoding::Half, 2) => schema::StreamFormat::Half2,
+        (StreamEncoding::Half, _) => schema::StreamFormat::Half4,
+        (StreamEncoding::Snorm8, _) if kind != StreamKind::Color => schema::StreamFormat::Snorm8x4,
+        (StreamEncoding::Unorm8, _) if kind != StreamKind::Direction => {
+            schema::StreamFormat::Unorm8x4
+        }
+        (StreamEncoding::Snorm16, 2) => schema::StreamFormat::Snorm16x2,
+        (StreamEncoding::Snorm16, _) if kind != StreamKind::Color => {
+            schema::StreamFormat::Snorm16x4
+        }
+        (StreamEncoding::Unorm16, 2) if kind != StreamKind::Direction => {
+            schema::StreamFormat::Unorm16x2
+        }
+        (StreamEncoding::Unorm16, _) if kind != StreamKind::Direction => {
+            schema::StreamFormat::Unorm16x4
+        }
+        (StreamEncoding::Octahedral8, 3) if kind == StreamKind::Direction => {
+            schema::StreamFormat::Octahedral8
+        }
+        (StreamEncoding::Octahedral16, 3) if kind == StreamKind::Direction => {
+            schema::StreamFormat::Octahedral16
+        }
+        (StreamEncoding::Packed1010102, 3) if kind == StreamKind::Direction => {
+            schema::StreamFormat::Snorm1010102
+        }
+        (StreamEncoding::Packed1010102, 3) | (StreamEncoding::Packed1010102, 4) => {
+            schema::StreamFormat::Unorm1010102
+        }
+        _ => return None,
+    };
+    Some(format)
+}
+
+/// Offset and scale mapping the values of a stream to [-1, 1] when `signed`,
+/// to [0, 1] otherwise.
+fn stream_bounds<A: AsRef<[f32]>>(values: &[A], signed: bool) -> (Vector3, Vector3) {
+    let mut min = Vector3::repeat(std::f32::MAX);
+    let mut max = Vector3::repeat(std::f32::MIN);
+    for value in values {
+        for (axis, &value) in value.as_ref().iter().take(3).enumerate() {
+            min[axis] = min[axis].min(value);
+            max[axis] = max[axis].max(value);
+        }
+    }
+    // Unused components decode to 0
+    for (min, max) in min.iter_mut().zip(max.iter_mut()) {
+        if *min > *max {
+            *min = 0.0;
+            *max = 0.0;
+        }
+    }
+
+    let extent = (max - min).map(|extent| if extent > 0.0 { extent } else { 1.0 });
+    if signed {
+        ((min + max) / 2.0, extent / 2.0)
+    } else {
+        (min, extent)
+    }
+}
+
+/// Append one element of `components` values, elements of three components
+/// are padded to four unless packed.
+fn encode_element(
+    data: &mut Vec<u8>,
+    element: &[f32; 4],
+    components: usize,
+    encoding: StreamEncoding,
+    signed: bool,
+) {
+    let padded = if components == 2 {
+        &element[..2]
+    } else {
+        &element[..]
+    };
+    match encoding {
+        StreamEncoding::Float => {
+            for value in &element[..components] {
+                data.extend_from_slice(&value.to_bits().to_le_bytes());
+            }
+        }
+        StreamEncoding::Half => {
+            for &value in padded {
+                data.extend_from_slice(&f32_to_f16(value).to_le_bytes());
+            }
+        }
+        StreamEncoding::Snorm8 => {
+            for &value in element {
+                data.push(snorm(value, 8) as i8 as u8);
+            }
+        }
+        StreamEncoding::Unorm8 => {
+            for &value in element {
+                data.push(unorm(value, 8) as u8);
+            }
+        }
+        StreamEncoding::Snorm16 => {
+            for &value in padded {
+                data.extend_from_slice(&(snorm(value, 16) as i16).to_le_bytes());
+            }
+        }
+        StreamEncoding::Unorm16 => {
+            for &value in padded {
+                data.extend_from_slice(&(unorm(value, 16) as u16).to_le_bytes());
+            }
+        }
+        StreamEncoding::Octahedral8 => {
+            let [x, y] = octahedral(element);
+            data.push(snorm(x, 8) as i8 as u8);
+            data.push(snorm(y, 8) as i8 as u8);
+        }
+        StreamEncoding::Octahedral16 => {
+            let [x, y] = octahedral(element);
+            data.extend_from_slice(&(snorm(x, 16) as i16).to_le_bytes());
+            data.extend_from_slice(&(snorm(y, 16) as i16).to_le_bytes());
+        }
+        StreamEncoding::Packed1010102 => {
+            let [x, y, z, w] = *element;
+            let packed = if signed {
+                let component = |value: f32| (snorm(value, 10) as u32) & 0x3ff;
+                component(x) | (component(y) << 10) | (component(z) << 20)
+            } else {
+                unorm(x, 10) | (unorm(y, 10) << 10) | (unorm(z, 10) << 20) | (unorm(w, 2) << 30)
+            };
+            data.extend_from_slice(&packed.to_le_bytes());
+        }
+    }
+}
+
+/// Signed normalized integer of `bits` bits, in two's complement.
+fn snorm(value: f32, bits: u32) -> i32 {
+    let max = ((1 << (bits - 1)) - 1) as f32;
+    (value.max(-1.0).min(1.0) * max).round() as i32
+}
+
+/// Unsigned normalized integer of `bits` bits.
+fn unorm(value: f32, bits: u32) -> u32 {
+    let max = ((1u32 << bits) - 1) as f32;
+    (value.max(0.0).min(1.0) * max).round() as u32
+}
+
+/// Project a unit vector onto the octahedron and unfold it to [-1, 1]².
+fn octahedral(&[x, y, z, _]: &[f32; 4]) -> [f32; 2] {
+    let length = x.abs() + y.abs() + z.abs();
+    if length <= 0.0 {
+        return [0.0, 0.0];
+    }
+    let (x, y, z) = (x / length, y / length, z / length);
+    if z >= 0.0 {
+        [x, y]
+    } else {
+        let sign = |value: f32| if value >= 0.0 { 1.0 } else { -1.0 };
+        [(1.0 - y.abs()) * sign(x), (1.0 - x.abs()) * sign(y)]
+    }
+}
+
+/// Convert to an IEEE 754 half float, rounding to nearest even.
+fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x007f_ffff;
+
+    if exponent == 0xff {
+        // Infinity or NaN
+        let nan = if mantissa != 0 { 0x0200 } else { 0 };
+        return sign | 0x7c00 | nan;
+    }
+
+    let half_exponent = exponent - 127 + 15;
+    if half_exponent >= 0x1f {
+        // Overflow to infinity
+        return sign | 0x7c00;
+    }
+    if half_exponent <= 0 {
+        // Subnormal half or zero
+        if half_exponent < -10 {
+            return sign;
+        }
+        let mantissa = mantissa | 0x0080_0000;
+        let shift = (14 - half_exponent) as u32;
+        let half_mantissa = mantissa >> shift;
+        let remainder = mantissa & ((1 << shift) - 1);
+        let halfway = 1 << (shift - 1);
+        let round_up = remainder > halfway || (remainder == halfway && (half_mantissa & 1) != 0);
+        return sign | (half_mantissa + round_up as u32) as u16;
+    }
+
+    let half = ((half_exponent as u32) << 10) | (mantissa >> 13);
+    let remainder = mantissa & 0x1fff;
+    let round_up = remainder > 0x1000 || (remainder == 0x1000 && (half & 1) != 0);
+    // A carry out of the mantissa correctly increments the exponent
+    sign | (half + round_up as u32) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn half_floats_handle_edge_cases() {
+        assert_eq!(f32_to_f16(0.0), 0x0000);
+        assert_eq!(f32_to_f16(-0.0), 0x8000);
+        assert_eq!(f32_to_f16(1.0), 0x3c00);
+        assert_eq!(f32_to_f16(-2.0), 0xc000);
+        assert_eq!(f32_to_f16(65504.0), 0x7bff);
+
+        // Subnormals, the smallest one and ties rounding to even
+        assert_eq!(f32_to_f16(2.0f32.powi(-14)), 0x0400);
+        assert_eq!(f32_to_f16(2.0f32.powi(-15)), 0x0200);
+        assert_eq!(f32_to_f16(2.0f32.powi(-24)), 0x0001);
+        assert_eq!(f32_to_f16(-2.0f32.powi(-24)), 0x8001);
+        assert_eq!(f32_to_f16(2.0f32.powi(-25)), 0x0000);
+        assert_eq!(f32_to_f16(1.5 * 2.0f32.powi(-25)), 0x0001);
+        assert_eq!(f32_to_f16(std::f32::MIN_POSITIVE), 0x0000);
+
+        // Overflow rounds to infinity
+        assert_eq!(f32_to_f16(65520.0), 0x7c00);
+        assert_eq!(f32_to_f16(1e10), 0x7c00);
+        assert_eq!(f32_to_f16(-1e10), 0xfc00);
+        assert_eq!(f32_to_f16(std::f32::INFINITY), 0x7c00);
+        assert_eq!(f32_to_f16(std::f32::NEG_INFINITY), 0xfc00);
+
+        // NaN stays NaN
+        let nan = f32_to_f16(std::f32::NAN);
+        assert_eq!(nan & 0x7c00, 0x7c00);
+        assert_ne!(nan & 0x03ff, 0);
+    }
+
+    #[test]
+    fn normalized_integers_are_clamped() {
+        assert_eq!(snorm(2.0, 8), 127);
+        assert_eq!(snorm(-2.0, 8), -127);
+        assert_eq!(snorm(-1.0, 16), -32767);
+        assert_eq!(snorm(0.5, 8), 64);
+        assert_eq!(snorm(0.0, 10), 0);
+        assert_eq!(unorm(-1.0, 8), 0);
+        assert_eq!(unorm(2.0, 8), 255);
+        assert_eq!(unorm(1.0, 16), 65535);
+        assert_eq!(unorm(0.5, 10), 512);
+        assert_eq!(unorm(7.0, 2), 3);
+    }
+
+    /// Decode octahedral coordinates stored as snorm integers of `bits` bits.
+    fn decode_octahedral(x: i32, y: i32, bits: u32) -> Vector3 {
+        let max = ((1 << (bits - 1)) - 1) as f32;
+        let (x, y) = (x as f32 / max, y as f32 / max);
+        let z = 1.0 - x.abs() - y.abs();
+        let (x, y) = if z < 0.0 {
+            let sign = |value: f32| if value >= 0.0 { 1.0 } else { -1.0 };
+            ((1.0 - y.abs()) * sign(x), (1.0 - x.abs()) * sign(y))
+        } else {
+            (x, y)
+        };
+        Vector3::new(x, y, z).normalize()
+    }
+
+    #[test]
+    fn octahedral_round_trip() {
+        let mut directions = vec![
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(-1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, -1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+            Vector3::new(0.0, 0.0, -1.0),
+        ];
+        for step in 0..200 {
+            // A spiral over the whole sphere
+            let z = 1.0 - (step as f32 + 0.5) / 100.0;
+            let angle = step as f32 * 2.4;
+            let radius = (1.0 - z * z).sqrt();
+            directions.push(Vector3::new(radius * angle.cos(), radius * angle.sin(), z));
+        }
+
+        for &(bits, tolerance) in &[(8, 0.02), (16, 1e-4)] {
+            for direction in &directions {
+                let [x, y] = octahedral(&[direction.x, direction.y, direction.z, 0.0]);
+                let decoded = decode_octahedral(snorm(x, bits), snorm(y, bits), bits);
+                let error = (decoded - direction).norm();
+                assert!(
+                    error < tolerance,
+                    "{:?} decodes to {:?} with {} bits",
+                    direction,
+                    decoded,
+                    bits
+                );
+            }
+        }
+    }
+}
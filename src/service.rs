@@ -0,0 +1,664 @@
+use crate::process_library;
+use crate::process_mesh;
+use crate::process_upload;
+use crate::proto;
+use crate::proto::server;
+use crate::resolve_relative;
+use crate::run_task;
+use crate::stream_mesh;
+use crate::Error;
+use crate::ErrorKind;
+use crate::GltfOptionOverrides;
+use crate::GltfOptions;
+use crate::Handedness;
+use crate::LengthUnit;
+use crate::MeshCache;
+use crate::MeshChunk;
+use crate::MeshLibrary;
+use crate::QuantizationPolicy;
+use crate::Result;
+use crate::StreamEncoding;
+use crate::UpAxis;
+use crate::VertexSemantic;
+use elapsed::measure_time;
+use futures::sync::mpsc;
+use futures::{future, Future, Sink, Stream};
+use futures_cpupool::CpuPool;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::executor::DefaultExecutor;
+use tokio::net::TcpListener;
+use tower_grpc::{Code, Request, Response, Status};
+
+/// Maximum number of stream bytes carried by a single `StreamMesh` message.
+const STREAM_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Number of `StreamMesh` messages buffered ahead of the client.
+const STREAM_BUFFER: usize = 4;
+
+/// Overrides carried by the options of a request.
+fn option_overrides(options: proto::GltfOptions) -> Result<GltfOptionOverrides> {
+    let tex_coord_u = options.generate_tex_coord_u;
+    let tex_coord_v = options.generate_tex_coord_v;
+    let generate_tex_coords = match (tex_coord_u, tex_coord_v) {
+        (None, None) => None,
+        (u, v) => Some((u.unwrap_or_default(), v.unwrap_or_default())),
+    };
+    let (lod_ratios, lod_errors) = match options.lods {
+        Some(lods) => (Some(lods.ratios), Some(lods.errors)),
+        None => (None, None),
+    };
+    let interleaved_attributes = match options.interleaved_attributes {
+        Some(attributes) => Some(
+            attributes
+                .attributes
+                .into_iter()
+                .map(vertex_semantic)
+                .collect::<Result<Vec<_>>>()?,
+        ),
+        None => None,
+    };
+    let quantization = match options.quantization {
+        Some(policy) => quantization_policy(policy)?,
+        None => QuantizationPolicy::default(),
+    };
+    Ok(GltfOptionOverrides {
+        scene_index: options.scene_index.map(|scene_index| scene_index as usize),
+        load_animations: options.load_animations,
+        regenerate_tangents: options.regenerate_tangents,
+        generate_tex_coords,
+        flip_v_coord: options.flip_v_coord,
+        split_scenes: options.split_scenes,
+        weld_epsilon: options.weld_epsilon,
+        optimize: options.optimize,
+        overdraw_threshold: options.overdraw_threshold,
+        lod_ratios,
+        lod_errors,
+        build_meshlets: options.build_meshlets,
+        meshlet_max_vertices: options.meshlet_max_vertices,
+        meshlet_max_triangles: options.meshlet_max_triangles,
+        quantization,
+        split_large_parts: options.split_large_parts,
+        interleave: options.interleave,
+        interleaved_attributes,
+        compress_streams: options.compress_streams,
+        instancing: options.instancing,
+        global_scale: options.global_scale,
+        units: length_unit(options.units),
+        up_axis: up_axis(options.up_axis),
+        handedness: handedness(options.handedness),
+    })
+}
+
+/// Length unit selected by a request, None when unset or unknown.
+fn length_unit(units: i32) -> Option<LengthUnit> {
+    match proto::LengthUnit::from_i32(units)? {
+        proto::LengthUnit::UnitUnset => None,
+        proto::LengthUnit::Meters => Some(LengthUnit::Meters),
+        proto::LengthUnit::Centimeters => Some(LengthUnit::Centimeters),
+        proto::LengthUnit::Inches => Some(LengthUnit::Inches),
+    }
+}
+
+/// Up axis selected by a request, None when unset or unknown.
+fn up_axis(up_axis: i32) -> Option<UpAxis> {
+    match proto::UpAxis::from_i32(up_axis)? {
+        proto::UpAxis::AxisUnset => None,
+        proto::UpAxis::YUp => Some(UpAxis::Y),
+        proto::UpAxis::ZUp => Some(UpAxis::Z),
+    }
+}
+
+/// Handedness selected by a request, None when unset or unknown.
+fn handedness(handedness: i32) -> Option<Handedness> {
+    match proto::Handedness::from_i32(handedness)? {
+        proto::Handedness::UnsetHandedness => None,
+        proto::Handedness::RightHanded => Some(Handedness::Right),
+        proto::Handedness::LeftHanded => Some(Handedness::Left),
+    }
+}
+
+/// Vertex attribute selected by a request.
+fn vertex_semantic(semantic: i32) -> Result<VertexSemantic> {
+    let semantic = match proto::VertexSemantic::from_i32(semantic) {
+        Some(semantic) => semantic,
+        None => {
+            return Err(Error::config(format!(
+                "unknown vertex attribute {}",
+                semantic
+            )))
+        }
+    };
+    let semantic = match semantic {
+        proto::VertexSemantic::Positions => VertexSemantic::Positions,
+        proto::VertexSemantic::Normals => VertexSemantic::Normals,
+        proto::VertexSemantic::Tangents => VertexSemantic::Tangents,
+        proto::VertexSemantic::Bitangents => VertexSemantic::Bitangents,
+        proto::VertexSemantic::TexCoords => VertexSemantic::TexCoords,
+        proto::VertexSemantic::Colors => VertexSemantic::Colors,
+    };
+    Ok(semantic)
+}
+
+/// Quantization policy carried by a request.
+fn quantization_policy(policy: proto::QuantizationPolicy) -> Result<QuantizationPolicy> {
+    Ok(QuantizationPolicy {
+        positions: stream_encoding(policy.positions)?,
+        normals: stream_encoding(policy.normals)?,
+        tangents: stream_encoding(policy.tangents)?,
+        bitangents: stream_encoding(policy.bitangents)?,
+        tex_coords: stream_encoding(policy.tex_coords)?,
+        colors: stream_encoding(policy.colors)?,
+    })
+}
+
+/// Encoding selected by a request, None when unset.
+fn stream_encoding(encoding: i32) -> Result<Option<StreamEncoding>> {
+    let encoding = match proto::StreamEncoding::from_i32(encoding) {
+        Some(encoding) => encoding,
+        None => {
+            return Err(Error::config(format!(
+                "unknown stream encoding {}",
+                encoding
+            )))
+        }
+    };
+    let encoding = match encoding {
+        proto::StreamEncoding::Unset => return Ok(None),
+        proto::StreamEncoding::Float => StreamEncoding::Float,
+        proto::StreamEncoding::Half => StreamEncoding::Half,
+        proto::StreamEncoding::Snorm8 => StreamEncoding::Snorm8,
+        proto::StreamEncoding::Unorm8 => StreamEncoding::Unorm8,
+        proto::StreamEncoding::Snorm16 => StreamEncoding::Snorm16,
+        proto::StreamEncoding::Unorm16 => StreamEncoding::Unorm16,
+        proto::StreamEncoding::Octahedral8 => StreamEncoding::Octahedral8,
+        proto::StreamEncoding::Octahedral16 => StreamEncoding::Octahedral16,
+        proto::StreamEncoding::Packed1010102 => StreamEncoding::Packed1010102,
+    };
+    Ok(Some(encoding))
+}
+
+/// Overrides carried by a request, unset when the request has no options.
+fn request_overrides(options: Option<proto::GltfOptions>) -> Result<GltfOptionOverrides> {
+    options.map_or_else(|| Ok(GltfOptionOverrides::default()), option_overrides)
+}
+
+/// Map a processing error to the gRPC status returned to the client.
+pub fn error_to_status(err: &Error) -> Status {
+    let code = match *err.kind() {
+        ErrorKind::Parse(_) | ErrorKind::Number | ErrorKind::Config(_) => Code::InvalidArgument,
+        ErrorKind::InvalidInput(_) | ErrorKind::Path(_) => Code::InvalidArgument,
+        ErrorKind::SceneIndex { .. } => Code::InvalidArgument,
+        ErrorKind::Memory(_) => Code::FailedPrecondition,
+        ErrorKind::Io => {
+            let io_kind = failure::Fail::iter_causes(err)
+                .filter_map(|cause| cause.downcast_ref::<std::io::Error>())
+                .map(|io_err| io_err.kind())
+                .next();
+            match io_kind {
+                Some(std::io::ErrorKind::NotFound) => Code::NotFound,
+                Some(std::io::ErrorKind::PermissionDenied) => Code::PermissionDenied,
+                _ => Code::Internal,
+            }
+        }
+        ErrorKind::Grpc => Code::Unknown,
+        _ => Code::Internal,
+    };
+
+    let mut message = err.to_string();
+    for cause in failure::Fail::iter_causes(err) {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+    }
+
+    Status::new(code, message)
+}
+
+#[derive(Clone)]
+pub struct MeshService {
+    pool: CpuPool,
+    asset_root: PathBuf,
+    cache: Option<Arc<MeshCache>>,
+}
+
+impl MeshService {
+    pub fn new(asset_root: PathBuf, cache: Option<MeshCache>) -> Self {
+        MeshService {
+            pool: CpuPool::new_num_cpus(),
+            asset_root,
+            cache: cache.map(Arc::new),
+        }
+    }
+
+    /// The cache used by a request, unless the request bypasses it.
+    fn request_cache(&self, bypass_cache: bool) -> Option<Arc<MeshCache>> {
+        if bypass_cache {
+            None
+        } else {
+            self.cache.clone()
+        }
+    }
+
+    /// Resolve a client supplied path against the asset root, rejecting
+    /// anything that would escape it.
+    fn resolve_path(&self, path: &str) -> Result<PathBuf> {
+        resolve_relative(&self.asset_root, path)
+    }
+}
+
+impl server::MeshService for MeshService {
+    type ProcessMeshFuture =
+        Box<dyn Future<Item = Response<proto::ProcessMeshResponse>, Error = Status> + Send>;
+
+    fn process_mesh(
+        &mut self,
+        request: Request<proto::ProcessMeshRequest>,
+    ) -> Self::ProcessMeshFuture {
+        let request = request.into_inner();
+        let path = match self.resolve_path(&request.path) {
+            Ok(path) => path,
+            Err(err) => return Box::new(future::err(error_to_status(&err))),
+        };
+        let options = match request_overrides(request.options) {
+            Ok(overrides) => GltfOptions::default().with_overrides(&overrides),
+            Err(err) => return Box::new(future::err(error_to_status(&err))),
+        };
+        let cache = self.request_cache(request.bypass_cache);
+
+        let task = self.pool.spawn_fn(move || {
+            let cache = cache.as_ref().map(Arc::as_ref);
+            let (elapsed, result) =
+                measure_time(|| run_task(|| process_mesh(&path, &options, cache)));
+            match result {
+                Ok(manifest) => {
+                    log::info!(
+                        "Processed '{}' - {} bytes in {}",
+                        path.to_string_lossy(),
+                        manifest.len(),
+                        elapsed
+                    );
+                    Ok(Response::new(proto::ProcessMeshResponse { manifest }))
+                }
+                Err(err) => {
+                    log::warn!("Failed to process '{}': {}", path.to_string_lossy(), err);
+                    Err(error_to_status(&err))
+                }
+            }
+        });
+
+        Box::new(task)
+    }
+
+    type ProcessUploadFuture =
+        Box<dyn Future<Item = Response<proto::ProcessMeshResponse>, Error = Status> + Send>;
+
+    type ProcessLibraryFuture =
+        Box<dyn Future<Item = Response<proto::ProcessLibraryResponse>, Error = Status> + Send>;
+
+    fn process_library(
+        &mut self,
+        request: Request<proto::ProcessLibraryRequest>,
+    ) -> Self::ProcessLibraryFuture {
+        let request = request.into_inner();
+        let path = match self.resolve_path(&request.path) {
+            Ok(path) => path,
+            Err(err) => return Box::new(future::err(error_to_status(&err))),
+        };
+        let overrides = match request_overrides(request.options) {
+            Ok(overrides) => overrides,
+            Err(err) => return Box::new(future::err(error_to_status(&err))),
+        };
+        let cache = self.request_cache(request.bypass_cache);
+
+        let task = self.pool.spawn_fn(move || {
+            let cache = cache.as_ref().map(Arc::as_ref);
+            let library = match MeshLibrary::from_path(&path) {
+                Ok(library) => library,
+                Err(err) => {
+                    log::warn!("Failed to load '{}': {}", path.to_string_lossy(), err);
+                    return Err(error_to_status(&err));
+                }
+            };
+            let root = path.parent().unwrap_or(&path).to_path_buf();
+
+            let (elapsed, report) =
+                measure_time(|| process_library(&library, &root, &overrides, cache));
+            log::info!(
+                "Processed '{}' - {} of {} entries failed, {} bytes in {}",
+                path.to_string_lossy(),
+                report.failed(),
+                report.entries.len(),
+                report.manifest.len(),
+                elapsed
+            );
+
+            let entries = report
+                .entries
+                .into_iter()
+                .map(|entry| {
+                    let error = match entry.error {
+                        Some(err) => {
+                            log::warn!("Failed to process '{}': {}", entry.name, err);
+                            error_to_status(&err).message().to_string()
+                        }
+                        None => String::new(),
+                    };
+                    proto::LibraryEntryStatus {
+                        name: entry.name,
+                        error,
+                    }
+                })
+                .collect();
+            Ok(Response::new(proto::ProcessLibraryResponse {
+                manifest: report.manifest,
+                entries,
+            }))
+        });
+
+        Box::new(task)
+    }
+
+    type StreamMeshStream =
+        Box<dyn Stream<Item = proto::StreamMeshResponse, Error = Status> + Send>;
+    type StreamMeshFuture = future::FutureResult<Response<Self::StreamMeshStream>, Status>;
+
+    fn stream_mesh(
+        &mut self,
+        request: Request<proto::ProcessMeshRequest>,
+    ) -> Self::StreamMeshFuture {
+        let request = request.into_inner();
+        let path = match self.resolve_path(&request.path) {
+            Ok(path) => path,
+            Err(err) => return future::err(error_to_status(&err)),
+        };
+        let options = match request_overrides(request.options) {
+            Ok(overrides) => GltfOptions::default().with_overrides(&overrides),
+            Err(err) => return future::err(error_to_status(&err)),
+        };
+        let cache = self.request_cache(request.bypass_cache);
+
+        // Messages are produced on the pool and block once the buffer is
+        // full, so only a bounded number of chunks is held in memory.
+        let (sender, receiver) = mpsc::channel(STREAM_BUFFER);
+        self.pool
+            .spawn_fn(move || {
+                let cache = cache.as_ref().map(Arc::as_ref);
+                let mut sender = sender.wait();
+                let (elapsed, result) = measure_time(|| {
+                    run_task(|| {
+                        let mut sent = 0;
+                        stream_mesh(&path, &options, cache, STREAM_CHUNK_SIZE, |chunk| {
+                            let payload = match chunk {
+                                MeshChunk::Header(header) => {
+                                    sent += header.len();
+                                    proto::stream_mesh_response::Payload::Header(header)
+                                }
+                                MeshChunk::Stream {
+                                    mesh,
+                                    stream,
+                                    offset,
+                                    data,
+                                } => {
+                                    sent += data.len();
+                                    proto::stream_mesh_response::Payload::Chunk(
+                                        proto::MeshStreamChunk {
+                                            mesh,
+                                            stream,
+                                            offset,
+                                            data,
+                                        },
+                                    )
+                                }
+                            };
+                            let response = proto::StreamMeshResponse {
+                                payload: Some(payload),
+                            };
+                            sender
+                                .send(Ok(response))
+                                .map_err(|_| Error::process("client disconnected"))
+                        })
+                        .map(|_| sent)
+                    })
+                });
+                match result {
+                    Ok(sent) => {
+                        log::info!(
+                            "Streamed '{}' - {} bytes in {}",
+                            path.to_string_lossy(),
+                            sent,
+                            elapsed
+                        );
+                    }
+                    Err(err) => {
+                        log::warn!("Failed to stream '{}': {}", path.to_string_lossy(), err);
+                        let _ = sender.send(Err(error_to_status(&err)));
+                    }
+                }
+                let _ = sender.close();
+                Ok::<(), ()>(())
+            })
+            .forget();
+
+        let stream = receiver.then(|message| match message {
+            Ok(result) => result,
+            Err(()) => Err(Status::new(Code::Internal, "mesh stream closed")),
+        });
+        future::ok(Response::new(Box::new(stream)))
+    }
+
+    fn process_upload(
+        &mut self,
+        request: Request<proto::ProcessUploadRequest>,
+    ) -> Self::ProcessUploadFuture {
+        let request = request.into_inner();
+        let name = if request.name.is_empty() {
+            "upload".to_string()
+        } else {
+            request.name
+        };
+        let asset = request.asset;
+        let resources = request.resources;
+        let options = match request_overrides(request.options) {
+            Ok(overrides) => GltfOptions::default().with_overrides(&overrides),
+            Err(err) => return Box::new(future::err(error_to_status(&err))),
+        };
+        let cache = self.request_cache(request.bypass_cache);
+
+        let task = self.pool.spawn_fn(move || {
+            let cache = cache.as_ref().map(Arc::as_ref);
+            let (elapsed, result) = measure_time(|| {
+                run_task(|| process_upload(&name, &asset, &resources, &options, cache))
+            });
+            match result {
+                Ok(manifest) => {
+                    log::info!(
+                        "Processed upload '{}' - {} bytes in {}",
+                        name,
+                        manifest.len(),
+                        elapsed
+                    );
+                    Ok(Response::new(proto::ProcessMeshResponse { manifest }))
+                }
+                Err(err) => {
+                    log::warn!("Failed to process upload '{}': {}", name, err);
+                    Err(error_to_status(&err))
+                }
+            }
+        });
+
+        Box::new(task)
+    }
+
+    type GetCacheStatsFuture = future::FutureResult<Response<proto::CacheStatsResponse>, Status>;
+
+    fn get_cache_stats(
+        &mut self,
+        _request: Request<proto::CacheStatsRequest>,
+    ) -> Self::GetCacheStatsFuture {
+        let response = match self.cache {
+            Some(ref cache) => {
+                let stats = cache.stats();
+                proto::CacheStatsResponse {
+                    enabled: true,
+                    hits: stats.hits as u64,
+                    misses: stats.misses as u64,
+                    entries: stats.entries as u64,
+                    size: stats.size,
+                }
+            }
+            None => proto::CacheStatsResponse::default(),
+        };
+        future::ok(Response::new(response))
+    }
+}
+
+pub fn serve(address: SocketAddr, service: MeshService) {
+    let new_service = server::MeshServiceServer::new(service);
+
+    let h2_settings = Default::default();
+    let mut h2 = tower_h2::Server::new(new_service, h2_settings, DefaultExecutor::current());
+
+    let bind = TcpListener::bind(&address).expect("bind");
+    let serve = bind
+        .incoming()
+        .for_each(move |sock| {
+            if let Err(e) = sock.set_nodelay(true) {
+                return Err(e);
+            }
+
+            let serve = h2.serve(sock);
+            tokio::spawn(serve.map_err(|e| log::error!("h2 error: {:?}", e)));
+
+            Ok(())
+        })
+        .map_err(|e| log::error!("accept error: {}", e));
+
+    tokio::run(serve)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external_triangle_gltf;
+    use crate::generated::service::mesh::schema;
+    use crate::proto::server::MeshService as _;
+    use crate::triangle_gltf;
+    use crate::TempDir;
+    use std::collections::HashMap;
+
+    fn upload(
+        service: &mut MeshService,
+        asset: Vec<u8>,
+        resources: HashMap<String, Vec<u8>>,
+    ) -> std::result::Result<Vec<u8>, Status> {
+        let request = proto::ProcessUploadRequest {
+            name: "triangle".to_string(),
+            asset,
+            resources,
+            options: None,
+            bypass_cache: false,
+        };
+        service
+            .process_upload(Request::new(request))
+            .wait()
+            .map(|response| response.into_inner().manifest)
+    }
+
+    fn process(service: &mut MeshService, path: &str) -> std::result::Result<Vec<u8>, Status> {
+        process_with(service, path, None)
+    }
+
+    fn process_with(
+        service: &mut MeshService,
+        path: &str,
+        options: Option<proto::GltfOptions>,
+    ) -> std::result::Result<Vec<u8>, Status> {
+        let request = proto::ProcessMeshRequest {
+            path: path.to_string(),
+            options,
+            bypass_cache: false,
+        };
+        service
+            .process_mesh(Request::new(request))
+            .wait()
+            .map(|response| response.into_inner().manifest)
+    }
+
+    #[test]
+    fn upload_is_processed() {
+        let mut service = MeshService::new(PathBuf::from("."), None);
+        let manifest = upload(&mut service, triangle_gltf(), HashMap::new()).unwrap();
+        let meshes = schema::get_root_as_manifest(&manifest).meshes().unwrap();
+        assert_eq!(meshes.len(), 1);
+        assert_eq!(meshes.get(0).name(), Some("triangle"));
+
+        let (asset, buffer) = external_triangle_gltf();
+        let mut resources = HashMap::new();
+        resources.insert("triangle.bin".to_string(), buffer);
+        assert!(upload(&mut service, asset, resources).is_ok());
+    }
+
+    #[test]
+    fn malformed_upload_is_invalid_argument() {
+        let mut service = MeshService::new(PathBuf::from("."), None);
+        let status = upload(&mut service, b"{ \"asset\":".to_vec(), HashMap::new()).unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+
+        // The external buffer is not part of the request
+        let (asset, _) = external_triangle_gltf();
+        let status = upload(&mut service, asset, HashMap::new()).unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+
+    #[test]
+    fn process_mesh_status_codes() {
+        let root = TempDir::new("service-root");
+        std::fs::write(root.path().join("triangle.gltf"), triangle_gltf()).unwrap();
+        let mut service = MeshService::new(root.path().to_path_buf(), None);
+
+        assert!(process(&mut service, "triangle.gltf").is_ok());
+        let status = process(&mut service, "missing.gltf").unwrap_err();
+        assert_eq!(status.code(), Code::NotFound);
+        let status = process(&mut service, "../triangle.gltf").unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+
+    #[test]
+    fn unknown_stream_encoding_is_invalid_argument() {
+        let root = TempDir::new("service-encoding");
+        std::fs::write(root.path().join("triangle.gltf"), triangle_gltf()).unwrap();
+        let mut service = MeshService::new(root.path().to_path_buf(), None);
+
+        let policy = |positions| proto::GltfOptions {
+            quantization: Some(proto::QuantizationPolicy {
+                positions,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let half = proto::StreamEncoding::Half as i32;
+        assert!(process_with(&mut service, "triangle.gltf", Some(policy(half))).is_ok());
+        let status = process_with(&mut service, "triangle.gltf", Some(policy(1000))).unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+
+    #[test]
+    fn unknown_interleaved_attribute_is_invalid_argument() {
+        let root = TempDir::new("service-interleave");
+        std::fs::write(root.path().join("triangle.gltf"), triangle_gltf()).unwrap();
+        let mut service = MeshService::new(root.path().to_path_buf(), None);
+
+        let interleave = |attributes| proto::GltfOptions {
+            interleave: Some(true),
+            interleaved_attributes: Some(proto::InterleavedAttributes { attributes }),
+            ..Default::default()
+        };
+        let positions = proto::VertexSemantic::Positions as i32;
+        let options = Some(interleave(vec![positions]));
+        assert!(process_with(&mut service, "triangle.gltf", options).is_ok());
+        let options = Some(interleave(vec![positions, 1000]));
+        let status = process_with(&mut service, "triangle.gltf", options).unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+}
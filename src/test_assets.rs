@@ -0,0 +1,284 @@
+//! Small glTF documents built in memory for tests.
+
+use crate::AnimationType;
+use crate::Dimensions;
+use crate::GltfVertex;
+use crate::Matrix4;
+use crate::MeshAssetPart;
+use crate::Topology;
+use serde_json::{json, Value};
+use std::path::PathBuf;
+
+/// Little endian bytes of `values`.
+fn float_bytes(values: &[f32]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|value| value.to_bits().to_le_bytes().to_vec())
+        .collect()
+}
+
+/// Embed `data` in a base64 data URI.
+fn data_uri(data: &[u8]) -> String {
+    format!(
+        "data:application/octet-stream;base64,{}",
+        base64::encode(data)
+    )
+}
+
+/// Positions of a right triangle followed by its 16-bit indices.
+fn triangle_buffer() -> Vec<u8> {
+    let mut buffer = float_bytes(&[0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+    buffer.extend_from_slice(&[0, 0, 1, 0, 2, 0, 0, 0]);
+    buffer
+}
+
+fn triangle_document(buffer_uri: &str) -> Vec<u8> {
+    let document = json!({
+        "asset": { "version": "2.0" },
+        "scene": 0,
+        "scenes": [{ "nodes": [0] }],
+        "nodes": [{ "name": "triangle", "mesh": 0 }],
+        "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0 }, "indices": 1 }] }],
+        "buffers": [{ "uri": buffer_uri, "byteLength": 44 }],
+        "bufferViews": [
+            { "buffer": 0, "byteOffset": 0, "byteLength": 36 },
+            { "buffer": 0, "byteOffset": 36, "byteLength": 6 }
+        ],
+        "accessors": [
+            {
+                "bufferView": 0,
+                "componentType": 5126,
+                "count": 3,
+                "type": "VEC3",
+                "min": [0.0, 0.0, 0.0],
+                "max": [1.0, 1.0, 0.0]
+            },
+            { "bufferView": 1, "componentType": 5123, "count": 3, "type": "SCALAR" }
+        ]
+    });
+    to_bytes(&document)
+}
+
+fn to_bytes(document: &Value) -> Vec<u8> {
+    serde_json::to_vec(document).expect("serializable document")
+}
+
+/// A glTF document with a single node named `triangle` drawing one triangle,
+/// its buffer embedded as a data URI.
+pub fn triangle_gltf() -> Vec<u8> {
+    triangle_document(&data_uri(&triangle_buffer()))
+}
+
+/// The triangle of `triangle_gltf` with its buffer stored externally as
+/// `triangle.bin`, along with the contents of that buffer.
+pub fn external_triangle_gltf() -> (Vec<u8>, Vec<u8>) {
+    (triangle_document("triangle.bin"), triangle_buffer())
+}
+
+/// The triangle of `triangle_gltf` skinned to a chain of two joints, `root`
+/// and `tip`, without any animation.
+pub fn skinned_triangle_gltf() -> Vec<u8> {
+    let mut buffer = float_bytes(&[0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+    // JOINTS_0 as unsigned bytes
+    buffer.extend_from_slice(&[0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0]);
+    // WEIGHTS_0
+    buffer.extend(float_bytes(&[
+        1.0, 0.0, 0.0, 0.0, 0.5, 0.5, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0,
+    ]));
+    buffer.extend_from_slice(&[0, 0, 1, 0, 2, 0, 0, 0]);
+
+    let document = json!({
+        "asset": { "version": "2.0" },
+        "scene": 0,
+        "scenes": [{ "nodes": [0, 1] }],
+        "nodes": [
+            { "name": "body", "mesh": 0, "skin": 0 },
+            { "name": "root", "children": [2] },
+            { "name": "tip", "translation": [0.0, 1.0, 0.0] }
+        ],
+        "skins": [{ "joints": [1, 2], "skeleton": 1 }],
+        "meshes": [{
+            "primitives": [{
+                "attributes": { "POSITION": 0, "JOINTS_0": 1, "WEIGHTS_0": 2 },
+                "indices": 3
+            }]
+        }],
+        "buffers": [{ "uri": data_uri(&buffer), "byteLength": buffer.len() }],
+        "bufferViews": [
+            { "buffer": 0, "byteOffset": 0, "byteLength": 36 },
+            { "buffer": 0, "byteOffset": 36, "byteLength": 12 },
+            { "buffer": 0, "byteOffset": 48, "byteLength": 48 },
+            { "buffer": 0, "byteOffset": 96, "byteLength": 6 }
+        ],
+        "accessors": [
+            {
+                "bufferView": 0,
+                "componentType": 5126,
+                "count": 3,
+                "type": "VEC3",
+                "min": [0.0, 0.0, 0.0],
+                "max": [1.0, 1.0, 0.0]
+            },
+            { "bufferView": 1, "componentType": 5121, "count": 3, "type": "VEC4" },
+            { "bufferView": 2, "componentType": 5126, "count": 3, "type": "VEC4" },
+            { "bufferView": 3, "componentType": 5123, "count": 3, "type": "SCALAR" }
+        ]
+    });
+    to_bytes(&document)
+}
+
+/// Two nodes rotated by an animation whose rotations are stored as normalized
+/// 16-bit and 8-bit integers. Both go from the identity to half a turn.
+pub fn normalized_rotation_gltf() -> Vec<u8> {
+    let mut buffer = float_bytes(&[0.0, 1.0]);
+    for component in &[0i16, 0, 0, 32767, 0, -32767, 0, 0] {
+        buffer.extend_from_slice(&component.to_le_bytes());
+    }
+    buffer.extend_from_slice(&[0, 0, 0, 127, 0, 0x80, 0, 0]);
+
+    let document = json!({
+        "asset": { "version": "2.0" },
+        "scene": 0,
+        "scenes": [{ "nodes": [0, 1] }],
+        "nodes": [{ "name": "short" }, { "name": "byte" }],
+        "animations": [{
+            "channels": [
+                { "sampler": 0, "target": { "node": 0, "path": "rotation" } },
+                { "sampler": 1, "target": { "node": 1, "path": "rotation" } }
+            ],
+            "samplers": [{ "input": 0, "output": 1 }, { "input": 0, "output": 2 }]
+        }],
+        "buffers": [{ "uri": data_uri(&buffer), "byteLength": buffer.len() }],
+        "bufferViews": [
+            { "buffer": 0, "byteOffset": 0, "byteLength": 8 },
+            { "buffer": 0, "byteOffset": 8, "byteLength": 16 },
+            { "buffer": 0, "byteOffset": 24, "byteLength": 8 }
+        ],
+        "accessors": [
+            {
+                "bufferView": 0,
+                "componentType": 5126,
+                "count": 2,
+                "type": "SCALAR",
+                "min": [0.0],
+                "max": [1.0]
+            },
+            {
+                "bufferView": 1,
+                "componentType": 5122,
+                "normalized": true,
+                "count": 2,
+                "type": "VEC4"
+            },
+            {
+                "bufferView": 2,
+                "componentType": 5120,
+                "normalized": true,
+                "count": 2,
+                "type": "VEC4"
+            }
+        ]
+    });
+    to_bytes(&document)
+}
+
+/// A vertex at `position` with every other attribute zeroed.
+pub fn vertex_at(position: [f32; 3]) -> GltfVertex {
+    GltfVertex {
+        position,
+        normal: [0.0; 3],
+        uv0: [0.0; 2],
+        color0: [0.0; 4],
+        joint0: [0; 4],
+        joint1: [0; 4],
+        joint2: [0; 4],
+        joint3: [0; 4],
+        weight0: [0.0; 4],
+        weight1: [0.0; 4],
+        weight2: [0.0; 4],
+        weight3: [0.0; 4],
+        tangent: [0.0; 3],
+        bitangent: [0.0; 3],
+        influence_count: 0,
+        skin_index: -1,
+    }
+}
+
+/// Successive states of a linear congruential generator started from `seed`,
+/// so tests get the same pseudo random numbers on every run.
+pub fn random_sequence(seed: u32) -> impl FnMut() -> u32 {
+    let mut state = seed;
+    move || {
+        state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        state
+    }
+}
+
+/// A grid of `size` by `size` quads in the XY plane, split into two triangles
+/// each. The vertices are in row order and the triangles are scrambled.
+pub fn grid_mesh(size: u32) -> (Vec<GltfVertex>, Vec<u32>) {
+    let vertices = (0..=size)
+        .flat_map(|y| (0..=size).map(move |x| vertex_at([x as f32, y as f32, 0.0])))
+        .collect();
+
+    let corner = |x: u32, y: u32| y * (size + 1) + x;
+    let mut triangles: Vec<[u32; 3]> = Vec::new();
+    for y in 0..size {
+        for x in 0..size {
+            triangles.push([corner(x, y), corner(x + 1, y), corner(x + 1, y + 1)]);
+            triangles.push([corner(x, y), corner(x + 1, y + 1), corner(x, y + 1)]);
+        }
+    }
+
+    // Shuffle with a fixed sequence
+    let mut random = random_sequence(1);
+    for index in (1..triangles.len()).rev() {
+        triangles.swap(index, (random() >> 16) as usize % (index + 1));
+    }
+    let indices = triangles
+        .iter()
+        .flat_map(|triangle| triangle.to_vec())
+        .collect();
+    (vertices, indices)
+}
+
+/// A part drawing `index_count` triangle list indices from `index_start`.
+pub fn triangle_part(index_start: u32, index_count: u32) -> MeshAssetPart {
+    MeshAssetPart {
+        index_start,
+        index_count,
+        material_index: None,
+        node_index: None,
+        base_transform: Matrix4::identity(),
+        name: None,
+        animation_type: AnimationType::None,
+        meshlet_start: 0,
+        meshlet_count: 0,
+        base_vertex: 0,
+        instance_transforms: Vec::new(),
+        bounds: Dimensions::default(),
+        topology: Topology::Triangles,
+    }
+}
+
+/// A directory under the system temporary directory, removed when dropped.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    pub fn new(name: &str) -> TempDir {
+        let path = std::env::temp_dir().join(format!("svc-mesh-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).expect("temporary directory");
+        TempDir(path)
+    }
+
+    pub fn path(&self) -> &std::path::Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
@@ -14,6 +14,9 @@ pub struct GltfTexture {
     pub index: GltfIndex,
     pub name: Option<String>,
     pub sampler: GltfSampler,
+    pub image_index: GltfIndex,
+    /// Name of the source image, falling back to its URI or `image_<index>`.
+    pub image_name: String,
     //pub tex_coord: u32, // the tex coord set to use
 }
 
@@ -24,7 +27,16 @@ impl GltfTexture {
         _base_path: &Path,
     ) -> Result<GltfTexture> {
         let _buffers = &data.buffers;
-        let _image_ref = texture_ref.source();
+        let image_ref = texture_ref.source();
+
+        let image_name = if let Some(name) = image_ref.name() {
+            name.to_owned()
+        } else {
+            match image_ref.source() {
+                gltf::image::Source::Uri { uri, .. } if !uri.starts_with("data:") => uri.to_owned(),
+                _ => format!("image_{}", image_ref.index()),
+            }
+        };
 
         let texture_name = if let Some(ref name) = texture_ref.name() {
             Some(name.to_owned().to_string())
@@ -36,6 +48,8 @@ impl GltfTexture {
             index: texture_ref.index(),
             name: texture_name,
             sampler: GltfSampler {},
+            image_index: image_ref.index(),
+            image_name,
         })
     }
 }